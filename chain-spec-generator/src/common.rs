@@ -15,55 +15,337 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
+//! Registry of the runtimes this binary is built with and of the chain specs that can be
+//! generated from their genesis config presets.
+//!
+//! Every preset returned by a runtime's `genesis_config_presets::preset_names` (the same list
+//! exposed through `GenesisBuilder::preset_names`) becomes a supported chain:
+//!
+//! - `development` is generated as `<runtime>-dev`,
+//! - `local_testnet` is generated as `<runtime>-local`,
+//! - `live` or a preset named after the runtime itself (e.g. `asset-hub-polkadot`) is generated as
+//!   the production chain `<runtime>`,
+//! - any other preset `<preset>` is generated as `<runtime>-<preset>`.
+//!
+//! Adding a preset to a runtime is therefore enough to make it available here.
+
 use crate::{
-	relay_chain_specs::{KusamaChainSpec, PolkadotChainSpec},
-	system_parachains_specs::{
-		AssetHubKusamaChainSpec, AssetHubPolkadotChainSpec, BridgeHubKusamaChainSpec,
-		BridgeHubPolkadotChainSpec, CollectivesPolkadotChainSpec, CoretimeKusamaChainSpec,
-		CoretimePolkadotChainSpec, EncointerKusamaChainSpec, GluttonKusamaChainSpec,
-		PeopleKusamaChainSpec, PeoplePolkadotChainSpec,
-	},
+	genesis_patch::{AuraKey, GenesisPatch},
+	relay_chain_specs::{RelayChainSpec, DEFAULT_PROTOCOL_ID},
+	system_parachains_specs::{Extensions, SystemParachainChainSpec},
 	ChainSpec,
 };
+use sc_chain_spec::{ChainSpecBuilder, ChainType, GenesisConfigBuilderRuntimeCaller, Properties};
+use sp_genesis_builder::{PresetId, DEV_RUNTIME_PRESET, LOCAL_TESTNET_RUNTIME_PRESET};
+use std::collections::BTreeMap;
+
+/// The name of the preset used to generate the production chain spec of a runtime.
+pub const LIVE_RUNTIME_PRESET: &str = "live";
+
+/// Whether a runtime is a relay chain or a system parachain.
+#[derive(Clone, Copy, Debug)]
+pub enum RuntimeKind {
+	/// A relay chain, with the genesis config patch for a given number of validators.
+	Relay { validators_genesis: fn(u32) -> serde_json::Value },
+	/// A system parachain of `relay_chain` (the id of the production relay chain spec), with the
+	/// key type of its collators if they are managed by `pallet_collator_selection`.
+	Parachain { relay_chain: &'static str, para_id: u32, aura: Option<AuraKey> },
+}
+
+/// Static description of a runtime, used to turn its genesis presets into chain specs.
+#[derive(Clone, Copy, Debug)]
+pub struct Runtime {
+	/// The id of the production chain spec, used as prefix for all other chain spec ids.
+	pub id: &'static str,
+	/// Additional chain spec id prefixes that identify this runtime in a json file.
+	pub id_aliases: &'static [&'static str],
+	/// The name of the production chain spec, used as prefix for all other chain spec names.
+	pub name: &'static str,
+	/// Relay chain or system parachain.
+	pub kind: RuntimeKind,
+	/// The runtime wasm blob.
+	pub wasm: Option<&'static [u8]>,
+	/// The genesis config presets exposed by the runtime.
+	pub preset_names: fn() -> Vec<PresetId>,
+	/// The genesis config patch of a preset exposed by the runtime.
+	pub get_preset: fn(&PresetId) -> Option<Vec<u8>>,
+	/// The `ss58Format` chain spec property.
+	pub ss58_format: u16,
+	/// The `tokenSymbol` and `tokenDecimals` chain spec properties.
+	pub token: Option<(&'static str, u8)>,
+	/// Boot nodes of the production network.
+	pub boot_nodes: &'static [&'static str],
+}
+
+impl Runtime {
+	/// The chain type of the chain spec generated from `preset`.
+	pub fn chain_type(&self, preset: &str) -> ChainType {
+		match preset {
+			DEV_RUNTIME_PRESET => ChainType::Development,
+			LOCAL_TESTNET_RUNTIME_PRESET => ChainType::Local,
+			_ => ChainType::Live,
+		}
+	}
+
+	/// The chain spec id of the chain spec generated from `preset`.
+	pub fn chain_id(&self, preset: &str) -> String {
+		match preset {
+			DEV_RUNTIME_PRESET => format!("{}-dev", self.id),
+			LOCAL_TESTNET_RUNTIME_PRESET => format!("{}-local", self.id),
+			LIVE_RUNTIME_PRESET => self.id.into(),
+			preset if preset == self.id => self.id.into(),
+			preset => format!("{}-{preset}", self.id),
+		}
+	}
+
+	/// The chain spec name of the chain spec generated from `preset`.
+	pub fn chain_name(&self, preset: &str) -> String {
+		match preset {
+			DEV_RUNTIME_PRESET => format!("{} Development", self.name),
+			LOCAL_TESTNET_RUNTIME_PRESET => format!("{} Local", self.name),
+			LIVE_RUNTIME_PRESET => self.name.into(),
+			preset if preset == self.id => self.name.into(),
+			preset => format!("{} ({preset})", self.name),
+		}
+	}
+
+	/// The chain spec properties of this runtime.
+	pub fn properties(&self) -> Properties {
+		let mut properties = Properties::new();
+		properties.insert("ss58Format".into(), self.ss58_format.into());
+		if let Some((symbol, decimals)) = self.token {
+			properties.insert("tokenSymbol".into(), symbol.into());
+			properties.insert("tokenDecimals".into(), decimals.into());
+		}
+		properties
+	}
+
+	/// Generates the chain spec for the given genesis config `preset`, with `patch` applied on
+	/// top of it.
+	///
+	/// A patched genesis config is checked by building the genesis state with the runtime.
+	pub fn chain_spec(
+		&self,
+		preset: &str,
+		patch: &GenesisPatch,
+	) -> Result<Box<dyn ChainSpec>, String> {
+		let wasm = self.wasm.ok_or_else(|| format!("{} wasm not available", self.name))?;
+		let chain_type = self.chain_type(preset);
+
+		let genesis = if patch.is_empty() {
+			None
+		} else {
+			let mut genesis = self.genesis_config_preset(preset)?;
+			patch.apply(self, &mut genesis)?;
+			GenesisConfigBuilderRuntimeCaller::<()>::new(wasm)
+				.get_storage_for_patch(genesis.clone())
+				.map_err(|e| format!("Patched genesis config of '{preset}' is invalid: {e}"))?;
+			Some(genesis)
+		};
+
+		Ok(match self.kind {
+			RuntimeKind::Relay { .. } => Box::new(
+				self.builder(RelayChainSpec::builder(wasm, Default::default()), preset, genesis)?
+					.with_protocol_id(DEFAULT_PROTOCOL_ID)
+					.build(),
+			),
+			RuntimeKind::Parachain { relay_chain, para_id, .. } => {
+				let relay_chain = match chain_type {
+					ChainType::Live => relay_chain.to_string(),
+					_ => format!("{relay_chain}-local"),
+				};
+				let para_id = patch.para_id.unwrap_or(para_id);
+				Box::new(
+					self.builder(
+						SystemParachainChainSpec::builder(
+							wasm,
+							Extensions { relay_chain, para_id },
+						),
+						preset,
+						genesis,
+					)?
+					.build(),
+				)
+			},
+		})
+	}
+
+	/// The genesis config patch of `preset`, as exposed by the runtime.
+	fn genesis_config_preset(&self, preset: &str) -> Result<serde_json::Value, String> {
+		let json = (self.get_preset)(&PresetId::from(preset))
+			.ok_or_else(|| format!("Preset '{preset}' not available for {}", self.name))?;
+		serde_json::from_slice(&json)
+			.map_err(|e| format!("Preset '{preset}' is not valid json: {e}"))
+	}
+
+	/// Applies everything common to relay chain and parachain specs to `builder`.
+	///
+	/// The genesis config is referenced by the `preset` name, unless a patched `genesis` is given.
+	fn builder<E>(
+		&self,
+		builder: ChainSpecBuilder<E>,
+		preset: &str,
+		genesis: Option<serde_json::Value>,
+	) -> Result<ChainSpecBuilder<E>, String> {
+		let chain_type = self.chain_type(preset);
+		let boot_nodes = match chain_type {
+			ChainType::Live => self
+				.boot_nodes
+				.iter()
+				.map(|addr| {
+					use std::str::FromStr;
+					sc_network::config::MultiaddrWithPeerId::from_str(addr)
+						.map_err(|e| format!("Boot node address '{addr}' is incorrect: {e}"))
+				})
+				.collect::<Result<Vec<_>, _>>()?,
+			_ => Vec::new(),
+		};
+
+		let builder = builder
+			.with_name(&self.chain_name(preset))
+			.with_id(&self.chain_id(preset))
+			.with_chain_type(chain_type)
+			.with_properties(self.properties())
+			.with_boot_nodes(boot_nodes);
+		Ok(match genesis {
+			Some(genesis) => builder.with_genesis_config_patch(genesis),
+			None => builder.with_genesis_config_preset_name(preset),
+		})
+	}
+}
+
+/// All runtimes this binary was built with.
+pub fn runtimes() -> Vec<Runtime> {
+	use crate::{relay_chain_specs as relay, system_parachains_specs as para};
+
+	vec![
+		#[cfg(feature = "polkadot")]
+		relay::polkadot(),
+		#[cfg(feature = "kusama")]
+		relay::kusama(),
+		#[cfg(feature = "asset-hub-polkadot")]
+		para::asset_hub_polkadot(),
+		#[cfg(feature = "asset-hub-kusama")]
+		para::asset_hub_kusama(),
+		#[cfg(feature = "collectives-polkadot")]
+		para::collectives_polkadot(),
+		#[cfg(feature = "bridge-hub-polkadot")]
+		para::bridge_hub_polkadot(),
+		#[cfg(feature = "bridge-hub-kusama")]
+		para::bridge_hub_kusama(),
+		#[cfg(feature = "glutton-kusama")]
+		para::glutton_kusama(),
+		#[cfg(feature = "encointer-kusama")]
+		para::encointer_kusama(),
+		#[cfg(feature = "coretime-kusama")]
+		para::coretime_kusama(),
+		#[cfg(feature = "coretime-polkadot")]
+		para::coretime_polkadot(),
+		#[cfg(feature = "people-kusama")]
+		para::people_kusama(),
+		#[cfg(feature = "people-polkadot")]
+		para::people_polkadot(),
+	]
+}
+
+/// All supported chain spec ids, mapped to the runtime and preset they are generated from.
+///
+/// If two presets of a runtime map to the same chain spec id, the first one listed by the
+/// runtime wins.
+pub fn supported_chains() -> BTreeMap<String, (Runtime, String)> {
+	let mut chains = BTreeMap::new();
+	for runtime in runtimes() {
+		for preset in (runtime.preset_names)() {
+			let preset: &str = preset.as_ref();
+			chains
+				.entry(runtime.chain_id(preset))
+				.or_insert_with(|| (runtime, preset.to_string()));
+		}
+	}
+	chains
+}
 
 #[derive(Debug, serde::Deserialize)]
 struct EmptyChainSpecWithId {
 	id: String,
 }
 
+/// Loads a chain spec from a json file, using the runtime whose id prefixes the chain spec id.
 pub fn from_json_file(filepath: &str, supported: String) -> Result<Box<dyn ChainSpec>, String> {
 	let path = std::path::PathBuf::from(&filepath);
 	let file = std::fs::File::open(filepath).expect("Failed to open file");
 	let reader = std::io::BufReader::new(file);
 	let chain_spec: EmptyChainSpecWithId = serde_json::from_reader(reader)
 		.expect("Failed to read 'json' file with ChainSpec configuration");
-	match &chain_spec.id {
-		x if x.starts_with("polkadot") | x.starts_with("dot") =>
-			Ok(Box::new(PolkadotChainSpec::from_json_file(path)?)),
-		x if x.starts_with("kusama") | x.starts_with("ksm") =>
-			Ok(Box::new(KusamaChainSpec::from_json_file(path)?)),
-		x if x.starts_with("asset-hub-polkadot") =>
-			Ok(Box::new(AssetHubPolkadotChainSpec::from_json_file(path)?)),
-		x if x.starts_with("asset-hub-kusama") =>
-			Ok(Box::new(AssetHubKusamaChainSpec::from_json_file(path)?)),
-		x if x.starts_with("collectives-polkadot") =>
-			Ok(Box::new(CollectivesPolkadotChainSpec::from_json_file(path)?)),
-		x if x.starts_with("bridge-hub-polkadot") =>
-			Ok(Box::new(BridgeHubPolkadotChainSpec::from_json_file(path)?)),
-		x if x.starts_with("bridge-hub-kusama") =>
-			Ok(Box::new(BridgeHubKusamaChainSpec::from_json_file(path)?)),
-		x if x.starts_with("coretime-kusama") =>
-			Ok(Box::new(CoretimeKusamaChainSpec::from_json_file(path)?)),
-		x if x.starts_with("coretime-polkadot") =>
-			Ok(Box::new(CoretimePolkadotChainSpec::from_json_file(path)?)),
-		x if x.starts_with("glutton-kusama") =>
-			Ok(Box::new(GluttonKusamaChainSpec::from_json_file(path)?)),
-		x if x.starts_with("encointer-kusama") =>
-			Ok(Box::new(EncointerKusamaChainSpec::from_json_file(path)?)),
-		x if x.starts_with("people-kusama") =>
-			Ok(Box::new(PeopleKusamaChainSpec::from_json_file(path)?)),
-		x if x.starts_with("people-polkadot") =>
-			Ok(Box::new(PeoplePolkadotChainSpec::from_json_file(path)?)),
-		_ => Err(format!("Unknown chain 'id' in json file. Only supported: {supported}'")),
+
+	// Prefer the longest matching prefix, so that e.g. `polkadot` never shadows a runtime whose id
+	// happens to start with it.
+	let runtime = runtimes()
+		.into_iter()
+		.filter_map(|runtime| {
+			core::iter::once(runtime.id)
+				.chain(runtime.id_aliases.iter().copied())
+				.filter(|prefix| chain_spec.id.starts_with(prefix))
+				.map(str::len)
+				.max()
+				.map(|len| (len, runtime))
+		})
+		.max_by_key(|(len, _)| *len)
+		.map(|(_, runtime)| runtime)
+		.ok_or_else(|| format!("Unknown chain 'id' in json file. Only supported: {supported}'"))?;
+
+	Ok(match runtime.kind {
+		RuntimeKind::Relay { .. } => Box::new(RelayChainSpec::from_json_file(path)?),
+		RuntimeKind::Parachain { .. } => Box::new(SystemParachainChainSpec::from_json_file(path)?),
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn previously_supported_chains_are_still_generated() {
+		let expected: &[&str] = &[
+			#[cfg(feature = "polkadot")]
+			"polkadot-dev",
+			#[cfg(feature = "polkadot")]
+			"polkadot-local",
+			#[cfg(feature = "kusama")]
+			"kusama-dev",
+			#[cfg(feature = "kusama")]
+			"kusama-local",
+			#[cfg(feature = "asset-hub-kusama")]
+			"asset-hub-kusama-local",
+			#[cfg(feature = "asset-hub-polkadot")]
+			"asset-hub-polkadot-local",
+			#[cfg(feature = "collectives-polkadot")]
+			"collectives-polkadot-local",
+			#[cfg(feature = "bridge-hub-polkadot")]
+			"bridge-hub-polkadot-local",
+			#[cfg(feature = "bridge-hub-kusama")]
+			"bridge-hub-kusama-local",
+			#[cfg(feature = "glutton-kusama")]
+			"glutton-kusama-local",
+			#[cfg(feature = "encointer-kusama")]
+			"encointer-kusama-local",
+			#[cfg(feature = "coretime-kusama")]
+			"coretime-kusama",
+			#[cfg(feature = "coretime-kusama")]
+			"coretime-kusama-local",
+			#[cfg(feature = "coretime-polkadot")]
+			"coretime-polkadot",
+			#[cfg(feature = "coretime-polkadot")]
+			"coretime-polkadot-local",
+			#[cfg(feature = "people-kusama")]
+			"people-kusama-local",
+			#[cfg(feature = "people-polkadot")]
+			"people-polkadot-local",
+		];
+
+		let supported = supported_chains();
+		for id in expected {
+			assert!(supported.contains_key(*id), "chain '{id}' is no longer supported");
+		}
 	}
 }
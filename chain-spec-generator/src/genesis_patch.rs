@@ -0,0 +1,342 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Typed patches applied on top of the genesis config preset of a runtime.
+
+use crate::common::{Runtime, RuntimeKind};
+use serde_json::{Map, Value};
+use sp_core::{
+	crypto::{AccountId32, Ss58Codec},
+	ed25519, sr25519, Pair,
+};
+use std::str::FromStr;
+
+/// The key type of the Aura authorities of a system parachain.
+#[derive(Clone, Copy, Debug)]
+pub enum AuraKey {
+	Sr25519,
+	Ed25519,
+}
+
+/// A key given either as a dev seed (`Alice`, `//Alice//stash`) or as a public key (ss58 or
+/// `0x`-prefixed hex).
+#[derive(Clone, Debug)]
+pub enum Key {
+	Seed(String),
+	Public([u8; 32]),
+}
+
+impl FromStr for Key {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		if s.starts_with("0x") {
+			let bytes =
+				sp_core::bytes::from_hex(s).map_err(|e| format!("Invalid hex '{s}': {e}"))?;
+			let public =
+				bytes.try_into().map_err(|_| format!("Public key '{s}' is not 32 bytes long"))?;
+			return Ok(Key::Public(public))
+		}
+		if let Ok(account) = AccountId32::from_ss58check(s) {
+			return Ok(Key::Public(account.into()))
+		}
+		let seed = if s.starts_with("//") { s.to_string() } else { format!("//{s}") };
+		sr25519::Pair::from_string(&seed, None)
+			.map_err(|e| format!("Invalid seed '{s}': {e:?}"))?;
+		Ok(Key::Seed(seed))
+	}
+}
+
+impl Key {
+	/// The account id, derived as sr25519 key from a seed.
+	pub fn account(&self) -> AccountId32 {
+		match self {
+			Key::Seed(seed) => AccountId32::new(Self::sr25519(seed).0),
+			Key::Public(public) => AccountId32::new(*public),
+		}
+	}
+
+	/// The Aura authority key, derived with the key type of the runtime from a seed.
+	pub fn aura(&self, key: AuraKey) -> AccountId32 {
+		match (self, key) {
+			(Key::Seed(seed), AuraKey::Sr25519) => AccountId32::new(Self::sr25519(seed).0),
+			(Key::Seed(seed), AuraKey::Ed25519) => AccountId32::new(
+				ed25519::Pair::from_string(seed, None)
+					.expect("Checked on parsing; qed")
+					.public()
+					.0,
+			),
+			(Key::Public(public), _) => AccountId32::new(*public),
+		}
+	}
+
+	fn sr25519(seed: &str) -> sr25519::Public {
+		sr25519::Pair::from_string(seed, None)
+			.expect("Checked on parsing; qed")
+			.public()
+	}
+}
+
+/// An endowment given as `<account>=<amount>`.
+#[derive(Clone, Debug)]
+pub struct Endowment {
+	pub account: Key,
+	pub amount: u128,
+}
+
+impl FromStr for Endowment {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (account, amount) = s
+			.split_once('=')
+			.ok_or_else(|| format!("Expected '<account>=<amount>', got '{s}'"))?;
+		Ok(Endowment {
+			account: account.parse()?,
+			amount: amount.parse().map_err(|e| format!("Invalid amount '{amount}': {e}"))?,
+		})
+	}
+}
+
+/// Typed changes applied on top of the genesis config preset of a runtime.
+#[derive(clap::Args, Clone, Debug, Default)]
+pub struct GenesisPatch {
+	/// Endow an account with the given amount of plancks, replacing any endowment of the
+	/// preset. The account is a dev seed, an ss58 address or a hex public key.
+	#[arg(long = "endow", value_name = "ACCOUNT=AMOUNT")]
+	pub endowments: Vec<Endowment>,
+
+	/// Replace the invulnerable collators (and their session keys) of a system parachain. Can
+	/// be given multiple times, as dev seed, ss58 address or hex public key.
+	#[arg(long = "invulnerable", value_name = "SEED|PUBKEY")]
+	pub invulnerables: Vec<Key>,
+
+	/// Set the para id of a system parachain.
+	#[arg(long)]
+	pub para_id: Option<u32>,
+
+	/// Replace the validator set of a relay chain with `N` dev validators.
+	#[arg(long, value_name = "N")]
+	pub validators: Option<u32>,
+}
+
+impl GenesisPatch {
+	/// Whether no change was requested.
+	pub fn is_empty(&self) -> bool {
+		self.endowments.is_empty() &&
+			self.invulnerables.is_empty() &&
+			self.para_id.is_none() &&
+			self.validators.is_none()
+	}
+
+	/// Applies all requested changes to the genesis config `genesis` of `runtime`.
+	pub fn apply(&self, runtime: &Runtime, genesis: &mut Value) -> Result<(), String> {
+		if let Some(count) = self.validators {
+			let RuntimeKind::Relay { validators_genesis } = runtime.kind else {
+				return Err(format!("--validators is not supported by {}", runtime.id))
+			};
+			if count == 0 {
+				return Err("--validators needs at least one validator".into())
+			}
+			let patch = validators_genesis(count);
+			for pallet in ["session", "staking"] {
+				let section = section(genesis, pallet)?;
+				if let Some(Value::Object(fields)) = patch.get(pallet) {
+					section.extend(fields.clone());
+				}
+			}
+			let endowments = patch["balances"]["balances"].as_array().cloned().unwrap_or_default();
+			for endowment in endowments {
+				let [account, amount] = endowment.as_array().map(Vec::as_slice).unwrap_or_default()
+				else {
+					return Err(format!("Unexpected endowment in validators genesis: {endowment}"))
+				};
+				endow(genesis, account_from_json(account)?, amount.clone())?;
+			}
+		}
+
+		if !self.invulnerables.is_empty() {
+			let RuntimeKind::Parachain { aura: Some(aura), .. } = runtime.kind else {
+				return Err(format!("--invulnerable is not supported by {}", runtime.id))
+			};
+			let invulnerables: Vec<_> = self
+				.invulnerables
+				.iter()
+				.map(|key| (json(&key.account()), json(&key.aura(aura))))
+				.collect();
+			section(genesis, "collatorSelection")?.insert(
+				"invulnerables".into(),
+				invulnerables.iter().map(|(account, _)| account.clone()).collect(),
+			);
+			section(genesis, "session")?.insert(
+				"keys".into(),
+				invulnerables
+					.into_iter()
+					.map(
+						|(account, aura)| serde_json::json!([account.clone(), account, { "aura": aura }]),
+					)
+					.collect(),
+			);
+		}
+
+		if let Some(para_id) = self.para_id {
+			let RuntimeKind::Parachain { .. } = runtime.kind else {
+				return Err(format!("--para-id is not supported by {}", runtime.id))
+			};
+			section(genesis, "parachainInfo")?.insert("parachainId".into(), para_id.into());
+		}
+
+		for Endowment { account, amount } in &self.endowments {
+			let amount = serde_json::to_value(amount)
+				.map_err(|e| format!("Amount {amount} can not be represented: {e}"))?;
+			endow(genesis, account.account(), amount)?;
+		}
+
+		Ok(())
+	}
+}
+
+/// The genesis config section of `pallet`, created if the preset does not contain it.
+fn section<'a>(genesis: &'a mut Value, pallet: &str) -> Result<&'a mut Map<String, Value>, String> {
+	genesis
+		.as_object_mut()
+		.ok_or("Genesis config is not a json object")?
+		.entry(pallet)
+		.or_insert_with(|| Value::Object(Map::new()))
+		.as_object_mut()
+		.ok_or_else(|| format!("Genesis config of '{pallet}' is not a json object"))
+}
+
+/// Sets the endowment of `account`, replacing an existing endowment of the same account.
+fn endow(genesis: &mut Value, account: AccountId32, amount: Value) -> Result<(), String> {
+	let balances = section(genesis, "balances")?
+		.entry("balances")
+		.or_insert_with(|| Value::Array(Vec::new()))
+		.as_array_mut()
+		.ok_or("Genesis config of 'balances.balances' is not a json array")?;
+
+	let existing = balances.iter_mut().find(|endowment| {
+		endowment
+			.get(0)
+			.and_then(|existing| account_from_json(existing).ok())
+			.is_some_and(|existing| existing == account)
+	});
+	match existing {
+		Some(endowment) => endowment[1] = amount,
+		None => balances.push(serde_json::json!([json(&account), amount])),
+	}
+	Ok(())
+}
+
+fn account_from_json(account: &Value) -> Result<AccountId32, String> {
+	serde_json::from_value(account.clone()).map_err(|e| format!("Invalid account {account}: {e}"))
+}
+
+fn json(account: &AccountId32) -> Value {
+	Value::String(account.to_ss58check())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn keys_are_parsed() {
+		let alice = sr25519::Pair::from_string("//Alice", None).unwrap().public();
+		let alice_account = AccountId32::new(alice.0);
+
+		for input in ["Alice", "//Alice"] {
+			let key: Key = input.parse().unwrap();
+			assert_eq!(key.account(), alice_account);
+			assert_eq!(key.aura(AuraKey::Sr25519), alice_account);
+			assert_ne!(key.aura(AuraKey::Ed25519), alice_account);
+		}
+		for input in [
+			alice_account.to_ss58check(),
+			format!("0x{}", sp_core::hexdisplay::HexDisplay::from(&alice.0)),
+		] {
+			let key: Key = input.parse().unwrap();
+			assert_eq!(key.account(), alice_account);
+			assert_eq!(key.aura(AuraKey::Ed25519), alice_account);
+		}
+		assert!("0x1234".parse::<Key>().is_err());
+	}
+
+	#[test]
+	fn endowments_are_upserted() {
+		let alice: Key = "Alice".parse().unwrap();
+		let mut genesis = serde_json::json!({
+			"balances": { "balances": [[json(&alice.account()), 1]] },
+		});
+
+		let patch = GenesisPatch {
+			endowments: vec!["Alice=5".parse().unwrap(), "Bob=7".parse().unwrap()],
+			..Default::default()
+		};
+		patch.apply(&test_runtime(), &mut genesis).unwrap();
+
+		let bob: Key = "Bob".parse().unwrap();
+		assert_eq!(
+			genesis["balances"]["balances"],
+			serde_json::json!([[json(&alice.account()), 5], [json(&bob.account()), 7]])
+		);
+		assert!("Alice".parse::<Endowment>().is_err());
+		assert!("Alice=-1".parse::<Endowment>().is_err());
+	}
+
+	#[test]
+	fn parachain_patches_are_applied() {
+		let mut genesis = serde_json::json!({ "parachainInfo": { "parachainId": 1000 } });
+		let patch = GenesisPatch {
+			invulnerables: vec!["Charlie".parse().unwrap()],
+			para_id: Some(2000),
+			..Default::default()
+		};
+		patch.apply(&test_runtime(), &mut genesis).unwrap();
+
+		let charlie = json(&"Charlie".parse::<Key>().unwrap().account());
+		assert_eq!(genesis["parachainInfo"]["parachainId"], 2000);
+		assert_eq!(genesis["collatorSelection"]["invulnerables"], serde_json::json!([charlie]));
+		assert_eq!(
+			genesis["session"]["keys"],
+			serde_json::json!([[charlie.clone(), charlie.clone(), { "aura": charlie }]])
+		);
+
+		// Relay chain only.
+		let patch = GenesisPatch { validators: Some(2), ..Default::default() };
+		assert!(patch.apply(&test_runtime(), &mut genesis).is_err());
+	}
+
+	fn test_runtime() -> Runtime {
+		Runtime {
+			id: "test-parachain",
+			id_aliases: &[],
+			name: "Test Parachain",
+			kind: RuntimeKind::Parachain {
+				relay_chain: "polkadot",
+				para_id: 1000,
+				aura: Some(AuraKey::Sr25519),
+			},
+			wasm: None,
+			preset_names: Vec::new,
+			get_preset: |_| None,
+			ss58_format: 0,
+			token: None,
+			boot_nodes: &[],
+		}
+	}
+}
@@ -15,102 +15,48 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
+use crate::common::{Runtime, RuntimeKind};
 use sc_chain_spec::NoExtension;
-#[cfg(any(feature = "polkadot", feature = "kusama"))]
-use sc_chain_spec::{ChainSpec, ChainType};
 
-pub type PolkadotChainSpec = sc_chain_spec::GenericChainSpec<NoExtension>;
+pub type RelayChainSpec = sc_chain_spec::GenericChainSpec<NoExtension>;
 
-pub type KusamaChainSpec = sc_chain_spec::GenericChainSpec<NoExtension>;
+/// The protocol id used by the relay chain specs.
+pub const DEFAULT_PROTOCOL_ID: &str = "dot";
 
-#[cfg(any(feature = "polkadot", feature = "kusama"))]
-const DEFAULT_PROTOCOL_ID: &str = "dot";
-
-/// Returns the properties for the [`PolkadotChainSpec`].
-#[cfg(feature = "polkadot")]
-pub fn polkadot_chain_spec_properties() -> serde_json::map::Map<String, serde_json::Value> {
-	serde_json::json!({
-		"tokenDecimals": 10,
-	})
-	.as_object()
-	.expect("Map given; qed")
-	.clone()
-}
-
-/// Polkadot development config (single validator Alice)
-#[cfg(feature = "polkadot")]
-pub fn polkadot_development_config() -> Result<Box<dyn ChainSpec>, String> {
-	Ok(Box::new(
-		PolkadotChainSpec::builder(
-			polkadot_runtime::WASM_BINARY.ok_or("Polkadot development wasm not available")?,
-			Default::default(),
-		)
-		.with_name("Polakdot Development")
-		.with_id("polkadot-dev")
-		.with_chain_type(ChainType::Development)
-		.with_genesis_config_patch(
-			polkadot_runtime::genesis_config_presets::polkadot_development_config_genesis(),
-		)
-		.with_protocol_id(DEFAULT_PROTOCOL_ID)
-		.with_properties(polkadot_chain_spec_properties())
-		.build(),
-	))
-}
-
-/// Kusama development config (single validator Alice)
-#[cfg(feature = "kusama")]
-pub fn kusama_development_config() -> Result<Box<dyn ChainSpec>, String> {
-	Ok(Box::new(
-		KusamaChainSpec::builder(
-			kusama_runtime::WASM_BINARY.ok_or("Kusama development wasm not available")?,
-			Default::default(),
-		)
-		.with_name("Kusama Development")
-		.with_id("kusama-dev")
-		.with_chain_type(ChainType::Development)
-		.with_genesis_config_patch(
-			kusama_runtime::genesis_config_presets::kusama_development_config_genesis(),
-		)
-		.with_protocol_id(DEFAULT_PROTOCOL_ID)
-		.build(),
-	))
-}
-
-/// Polkadot local testnet config (multivalidator Alice + Bob)
+/// The Polkadot relay chain.
 #[cfg(feature = "polkadot")]
-pub fn polkadot_local_testnet_config() -> Result<Box<dyn ChainSpec>, String> {
-	Ok(Box::new(
-		PolkadotChainSpec::builder(
-			polkadot_runtime::WASM_BINARY.ok_or("Polkadot development wasm not available")?,
-			Default::default(),
-		)
-		.with_name("Polkadot Local Testnet")
-		.with_id("polkadot-local")
-		.with_chain_type(ChainType::Local)
-		.with_genesis_config_patch(
-			polkadot_runtime::genesis_config_presets::polkadot_local_testnet_genesis(),
-		)
-		.with_protocol_id(DEFAULT_PROTOCOL_ID)
-		.with_properties(polkadot_chain_spec_properties())
-		.build(),
-	))
+pub fn polkadot() -> Runtime {
+	Runtime {
+		id: "polkadot",
+		id_aliases: &["dot"],
+		name: "Polkadot",
+		kind: RuntimeKind::Relay {
+			validators_genesis: polkadot_runtime::genesis_config_presets::validators_genesis_patch,
+		},
+		wasm: polkadot_runtime::WASM_BINARY,
+		preset_names: polkadot_runtime::genesis_config_presets::preset_names,
+		get_preset: polkadot_runtime::genesis_config_presets::get_preset,
+		ss58_format: 0,
+		token: Some(("DOT", 10)),
+		boot_nodes: &[],
+	}
 }
 
-/// Kusama local testnet config (multivalidator Alice + Bob)
+/// The Kusama relay chain.
 #[cfg(feature = "kusama")]
-pub fn kusama_local_testnet_config() -> Result<Box<dyn ChainSpec>, String> {
-	Ok(Box::new(
-		KusamaChainSpec::builder(
-			kusama_runtime::WASM_BINARY.ok_or("Kusama development wasm not available")?,
-			Default::default(),
-		)
-		.with_name("Kusama Local Testnet")
-		.with_id("kusama-local")
-		.with_chain_type(ChainType::Local)
-		.with_genesis_config_patch(
-			kusama_runtime::genesis_config_presets::kusama_local_testnet_genesis(),
-		)
-		.with_protocol_id(DEFAULT_PROTOCOL_ID)
-		.build(),
-	))
+pub fn kusama() -> Runtime {
+	Runtime {
+		id: "kusama",
+		id_aliases: &["ksm"],
+		name: "Kusama",
+		kind: RuntimeKind::Relay {
+			validators_genesis: kusama_runtime::genesis_config_presets::validators_genesis_patch,
+		},
+		wasm: kusama_runtime::WASM_BINARY,
+		preset_names: kusama_runtime::genesis_config_presets::preset_names,
+		get_preset: kusama_runtime::genesis_config_presets::get_preset,
+		ss58_format: 2,
+		token: Some(("KSM", 12)),
+		boot_nodes: &[],
+	}
 }
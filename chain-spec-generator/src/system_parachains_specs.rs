@@ -15,6 +15,10 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
+use crate::{
+	common::{Runtime, RuntimeKind},
+	genesis_patch::AuraKey,
+};
 use sc_chain_spec::{ChainSpecExtension, ChainSpecGroup};
 use serde::{Deserialize, Serialize};
 
@@ -28,341 +32,243 @@ pub struct Extensions {
 	pub para_id: u32,
 }
 
-pub type AssetHubPolkadotChainSpec = sc_chain_spec::GenericChainSpec<Extensions>;
-
-pub type AssetHubKusamaChainSpec = sc_chain_spec::GenericChainSpec<Extensions>;
-
-pub type CollectivesPolkadotChainSpec = sc_chain_spec::GenericChainSpec<Extensions>;
-
-pub type BridgeHubPolkadotChainSpec = sc_chain_spec::GenericChainSpec<Extensions>;
-
-pub type BridgeHubKusamaChainSpec = sc_chain_spec::GenericChainSpec<Extensions>;
-
-pub type GluttonKusamaChainSpec = sc_chain_spec::GenericChainSpec<Extensions>;
-
-pub type EncointerKusamaChainSpec = sc_chain_spec::GenericChainSpec<Extensions>;
-
-pub type CoretimeKusamaChainSpec = sc_chain_spec::GenericChainSpec<Extensions>;
-
-pub type CoretimePolkadotChainSpec = sc_chain_spec::GenericChainSpec<Extensions>;
-
-pub type PeopleKusamaChainSpec = sc_chain_spec::GenericChainSpec<Extensions>;
-
-pub type PeoplePolkadotChainSpec = sc_chain_spec::GenericChainSpec<Extensions>;
+pub type SystemParachainChainSpec = sc_chain_spec::GenericChainSpec<Extensions>;
 
+/// The Polkadot Asset Hub system parachain.
 #[cfg(feature = "asset-hub-polkadot")]
-pub fn asset_hub_polkadot_local_testnet_config() -> Result<Box<dyn sc_chain_spec::ChainSpec>, String>
-{
-	let mut properties = sc_chain_spec::Properties::new();
-	properties.insert("ss58Format".into(), 0.into());
-	properties.insert("tokenSymbol".into(), "DOT".into());
-	properties.insert("tokenDecimals".into(), 10.into());
-
-	Ok(Box::new(
-		AssetHubPolkadotChainSpec::builder(
-			asset_hub_polkadot_runtime::WASM_BINARY.expect("AssetHubPolkadot wasm not available!"),
-			Extensions { relay_chain: "polkadot-local".into(), para_id: 1000 },
-		)
-		.with_name("Polkadot Asset Hub Local")
-		.with_id("asset-hub-polkadot-local")
-		.with_chain_type(sc_chain_spec::ChainType::Local)
-		.with_genesis_config_preset_name("local_testnet")
-		.with_properties(properties)
-		.build(),
-	))
+pub fn asset_hub_polkadot() -> Runtime {
+	Runtime {
+		id: "asset-hub-polkadot",
+		id_aliases: &[],
+		name: "Polkadot Asset Hub",
+		kind: RuntimeKind::Parachain {
+			relay_chain: "polkadot",
+			para_id: 1000,
+			aura: Some(AuraKey::Ed25519),
+		},
+		wasm: asset_hub_polkadot_runtime::WASM_BINARY,
+		preset_names: asset_hub_polkadot_runtime::genesis_config_presets::preset_names,
+		get_preset: asset_hub_polkadot_runtime::genesis_config_presets::get_preset,
+		ss58_format: 0,
+		token: Some(("DOT", 10)),
+		boot_nodes: &[],
+	}
 }
 
+/// The Kusama Asset Hub system parachain.
 #[cfg(feature = "asset-hub-kusama")]
-pub fn asset_hub_kusama_local_testnet_config() -> Result<Box<dyn sc_chain_spec::ChainSpec>, String>
-{
-	let mut properties = sc_chain_spec::Properties::new();
-	properties.insert("ss58Format".into(), 2.into());
-	properties.insert("tokenSymbol".into(), "KSM".into());
-	properties.insert("tokenDecimals".into(), 12.into());
-
-	Ok(Box::new(
-		AssetHubKusamaChainSpec::builder(
-			asset_hub_kusama_runtime::WASM_BINARY.expect("AssetHubKusama wasm not available!"),
-			Extensions { relay_chain: "kusama-local".into(), para_id: 1000 },
-		)
-		.with_name("Kusama Asset Hub Local")
-		.with_id("asset-hub-kusama-local")
-		.with_chain_type(sc_chain_spec::ChainType::Local)
-		.with_genesis_config_preset_name("local_testnet")
-		.with_properties(properties)
-		.build(),
-	))
+pub fn asset_hub_kusama() -> Runtime {
+	Runtime {
+		id: "asset-hub-kusama",
+		id_aliases: &[],
+		name: "Kusama Asset Hub",
+		kind: RuntimeKind::Parachain {
+			relay_chain: "kusama",
+			para_id: 1000,
+			aura: Some(AuraKey::Sr25519),
+		},
+		wasm: asset_hub_kusama_runtime::WASM_BINARY,
+		preset_names: asset_hub_kusama_runtime::genesis_config_presets::preset_names,
+		get_preset: asset_hub_kusama_runtime::genesis_config_presets::get_preset,
+		ss58_format: 2,
+		token: Some(("KSM", 12)),
+		boot_nodes: &[],
+	}
 }
 
+/// The Polkadot Collectives system parachain.
 #[cfg(feature = "collectives-polkadot")]
-pub fn collectives_polkadot_local_testnet_config(
-) -> Result<Box<dyn sc_chain_spec::ChainSpec>, String> {
-	let mut properties = sc_chain_spec::Properties::new();
-	properties.insert("ss58Format".into(), 0.into());
-	properties.insert("tokenSymbol".into(), "DOT".into());
-	properties.insert("tokenDecimals".into(), 10.into());
-
-	Ok(Box::new(
-		CollectivesPolkadotChainSpec::builder(
-			collectives_polkadot_runtime::WASM_BINARY
-				.expect("CollectivesPolkadot wasm not available!"),
-			Extensions { relay_chain: "polkadot-local".into(), para_id: 1001 },
-		)
-		.with_name("Polkadot Collectives Local")
-		.with_id("collectives-polkadot-local")
-		.with_chain_type(sc_chain_spec::ChainType::Local)
-		.with_genesis_config_preset_name("local_testnet")
-		.with_properties(properties)
-		.build(),
-	))
+pub fn collectives_polkadot() -> Runtime {
+	Runtime {
+		id: "collectives-polkadot",
+		id_aliases: &[],
+		name: "Polkadot Collectives",
+		kind: RuntimeKind::Parachain {
+			relay_chain: "polkadot",
+			para_id: 1001,
+			aura: Some(AuraKey::Sr25519),
+		},
+		wasm: collectives_polkadot_runtime::WASM_BINARY,
+		preset_names: collectives_polkadot_runtime::genesis_config_presets::preset_names,
+		get_preset: collectives_polkadot_runtime::genesis_config_presets::get_preset,
+		ss58_format: 0,
+		token: Some(("DOT", 10)),
+		boot_nodes: &[],
+	}
 }
 
+/// The Polkadot Bridge Hub system parachain.
 #[cfg(feature = "bridge-hub-polkadot")]
-pub fn bridge_hub_polkadot_local_testnet_config(
-) -> Result<Box<dyn sc_chain_spec::ChainSpec>, String> {
-	let mut properties = sc_chain_spec::Properties::new();
-	properties.insert("ss58Format".into(), 0.into());
-	properties.insert("tokenSymbol".into(), "DOT".into());
-	properties.insert("tokenDecimals".into(), 10.into());
-
-	Ok(Box::new(
-		BridgeHubPolkadotChainSpec::builder(
-			bridge_hub_polkadot_runtime::WASM_BINARY
-				.expect("BridgeHubPolkadot wasm not available!"),
-			Extensions { relay_chain: "polkadot-local".into(), para_id: 1002 },
-		)
-		.with_name("Polkadot Bridge Hub Local")
-		.with_id("bridge-hub-polkadot-local")
-		.with_chain_type(sc_chain_spec::ChainType::Local)
-		.with_genesis_config_preset_name("local_testnet")
-		.with_properties(properties)
-		.build(),
-	))
+pub fn bridge_hub_polkadot() -> Runtime {
+	Runtime {
+		id: "bridge-hub-polkadot",
+		id_aliases: &[],
+		name: "Polkadot Bridge Hub",
+		kind: RuntimeKind::Parachain {
+			relay_chain: "polkadot",
+			para_id: 1002,
+			aura: Some(AuraKey::Sr25519),
+		},
+		wasm: bridge_hub_polkadot_runtime::WASM_BINARY,
+		preset_names: bridge_hub_polkadot_runtime::genesis_config_presets::preset_names,
+		get_preset: bridge_hub_polkadot_runtime::genesis_config_presets::get_preset,
+		ss58_format: 0,
+		token: Some(("DOT", 10)),
+		boot_nodes: &[],
+	}
 }
 
+/// The Kusama Bridge Hub system parachain.
 #[cfg(feature = "bridge-hub-kusama")]
-pub fn bridge_hub_kusama_local_testnet_config() -> Result<Box<dyn sc_chain_spec::ChainSpec>, String>
-{
-	let mut properties = sc_chain_spec::Properties::new();
-	properties.insert("ss58Format".into(), 2.into());
-	properties.insert("tokenSymbol".into(), "KSM".into());
-	properties.insert("tokenDecimals".into(), 12.into());
-
-	Ok(Box::new(
-		BridgeHubKusamaChainSpec::builder(
-			bridge_hub_kusama_runtime::WASM_BINARY.expect("BridgeHubKusama wasm not available!"),
-			Extensions { relay_chain: "kusama-local".into(), para_id: 1002 },
-		)
-		.with_name("Kusama Bridge Hub Local")
-		.with_id("bridge-hub-kusama-local")
-		.with_chain_type(sc_chain_spec::ChainType::Local)
-		.with_genesis_config_preset_name("local_testnet")
-		.with_properties(properties)
-		.build(),
-	))
+pub fn bridge_hub_kusama() -> Runtime {
+	Runtime {
+		id: "bridge-hub-kusama",
+		id_aliases: &[],
+		name: "Kusama Bridge Hub",
+		kind: RuntimeKind::Parachain {
+			relay_chain: "kusama",
+			para_id: 1002,
+			aura: Some(AuraKey::Sr25519),
+		},
+		wasm: bridge_hub_kusama_runtime::WASM_BINARY,
+		preset_names: bridge_hub_kusama_runtime::genesis_config_presets::preset_names,
+		get_preset: bridge_hub_kusama_runtime::genesis_config_presets::get_preset,
+		ss58_format: 2,
+		token: Some(("KSM", 12)),
+		boot_nodes: &[],
+	}
 }
 
+/// The Kusama Glutton system parachain.
 #[cfg(feature = "glutton-kusama")]
-pub fn glutton_kusama_local_testnet_config() -> Result<Box<dyn sc_chain_spec::ChainSpec>, String> {
-	let mut properties = sc_chain_spec::Properties::new();
-	properties.insert("ss58Format".into(), 2.into());
-
-	Ok(Box::new(
-		GluttonKusamaChainSpec::builder(
-			glutton_kusama_runtime::WASM_BINARY.expect("GluttonKusama wasm not available!"),
-			Extensions { relay_chain: "kusama-local".into(), para_id: 1300 },
-		)
-		.with_name("Kusama Glutton Local")
-		.with_id("glutton-kusama-local")
-		.with_chain_type(sc_chain_spec::ChainType::Local)
-		.with_genesis_config_preset_name("local_testnet")
-		.with_properties(properties)
-		.build(),
-	))
+pub fn glutton_kusama() -> Runtime {
+	Runtime {
+		id: "glutton-kusama",
+		id_aliases: &[],
+		name: "Kusama Glutton",
+		kind: RuntimeKind::Parachain { relay_chain: "kusama", para_id: 1300, aura: None },
+		wasm: glutton_kusama_runtime::WASM_BINARY,
+		preset_names: glutton_kusama_runtime::genesis_config_presets::preset_names,
+		get_preset: glutton_kusama_runtime::genesis_config_presets::get_preset,
+		ss58_format: 2,
+		token: None,
+		boot_nodes: &[],
+	}
 }
 
+/// The Kusama Encointer system parachain.
 #[cfg(feature = "encointer-kusama")]
-pub fn encointer_kusama_local_testnet_config() -> Result<Box<dyn sc_chain_spec::ChainSpec>, String>
-{
-	let mut properties = sc_chain_spec::Properties::new();
-	properties.insert("ss58Format".into(), 2.into());
-	properties.insert("tokenSymbol".into(), "KSM".into());
-	properties.insert("tokenDecimals".into(), 12.into());
-
-	Ok(Box::new(
-		EncointerKusamaChainSpec::builder(
-			encointer_kusama_runtime::WASM_BINARY.expect("EncointerKusama wasm not available!"),
-			Extensions { relay_chain: "kusama-local".into(), para_id: 1001 },
-		)
-		.with_name("Kusama Encointer Local")
-		.with_id("encointer-kusama-local")
-		.with_chain_type(sc_chain_spec::ChainType::Local)
-		.with_genesis_config_preset_name("local_testnet")
-		.with_properties(properties)
-		.build(),
-	))
-}
-
-#[cfg(feature = "coretime-kusama")]
-pub fn coretime_kusama_local_testnet_config() -> Result<Box<dyn sc_chain_spec::ChainSpec>, String> {
-	let mut properties = sc_chain_spec::Properties::new();
-	properties.insert("ss58Format".into(), 2.into());
-	properties.insert("tokenSymbol".into(), "KSM".into());
-	properties.insert("tokenDecimals".into(), 12.into());
-
-	Ok(Box::new(
-		CoretimeKusamaChainSpec::builder(
-			coretime_kusama_runtime::WASM_BINARY.expect("CoretimeKusama wasm not available!"),
-			Extensions { relay_chain: "kusama-local".into(), para_id: 1005 },
-		)
-		.with_name("Kusama Coretime Local")
-		.with_id("coretime-kusama-local")
-		.with_chain_type(sc_chain_spec::ChainType::Local)
-		.with_genesis_config_preset_name("local_testnet")
-		.with_properties(properties)
-		.build(),
-	))
+pub fn encointer_kusama() -> Runtime {
+	Runtime {
+		id: "encointer-kusama",
+		id_aliases: &[],
+		name: "Kusama Encointer",
+		kind: RuntimeKind::Parachain {
+			relay_chain: "kusama",
+			para_id: 1001,
+			aura: Some(AuraKey::Sr25519),
+		},
+		wasm: encointer_kusama_runtime::WASM_BINARY,
+		preset_names: encointer_kusama_runtime::genesis_config_presets::preset_names,
+		get_preset: encointer_kusama_runtime::genesis_config_presets::get_preset,
+		ss58_format: 2,
+		token: Some(("KSM", 12)),
+		boot_nodes: &[],
+	}
 }
 
+/// The Kusama Coretime system parachain.
 #[cfg(feature = "coretime-kusama")]
-pub fn coretime_kusama_config() -> Result<Box<dyn sc_chain_spec::ChainSpec>, String> {
-	let mut properties = sc_chain_spec::Properties::new();
-	properties.insert("ss58Format".into(), 2.into());
-	properties.insert("tokenSymbol".into(), "KSM".into());
-	properties.insert("tokenDecimals".into(), 12.into());
-
-	let boot_nodes = [
-		"/dns/kusama-coretime-connect-a-0.polkadot.io/tcp/30334/p2p/12D3KooWR7Biy6nPgQFhk2eYP62pAkcFA6he9RUFURTDh7ewTjpo",
-		"/dns/kusama-coretime-connect-a-1.polkadot.io/tcp/30334/p2p/12D3KooWAGFiMZDF9RxdacrkenzGdo8nhfSe9EXofHc5mHeJ9vGX",
-		"/dns/kusama-coretime-connect-a-0.polkadot.io/tcp/443/wss/p2p/12D3KooWR7Biy6nPgQFhk2eYP62pAkcFA6he9RUFURTDh7ewTjpo",
-		"/dns/kusama-coretime-connect-a-1.polkadot.io/tcp/443/wss/p2p/12D3KooWAGFiMZDF9RxdacrkenzGdo8nhfSe9EXofHc5mHeJ9vGX",
-	];
-
-	Ok(Box::new(
-		CoretimeKusamaChainSpec::builder(
-			coretime_kusama_runtime::WASM_BINARY.expect("Kusama Coretime wasm not available!"),
-			Extensions { relay_chain: "kusama".into(), para_id: 1005 },
-		)
-		.with_name("Kusama Coretime")
-		.with_id("coretime-kusama")
-		.with_chain_type(sc_chain_spec::ChainType::Live)
-		.with_genesis_config_preset_name("live")
-		.with_properties(properties)
-		.with_boot_nodes(
-			boot_nodes
-				.iter()
-				.map(|addr| {
-					use std::str::FromStr;
-					sc_network::config::MultiaddrWithPeerId::from_str(addr)
-						.expect("Boot node address is incorrect.")
-				})
-				.collect(),
-		)
-		.build(),
-	))
-}
-
-#[cfg(feature = "coretime-polkadot")]
-pub fn coretime_polkadot_local_testnet_config() -> Result<Box<dyn sc_chain_spec::ChainSpec>, String>
-{
-	let mut properties = sc_chain_spec::Properties::new();
-	properties.insert("ss58Format".into(), 0.into());
-	properties.insert("tokenSymbol".into(), "DOT".into());
-	properties.insert("tokenDecimals".into(), 10.into());
-
-	Ok(Box::new(
-		CoretimePolkadotChainSpec::builder(
-			coretime_polkadot_runtime::WASM_BINARY.expect("CoretimePolkadot wasm not available!"),
-			Extensions { relay_chain: "polkadot-local".into(), para_id: 1005 },
-		)
-		.with_name("Polkadot Coretime Local")
-		.with_id("coretime-polkadot-local")
-		.with_chain_type(sc_chain_spec::ChainType::Local)
-		.with_genesis_config_preset_name("local_testnet")
-		.with_properties(properties)
-		.build(),
-	))
+pub fn coretime_kusama() -> Runtime {
+	Runtime {
+		id: "coretime-kusama",
+		id_aliases: &[],
+		name: "Kusama Coretime",
+		kind: RuntimeKind::Parachain {
+			relay_chain: "kusama",
+			para_id: 1005,
+			aura: Some(AuraKey::Sr25519),
+		},
+		wasm: coretime_kusama_runtime::WASM_BINARY,
+		preset_names: coretime_kusama_runtime::genesis_config_presets::preset_names,
+		get_preset: coretime_kusama_runtime::genesis_config_presets::get_preset,
+		ss58_format: 2,
+		token: Some(("KSM", 12)),
+		boot_nodes: &[
+			"/dns/kusama-coretime-connect-a-0.polkadot.io/tcp/30334/p2p/12D3KooWR7Biy6nPgQFhk2eYP62pAkcFA6he9RUFURTDh7ewTjpo",
+			"/dns/kusama-coretime-connect-a-1.polkadot.io/tcp/30334/p2p/12D3KooWAGFiMZDF9RxdacrkenzGdo8nhfSe9EXofHc5mHeJ9vGX",
+			"/dns/kusama-coretime-connect-a-0.polkadot.io/tcp/443/wss/p2p/12D3KooWR7Biy6nPgQFhk2eYP62pAkcFA6he9RUFURTDh7ewTjpo",
+			"/dns/kusama-coretime-connect-a-1.polkadot.io/tcp/443/wss/p2p/12D3KooWAGFiMZDF9RxdacrkenzGdo8nhfSe9EXofHc5mHeJ9vGX",
+		],
+	}
 }
 
+/// The Polkadot Coretime system parachain.
 #[cfg(feature = "coretime-polkadot")]
-pub fn coretime_polkadot_config() -> Result<Box<dyn sc_chain_spec::ChainSpec>, String> {
-	let mut properties = sc_chain_spec::Properties::new();
-	properties.insert("ss58Format".into(), 0.into());
-	properties.insert("tokenSymbol".into(), "DOT".into());
-	properties.insert("tokenDecimals".into(), 10.into());
-
-	let boot_nodes = [
-		"/dns/polkadot-coretime-connect-a-0.polkadot.io/tcp/30334/p2p/12D3KooWKjnixAHbKMsPTJwGx8SrBeGEJLHA8KmKcEDYMp3YmWgR",
-		"/dns/polkadot-coretime-connect-a-1.polkadot.io/tcp/30334/p2p/12D3KooWQ7B7p4DFv1jWqaKfhrZBcMmi5g8bWFnmskguLaGEmT6n",
-		"/dns/polkadot-coretime-connect-a-0.polkadot.io/tcp/443/wss/p2p/12D3KooWKjnixAHbKMsPTJwGx8SrBeGEJLHA8KmKcEDYMp3YmWgR",
-		"/dns/polkadot-coretime-connect-a-1.polkadot.io/tcp/443/wss/p2p/12D3KooWQ7B7p4DFv1jWqaKfhrZBcMmi5g8bWFnmskguLaGEmT6n",
-		"/dns4/coretime-polkadot.boot.stake.plus/tcp/30332/wss/p2p/12D3KooWFJ2yBTKFKYwgKUjfY3F7XfaxHV8hY6fbJu5oMkpP7wZ9",
-		"/dns4/coretime-polkadot.boot.stake.plus/tcp/31332/wss/p2p/12D3KooWCy5pToLafcQzPHn5kadxAftmF6Eh8ZJGPXhSeXSUDfjv",
-
-	];
-
-	Ok(Box::new(
-		CoretimePolkadotChainSpec::builder(
-			coretime_polkadot_runtime::WASM_BINARY.expect("Polkadot Coretime wasm not available!"),
-			Extensions { relay_chain: "polkadot".into(), para_id: 1005 },
-		)
-		.with_name("Polkadot Coretime")
-		.with_id("coretime-polkadot")
-		.with_chain_type(sc_chain_spec::ChainType::Live)
-		.with_genesis_config_preset_name("live")
-		.with_properties(properties)
-		.with_boot_nodes(
-			boot_nodes
-				.iter()
-				.map(|addr| {
-					use std::str::FromStr;
-					sc_network::config::MultiaddrWithPeerId::from_str(addr)
-						.expect("Boot node address is incorrect.")
-				})
-				.collect(),
-		)
-		.build(),
-	))
+pub fn coretime_polkadot() -> Runtime {
+	Runtime {
+		id: "coretime-polkadot",
+		id_aliases: &[],
+		name: "Polkadot Coretime",
+		kind: RuntimeKind::Parachain {
+			relay_chain: "polkadot",
+			para_id: 1005,
+			aura: Some(AuraKey::Sr25519),
+		},
+		wasm: coretime_polkadot_runtime::WASM_BINARY,
+		preset_names: coretime_polkadot_runtime::genesis_config_presets::preset_names,
+		get_preset: coretime_polkadot_runtime::genesis_config_presets::get_preset,
+		ss58_format: 0,
+		token: Some(("DOT", 10)),
+		boot_nodes: &[
+			"/dns/polkadot-coretime-connect-a-0.polkadot.io/tcp/30334/p2p/12D3KooWKjnixAHbKMsPTJwGx8SrBeGEJLHA8KmKcEDYMp3YmWgR",
+			"/dns/polkadot-coretime-connect-a-1.polkadot.io/tcp/30334/p2p/12D3KooWQ7B7p4DFv1jWqaKfhrZBcMmi5g8bWFnmskguLaGEmT6n",
+			"/dns/polkadot-coretime-connect-a-0.polkadot.io/tcp/443/wss/p2p/12D3KooWKjnixAHbKMsPTJwGx8SrBeGEJLHA8KmKcEDYMp3YmWgR",
+			"/dns/polkadot-coretime-connect-a-1.polkadot.io/tcp/443/wss/p2p/12D3KooWQ7B7p4DFv1jWqaKfhrZBcMmi5g8bWFnmskguLaGEmT6n",
+			"/dns4/coretime-polkadot.boot.stake.plus/tcp/30332/wss/p2p/12D3KooWFJ2yBTKFKYwgKUjfY3F7XfaxHV8hY6fbJu5oMkpP7wZ9",
+			"/dns4/coretime-polkadot.boot.stake.plus/tcp/31332/wss/p2p/12D3KooWCy5pToLafcQzPHn5kadxAftmF6Eh8ZJGPXhSeXSUDfjv",
+		],
+	}
 }
 
+/// The Kusama People system parachain.
 #[cfg(feature = "people-kusama")]
-pub fn people_kusama_local_testnet_config() -> Result<Box<dyn sc_chain_spec::ChainSpec>, String> {
-	let mut properties = sc_chain_spec::Properties::new();
-	properties.insert("ss58Format".into(), 2.into());
-	properties.insert("tokenSymbol".into(), "KSM".into());
-	properties.insert("tokenDecimals".into(), 12.into());
-
-	Ok(Box::new(
-		PeopleKusamaChainSpec::builder(
-			people_kusama_runtime::WASM_BINARY.expect("PeopleKusama wasm not available!"),
-			Extensions { relay_chain: "kusama-local".into(), para_id: 1004 },
-		)
-		.with_name("Kusama People Local")
-		.with_id("people-kusama-local")
-		.with_chain_type(sc_chain_spec::ChainType::Local)
-		.with_genesis_config_preset_name("local_testnet")
-		.with_properties(properties)
-		.build(),
-	))
+pub fn people_kusama() -> Runtime {
+	Runtime {
+		id: "people-kusama",
+		id_aliases: &[],
+		name: "Kusama People",
+		kind: RuntimeKind::Parachain {
+			relay_chain: "kusama",
+			para_id: 1004,
+			aura: Some(AuraKey::Sr25519),
+		},
+		wasm: people_kusama_runtime::WASM_BINARY,
+		preset_names: people_kusama_runtime::genesis_config_presets::preset_names,
+		get_preset: people_kusama_runtime::genesis_config_presets::get_preset,
+		ss58_format: 2,
+		token: Some(("KSM", 12)),
+		boot_nodes: &[],
+	}
 }
 
+/// The Polkadot People system parachain.
 #[cfg(feature = "people-polkadot")]
-pub fn people_polkadot_local_testnet_config() -> Result<Box<dyn sc_chain_spec::ChainSpec>, String> {
-	let mut properties = sc_chain_spec::Properties::new();
-	properties.insert("ss58Format".into(), 0.into());
-	properties.insert("tokenSymbol".into(), "DOT".into());
-	properties.insert("tokenDecimals".into(), 10.into());
-
-	Ok(Box::new(
-		PeoplePolkadotChainSpec::builder(
-			people_polkadot_runtime::WASM_BINARY.expect("PeoplePolkadot wasm not available!"),
-			Extensions { relay_chain: "polkadot-local".into(), para_id: 1004 },
-		)
-		.with_name("Polkadot People Local")
-		.with_id("people-polkadot-local")
-		.with_chain_type(sc_chain_spec::ChainType::Local)
-		.with_genesis_config_preset_name("local_testnet")
-		.with_properties(properties)
-		.build(),
-	))
+pub fn people_polkadot() -> Runtime {
+	Runtime {
+		id: "people-polkadot",
+		id_aliases: &[],
+		name: "Polkadot People",
+		kind: RuntimeKind::Parachain {
+			relay_chain: "polkadot",
+			para_id: 1004,
+			aura: Some(AuraKey::Sr25519),
+		},
+		wasm: people_polkadot_runtime::WASM_BINARY,
+		preset_names: people_polkadot_runtime::genesis_config_presets::preset_names,
+		get_preset: people_polkadot_runtime::genesis_config_presets::get_preset,
+		ss58_format: 0,
+		token: Some(("DOT", 10)),
+		boot_nodes: &[],
+	}
 }
@@ -48,6 +48,11 @@ decl_test_parachains! {
 			ForeignAssets: asset_hub_polkadot_runtime::ForeignAssets,
 			PoolAssets: asset_hub_polkadot_runtime::PoolAssets,
 			AssetConversion: asset_hub_polkadot_runtime::AssetConversion,
+			Nfts: asset_hub_polkadot_runtime::Nfts,
+			NftFractionalization: asset_hub_polkadot_runtime::NftFractionalization,
+			Revive: asset_hub_polkadot_runtime::Revive,
+			RemoteProxyRelayChain: asset_hub_polkadot_runtime::RemoteProxyRelayChain,
+			RelayStateOracle: asset_hub_polkadot_runtime::RelayStateOracle,
 		}
 	},
 }
@@ -42,6 +42,7 @@ decl_test_parachains! {
 			PolkadotXcm: people_kusama_runtime::PolkadotXcm,
 			Balances: people_kusama_runtime::Balances,
 			Identity: people_kusama_runtime::Identity,
+			Recovery: people_kusama_runtime::Recovery,
 		}
 	},
 }
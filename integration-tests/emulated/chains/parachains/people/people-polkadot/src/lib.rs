@@ -42,6 +42,7 @@ decl_test_parachains! {
 			PolkadotXcm: people_polkadot_runtime::PolkadotXcm,
 			Balances: people_polkadot_runtime::Balances,
 			Identity: people_polkadot_runtime::Identity,
+			Recovery: people_polkadot_runtime::Recovery,
 		}
 	},
 }
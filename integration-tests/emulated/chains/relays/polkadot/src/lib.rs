@@ -39,6 +39,7 @@ decl_test_relay_chains! {
 			Treasury: polkadot_runtime::Treasury,
 			AssetRate: polkadot_runtime::AssetRate,
 			Hrmp: polkadot_runtime::Hrmp,
+			Proxy: polkadot_runtime::Proxy,
 		}
 	},
 }
@@ -17,7 +17,10 @@ mod aliases;
 mod claim_assets;
 mod fellowship_treasury;
 mod hybrid_transfers;
+mod nft_fractionalization;
+mod remote_proxy;
 mod reserve_transfer;
+mod revive;
 mod send;
 mod set_xcm_versions;
 mod swap;
@@ -0,0 +1,107 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for fractionalizing NFTs into fungible assets via `pallet_nft_fractionalization`.
+
+use crate::*;
+
+type AssetHubRuntime = <AssetHubPolkadot as Chain>::Runtime;
+type AssetHubOrigin = <AssetHubPolkadot as Chain>::RuntimeOrigin;
+type AssetHubEvent = <AssetHubPolkadot as Chain>::RuntimeEvent;
+type Assets = <AssetHubPolkadot as AssetHubPolkadotPallet>::Assets;
+type Nfts = <AssetHubPolkadot as AssetHubPolkadotPallet>::Nfts;
+type NftFractionalization = <AssetHubPolkadot as AssetHubPolkadotPallet>::NftFractionalization;
+
+#[test]
+fn fractionalize_and_unify_nft_works() {
+	let owner = AssetHubPolkadotSender::get();
+	let asset_id = 7777;
+	let fractions = 1_000;
+
+	AssetHubPolkadot::execute_with(|| {
+		let collection =
+			pallet_nfts::NextCollectionId::<AssetHubRuntime>::get().unwrap_or_default();
+		let item = 0;
+
+		assert_ok!(Nfts::create(
+			AssetHubOrigin::signed(owner.clone()),
+			owner.clone().into(),
+			pallet_nfts::CollectionConfig {
+				settings: pallet_nfts::CollectionSettings::all_enabled(),
+				max_supply: None,
+				mint_settings: pallet_nfts::MintSettings::default(),
+			},
+		));
+		assert_ok!(Nfts::mint(
+			AssetHubOrigin::signed(owner.clone()),
+			collection,
+			item,
+			owner.clone().into(),
+			None,
+		));
+
+		assert_ok!(NftFractionalization::fractionalize(
+			AssetHubOrigin::signed(owner.clone()),
+			collection,
+			item,
+			asset_id,
+			owner.clone().into(),
+			fractions,
+		));
+		assert_expected_events!(
+			AssetHubPolkadot,
+			vec![
+				AssetHubEvent::NftFractionalization(
+					pallet_nft_fractionalization::Event::NftFractionalized { .. }
+				) => {},
+			]
+		);
+		assert_eq!(<Assets as Inspect<_>>::balance(asset_id, &owner), fractions);
+
+		// The NFT is locked while it is fractionalized.
+		assert_err!(
+			Nfts::transfer(
+				AssetHubOrigin::signed(owner.clone()),
+				collection,
+				item,
+				AssetHubPolkadotReceiver::get().into(),
+			),
+			pallet_nfts::Error::<AssetHubRuntime>::ItemLocked
+		);
+
+		assert_ok!(NftFractionalization::unify(
+			AssetHubOrigin::signed(owner.clone()),
+			collection,
+			item,
+			asset_id,
+			owner.clone().into(),
+		));
+		assert_expected_events!(
+			AssetHubPolkadot,
+			vec![
+				AssetHubEvent::NftFractionalization(
+					pallet_nft_fractionalization::Event::NftUnified { .. }
+				) => {},
+			]
+		);
+		assert!(!<Assets as Inspect<_>>::asset_exists(asset_id));
+		assert_ok!(Nfts::transfer(
+			AssetHubOrigin::signed(owner.clone()),
+			collection,
+			item,
+			AssetHubPolkadotReceiver::get().into(),
+		));
+	});
+}
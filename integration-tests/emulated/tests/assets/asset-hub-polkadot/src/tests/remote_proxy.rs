@@ -0,0 +1,332 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for using Polkadot relay chain proxies on Asset Hub via `pallet_remote_proxy`.
+
+use crate::*;
+use codec::Decode;
+use cumulus_pallet_parachain_system::OnSystemEvent;
+use emulated_integration_tests_common::accounts::{ALICE, BOB, CHARLIE};
+use frame_support::traits::fungible::Inspect as FungibleInspect;
+use pallet_remote_proxy::{PersistedValidationData, RemoteProxyProof, RemotePureProxy};
+use parachains_common::{BlockNumber, Hash};
+use polkadot_runtime_constants::proxy::ProxyType as RelayProxyType;
+use sp_runtime::traits::{BlakeTwo256, Hash as _};
+use sp_trie::TrieMut;
+
+/// Add a proxy with the given `delay` for `real` on the relay chain and prove its proxy
+/// definitions.
+///
+/// Returns the `proof` together with the relay chain block and storage root it is anchored at.
+fn add_relay_proxy_and_prove(
+	real: &AccountId,
+	delegate: &AccountId,
+	proxy_type: RelayProxyType,
+	delay: BlockNumber,
+) -> (Vec<Vec<u8>>, BlockNumber, Hash) {
+	let (key, value, block) = Polkadot::execute_with(|| {
+		type Proxy = <Polkadot as PolkadotPallet>::Proxy;
+		type Runtime = <Polkadot as Chain>::Runtime;
+
+		// The relay chain wraps the proxy type, but shares the encoding with the constants.
+		let proxy_type = Decode::decode(&mut &proxy_type.encode()[..]).unwrap();
+		assert_ok!(Proxy::add_proxy(
+			<Polkadot as Chain>::RuntimeOrigin::signed(real.clone()),
+			delegate.clone().into(),
+			proxy_type,
+			delay,
+		));
+
+		let key = pallet_proxy::Proxies::<Runtime>::hashed_key_for(real);
+		let value = frame_support::storage::unhashed::get_raw(&key).unwrap();
+		(key, value, frame_system::Pallet::<Runtime>::block_number())
+	});
+
+	// Only the proxy definitions are required to be part of the trie to create a valid proof.
+	let (mut db, mut root) = sp_trie::MemoryDB::<BlakeTwo256>::default_with_root();
+	let mut trie =
+		sp_trie::TrieDBMutBuilder::<sp_trie::LayoutV1<_>>::new(&mut db, &mut root).build();
+	trie.insert(&key, &value).unwrap();
+	drop(trie);
+
+	(db.drain().into_values().map(|d| d.0).collect(), block, root)
+}
+
+/// Create a pure proxy spawned by `spawner` on the relay chain and prove its proxy definitions.
+///
+/// Returns the parameters identifying the pure proxy together with the `proof` and the relay
+/// chain block and storage root it is anchored at.
+fn create_relay_pure_proxy_and_prove(
+	spawner: &AccountId,
+) -> (RemotePureProxy<AccountId, RelayProxyType, BlockNumber>, Vec<Vec<u8>>, BlockNumber, Hash) {
+	let (pure, key, value, block) = Polkadot::execute_with(|| {
+		type Proxy = <Polkadot as PolkadotPallet>::Proxy;
+		type Runtime = <Polkadot as Chain>::Runtime;
+
+		let proxy_type = Decode::decode(&mut &RelayProxyType::Any.encode()[..]).unwrap();
+		assert_ok!(Proxy::create_pure(
+			<Polkadot as Chain>::RuntimeOrigin::signed(spawner.clone()),
+			proxy_type,
+			0,
+			0,
+		));
+
+		let block = frame_system::Pallet::<Runtime>::block_number();
+		let pure = RemotePureProxy {
+			spawner: spawner.clone(),
+			proxy_type: RelayProxyType::Any,
+			index: 0,
+			height: block,
+			ext_index: frame_system::Pallet::<Runtime>::extrinsic_index().unwrap_or_default(),
+		};
+		let pure_account = Proxy::pure_account(spawner, &proxy_type, 0, None);
+
+		let key = pallet_proxy::Proxies::<Runtime>::hashed_key_for(pure_account);
+		let value = frame_support::storage::unhashed::get_raw(&key).unwrap();
+		(pure, key, value, block)
+	});
+
+	let (mut db, mut root) = sp_trie::MemoryDB::<BlakeTwo256>::default_with_root();
+	let mut trie =
+		sp_trie::TrieDBMutBuilder::<sp_trie::LayoutV1<_>>::new(&mut db, &mut root).build();
+	trie.insert(&key, &value).unwrap();
+	drop(trie);
+
+	(pure, db.drain().into_values().map(|d| d.0).collect(), block, root)
+}
+
+/// Make the relay chain storage `root` at `block` known to the relay state oracle.
+fn note_relay_storage_root(block: BlockNumber, root: Hash) {
+	type RelayStateOracle = <AssetHubPolkadot as AssetHubPolkadotPallet>::RelayStateOracle;
+
+	RelayStateOracle::on_validation_data(&PersistedValidationData {
+		parent_head: vec![].into(),
+		relay_parent_number: block,
+		relay_parent_storage_root: root,
+		max_pov_size: 5_000_000,
+	});
+}
+
+#[test]
+fn relay_chain_proxy_can_be_used_on_asset_hub() {
+	let alice = AssetHubPolkadot::account_id_of(ALICE);
+	let bob = AssetHubPolkadot::account_id_of(BOB);
+	let charlie = AssetHubPolkadot::account_id_of(CHARLIE);
+	let amount = ASSET_HUB_POLKADOT_ED * 10;
+
+	let (proof, block, root) = add_relay_proxy_and_prove(&alice, &bob, RelayProxyType::Any, 0);
+
+	AssetHubPolkadot::execute_with(|| {
+		type RuntimeEvent = <AssetHubPolkadot as Chain>::RuntimeEvent;
+		type RuntimeCall = <AssetHubPolkadot as Chain>::RuntimeCall;
+		type Balances = <AssetHubPolkadot as AssetHubPolkadotPallet>::Balances;
+		type RemoteProxyRelayChain =
+			<AssetHubPolkadot as AssetHubPolkadotPallet>::RemoteProxyRelayChain;
+
+		note_relay_storage_root(block, root);
+
+		let charlie_balance_before = <Balances as FungibleInspect<_>>::balance(&charlie);
+		let call = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+			dest: charlie.clone().into(),
+			value: amount,
+		});
+
+		assert_ok!(RemoteProxyRelayChain::remote_proxy(
+			<AssetHubPolkadot as Chain>::RuntimeOrigin::signed(bob),
+			alice.into(),
+			None,
+			Box::new(call),
+			RemoteProxyProof::RelayChain { proof, block },
+		));
+
+		assert_expected_events!(
+			AssetHubPolkadot,
+			vec![
+				RuntimeEvent::Proxy(pallet_proxy::Event::ProxyExecuted { result: Ok(()) }) => {},
+			]
+		);
+		assert_eq!(
+			<Balances as FungibleInspect<_>>::balance(&charlie),
+			charlie_balance_before + amount
+		);
+	});
+}
+
+#[test]
+fn unsupported_relay_chain_proxy_type_is_rejected_on_asset_hub() {
+	let alice = AssetHubPolkadot::account_id_of(ALICE);
+	let bob = AssetHubPolkadot::account_id_of(BOB);
+
+	// `Staking` proxies have no equivalent on Asset Hub and are filtered by the converter.
+	let (proof, block, root) = add_relay_proxy_and_prove(&alice, &bob, RelayProxyType::Staking, 0);
+
+	AssetHubPolkadot::execute_with(|| {
+		type Runtime = <AssetHubPolkadot as Chain>::Runtime;
+		type RuntimeCall = <AssetHubPolkadot as Chain>::RuntimeCall;
+		type RemoteProxyRelayChain =
+			<AssetHubPolkadot as AssetHubPolkadotPallet>::RemoteProxyRelayChain;
+
+		note_relay_storage_root(block, root);
+
+		let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+
+		assert_err!(
+			RemoteProxyRelayChain::remote_proxy(
+				<AssetHubPolkadot as Chain>::RuntimeOrigin::signed(bob),
+				alice.into(),
+				None,
+				Box::new(call),
+				RemoteProxyProof::RelayChain { proof, block },
+			),
+			pallet_remote_proxy::Error::<Runtime>::DidNotFindMatchingProxyDefinition
+		);
+	});
+}
+
+#[test]
+fn delayed_relay_chain_proxy_requires_announcement_on_asset_hub() {
+	let alice = AssetHubPolkadot::account_id_of(ALICE);
+	let bob = AssetHubPolkadot::account_id_of(BOB);
+	let charlie = AssetHubPolkadot::account_id_of(CHARLIE);
+	let amount = ASSET_HUB_POLKADOT_ED * 10;
+	let delay = 10;
+
+	let (proof, block, root) = add_relay_proxy_and_prove(&alice, &bob, RelayProxyType::Any, delay);
+
+	AssetHubPolkadot::execute_with(|| {
+		type Runtime = <AssetHubPolkadot as Chain>::Runtime;
+		type RuntimeEvent = <AssetHubPolkadot as Chain>::RuntimeEvent;
+		type RuntimeCall = <AssetHubPolkadot as Chain>::RuntimeCall;
+		type RuntimeOrigin = <AssetHubPolkadot as Chain>::RuntimeOrigin;
+		type Balances = <AssetHubPolkadot as AssetHubPolkadotPallet>::Balances;
+		type RemoteProxyRelayChain =
+			<AssetHubPolkadot as AssetHubPolkadotPallet>::RemoteProxyRelayChain;
+
+		note_relay_storage_root(block, root);
+
+		let call = Box::new(RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+			dest: charlie.clone().into(),
+			value: amount,
+		}));
+		let call_hash = BlakeTwo256::hash_of(&call);
+		let proof = RemoteProxyProof::RelayChain { proof, block };
+
+		assert_err!(
+			RemoteProxyRelayChain::remote_proxy(
+				RuntimeOrigin::signed(bob.clone()),
+				alice.clone().into(),
+				None,
+				call.clone(),
+				proof.clone(),
+			),
+			pallet_remote_proxy::Error::<Runtime>::Unannounced
+		);
+
+		assert_ok!(RemoteProxyRelayChain::announce(
+			RuntimeOrigin::signed(bob.clone()),
+			alice.clone().into(),
+			call_hash,
+			proof.clone(),
+		));
+
+		assert_err!(
+			RemoteProxyRelayChain::remote_proxy_announced(
+				RuntimeOrigin::signed(bob.clone()),
+				bob.clone().into(),
+				alice.clone().into(),
+				None,
+				call.clone(),
+				proof.clone(),
+			),
+			pallet_remote_proxy::Error::<Runtime>::Unannounced
+		);
+
+		// Relay chain and Asset Hub share the same block time, so the delay is kept as is.
+		let now = frame_system::Pallet::<Runtime>::block_number();
+		frame_system::Pallet::<Runtime>::set_block_number(now + delay);
+
+		let charlie_balance_before = <Balances as FungibleInspect<_>>::balance(&charlie);
+		assert_ok!(RemoteProxyRelayChain::remote_proxy_announced(
+			RuntimeOrigin::signed(bob.clone()),
+			bob.into(),
+			alice.into(),
+			None,
+			call,
+			proof,
+		));
+
+		assert_expected_events!(
+			AssetHubPolkadot,
+			vec![
+				RuntimeEvent::Proxy(pallet_proxy::Event::ProxyExecuted { result: Ok(()) }) => {},
+			]
+		);
+		assert_eq!(
+			<Balances as FungibleInspect<_>>::balance(&charlie),
+			charlie_balance_before + amount
+		);
+	});
+}
+
+#[test]
+fn relay_chain_pure_proxy_can_be_used_on_asset_hub() {
+	let alice = AssetHubPolkadot::account_id_of(ALICE);
+	let charlie = AssetHubPolkadot::account_id_of(CHARLIE);
+	let amount = ASSET_HUB_POLKADOT_ED * 10;
+
+	let (pure, proof, block, root) = create_relay_pure_proxy_and_prove(&alice);
+
+	AssetHubPolkadot::execute_with(|| {
+		type RuntimeEvent = <AssetHubPolkadot as Chain>::RuntimeEvent;
+		type RuntimeCall = <AssetHubPolkadot as Chain>::RuntimeCall;
+		type Balances = <AssetHubPolkadot as AssetHubPolkadotPallet>::Balances;
+		type RemoteProxyRelayChain =
+			<AssetHubPolkadot as AssetHubPolkadotPallet>::RemoteProxyRelayChain;
+
+		note_relay_storage_root(block, root);
+
+		// The pure proxy has the same account on Asset Hub.
+		let pure_account = RemoteProxyRelayChain::remote_pure_account(pure.clone()).unwrap();
+		assert_ok!(<Balances as frame_support::traits::fungible::Mutate<_>>::mint_into(
+			&pure_account,
+			amount * 2,
+		));
+
+		let charlie_balance_before = <Balances as FungibleInspect<_>>::balance(&charlie);
+		let call = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+			dest: charlie.clone().into(),
+			value: amount,
+		});
+
+		assert_ok!(RemoteProxyRelayChain::remote_pure_proxy(
+			<AssetHubPolkadot as Chain>::RuntimeOrigin::signed(alice),
+			pure,
+			None,
+			Box::new(call),
+			RemoteProxyProof::RelayChain { proof, block },
+		));
+
+		assert_expected_events!(
+			AssetHubPolkadot,
+			vec![
+				RuntimeEvent::Proxy(pallet_proxy::Event::ProxyExecuted { result: Ok(()) }) => {},
+			]
+		);
+		assert_eq!(
+			<Balances as FungibleInspect<_>>::balance(&charlie),
+			charlie_balance_before + amount
+		);
+	});
+}
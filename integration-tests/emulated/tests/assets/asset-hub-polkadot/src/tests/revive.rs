@@ -0,0 +1,66 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for smart contracts on Asset Hub Polkadot via `pallet_revive`.
+
+use crate::*;
+use frame_support::traits::fungible::Inspect as FungibleInspect;
+use pallet_revive::AddressMapper;
+use sp_core::H160;
+
+type AssetHubRuntime = <AssetHubPolkadot as Chain>::Runtime;
+type AssetHubOrigin = <AssetHubPolkadot as Chain>::RuntimeOrigin;
+type Balances = <AssetHubPolkadot as AssetHubPolkadotPallet>::Balances;
+type Revive = <AssetHubPolkadot as AssetHubPolkadotPallet>::Revive;
+
+#[test]
+fn transfer_to_eth_address_works() {
+	let sender = AssetHubPolkadotSender::get();
+	let dest = H160::repeat_byte(0x42);
+	let amount = ASSET_HUB_POLKADOT_ED * 100;
+
+	AssetHubPolkadot::execute_with(|| {
+		let dest_account =
+			<AssetHubRuntime as pallet_revive::Config>::AddressMapper::to_account_id(&dest);
+		assert_eq!(<Balances as FungibleInspect<_>>::balance(&dest_account), 0);
+
+		// Accounts need to be mapped to an address before they can interact with contracts.
+		assert_err!(
+			Revive::call(
+				AssetHubOrigin::signed(sender.clone()),
+				dest,
+				amount,
+				Weight::from_parts(1_000_000_000, 100_000),
+				u128::MAX,
+				vec![],
+			)
+			.map_err(|e| e.error),
+			pallet_revive::Error::<AssetHubRuntime>::AccountUnmapped
+		);
+		assert_ok!(Revive::map_account(AssetHubOrigin::signed(sender.clone())));
+		assert!(<AssetHubRuntime as pallet_revive::Config>::AddressMapper::is_mapped(&sender));
+
+		// Calling an address without a contract transfers the value to it.
+		assert_ok!(Revive::call(
+			AssetHubOrigin::signed(sender.clone()),
+			dest,
+			amount,
+			Weight::from_parts(1_000_000_000, 100_000),
+			u128::MAX,
+			vec![],
+		));
+		assert_eq!(<Balances as FungibleInspect<_>>::balance(&dest_account), amount);
+	});
+}
@@ -14,7 +14,7 @@
 // limitations under the License.
 
 use crate::*;
-use frame_support::traits::OnInitialize;
+use frame_support::traits::{Get, OnInitialize};
 use kusama_runtime::Dmp;
 use kusama_runtime_constants::system_parachain::coretime::TIMESLICE_PERIOD;
 use pallet_broker::{ConfigRecord, Configuration, CoreAssignment, CoreMask, ScheduleItem};
@@ -28,12 +28,16 @@ fn transact_hardcoded_weights_are_sane() {
 	//   extrinsics.
 	// - Request revenue info - triggered when each timeslice is committed.
 	// - Assign core - triggered when an entry is encountered in the workplan for the next
-	//   timeslice.
+	//   timeslice. The worst case assignment of 80 tasks used below is split into several
+	//   `assign_core_part` calls, which must all be processed for the core to be assigned.
+	// The fourth one, credit account, is triggered by `purchase_credit` and covered by
+	// `purchase_credit_credits_account_on_relay`.
 
 	// RuntimeEvent aliases to avoid warning from usage of qualified paths in assertions due to
 	// <https://github.com/rust-lang/rust/issues/86935>
 	type CoretimeEvent = <CoretimeKusama as Chain>::RuntimeEvent;
 	type RelayEvent = <Kusama as Chain>::RuntimeEvent;
+	type KusamaRuntime = <Kusama as Chain>::Runtime;
 
 	Kusama::execute_with(|| {
 		Dmp::make_parachain_reachable(CoretimeKusama::para_id());
@@ -184,11 +188,16 @@ fn transact_hardcoded_weights_are_sane() {
 		);
 	});
 
-	// Check that the assign_core and request_revenue_info_at messages were processed successfully.
-	// This will fail if the weights are misconfigured.
+	// Check that the assign_core_part and request_revenue_info_at messages were processed
+	// successfully and that all 80 tasks made it to the relay chain. This will fail if the weights
+	// are misconfigured.
 	Kusama::execute_with(|| {
 		Kusama::assert_ump_queue_processed(true, Some(CoretimeKusama::para_id()), None);
 
+		assert!(pallet_coretime_assignment_buffer::PendingAssignments::<KusamaRuntime>::iter()
+			.next()
+			.is_none());
+
 		assert_expected_events!(
 			Kusama,
 			vec![
@@ -198,8 +207,12 @@ fn transact_hardcoded_weights_are_sane() {
 				RelayEvent::MessageQueue(
 					pallet_message_queue::Event::Processed { success: true, .. }
 				) => {},
-				RelayEvent::Coretime(
-					runtime_parachains::coretime::Event::CoreAssigned { .. }
+				RelayEvent::CoretimeAssignmentBuffer(
+					pallet_coretime_assignment_buffer::Event::CoreAssigned {
+						core: 0,
+						assignments: 80,
+						..
+					}
 				) => {},
 			]
 		);
@@ -226,3 +239,62 @@ fn transact_hardcoded_weights_are_sane() {
 		);
 	});
 }
+
+#[test]
+fn purchase_credit_credits_account_on_relay() {
+	// RuntimeEvent aliases to avoid warning from usage of qualified paths in assertions due to
+	// <https://github.com/rust-lang/rust/issues/86935>
+	type CoretimeEvent = <CoretimeKusama as Chain>::RuntimeEvent;
+	type CoretimeRuntime = <CoretimeKusama as Chain>::Runtime;
+	type KusamaRuntime = <Kusama as Chain>::Runtime;
+
+	Kusama::execute_with(|| {
+		Dmp::make_parachain_reachable(CoretimeKusama::para_id());
+	});
+
+	let buyer = CoretimeKusamaSender::get();
+	let beneficiary = KusamaReceiver::get();
+	let amount = <CoretimeRuntime as pallet_broker::Config>::MinimumCreditPurchase::get() * 10;
+
+	Kusama::execute_with(|| {
+		assert_eq!(runtime_parachains::on_demand::Credits::<KusamaRuntime>::get(&beneficiary), 0);
+	});
+
+	CoretimeKusama::execute_with(|| {
+		let buyer_balance_before =
+			<CoretimeKusama as CoretimeKusamaPallet>::Balances::free_balance(&buyer);
+
+		assert_ok!(<CoretimeKusama as CoretimeKusamaPallet>::Broker::purchase_credit(
+			<CoretimeKusama as Chain>::RuntimeOrigin::signed(buyer.clone()),
+			amount,
+			beneficiary.clone(),
+		));
+
+		assert_eq!(
+			<CoretimeKusama as CoretimeKusamaPallet>::Balances::free_balance(&buyer),
+			buyer_balance_before - amount
+		);
+		assert_expected_events!(
+			CoretimeKusama,
+			vec![
+				CoretimeEvent::Broker(
+					pallet_broker::Event::CreditPurchased { .. }
+				) => {},
+				CoretimeEvent::ParachainSystem(
+					cumulus_pallet_parachain_system::Event::UpwardMessageSent { .. }
+				) => {},
+			]
+		);
+	});
+
+	// The `credit_account` transact is executed on the relay and credits the beneficiary. This
+	// will fail if the hardcoded weight is misconfigured.
+	Kusama::execute_with(|| {
+		Kusama::assert_ump_queue_processed(true, Some(CoretimeKusama::para_id()), None);
+
+		assert_eq!(
+			runtime_parachains::on_demand::Credits::<KusamaRuntime>::get(&beneficiary),
+			amount
+		);
+	});
+}
@@ -14,7 +14,7 @@
 // limitations under the License.
 
 use crate::*;
-use frame_support::traits::OnInitialize;
+use frame_support::traits::{Get, OnInitialize};
 use pallet_broker::{ConfigRecord, Configuration, CoreAssignment, CoreMask, ScheduleItem};
 use polkadot_runtime::Dmp;
 use polkadot_runtime_constants::system_parachain::coretime::TIMESLICE_PERIOD;
@@ -28,12 +28,16 @@ fn transact_hardcoded_weights_are_sane() {
 	//   extrinsics.
 	// - Request revenue info - triggered when each timeslice is committed.
 	// - Assign core - triggered when an entry is encountered in the workplan for the next
-	//   timeslice.
+	//   timeslice. The worst case assignment of 80 tasks used below is split into several
+	//   `assign_core_part` calls, which must all be processed for the core to be assigned.
+	// The fourth one, credit account, is triggered by `purchase_credit` and covered by
+	// `purchase_credit_credits_account_on_relay`.
 
 	// RuntimeEvent aliases to avoid warning from usage of qualified paths in assertions due to
 	// <https://github.com/rust-lang/rust/issues/86935>
 	type CoretimeEvent = <CoretimePolkadot as Chain>::RuntimeEvent;
 	type RelayEvent = <Polkadot as Chain>::RuntimeEvent;
+	type PolkadotRuntime = <Polkadot as Chain>::Runtime;
 
 	Polkadot::execute_with(|| {
 		Dmp::make_parachain_reachable(CoretimePolkadot::para_id());
@@ -184,11 +188,16 @@ fn transact_hardcoded_weights_are_sane() {
 		);
 	});
 
-	// Check that the assign_core and request_revenue_info_at messages were processed successfully.
-	// This will fail if the weights are misconfigured.
+	// Check that the assign_core_part and request_revenue_info_at messages were processed
+	// successfully and that all 80 tasks made it to the relay chain. This will fail if the weights
+	// are misconfigured.
 	Polkadot::execute_with(|| {
 		Polkadot::assert_ump_queue_processed(true, Some(CoretimePolkadot::para_id()), None);
 
+		assert!(pallet_coretime_assignment_buffer::PendingAssignments::<PolkadotRuntime>::iter()
+			.next()
+			.is_none());
+
 		assert_expected_events!(
 			Polkadot,
 			vec![
@@ -198,8 +207,12 @@ fn transact_hardcoded_weights_are_sane() {
 				RelayEvent::MessageQueue(
 					pallet_message_queue::Event::Processed { success: true, .. }
 				) => {},
-				RelayEvent::Coretime(
-					runtime_parachains::coretime::Event::CoreAssigned { .. }
+				RelayEvent::CoretimeAssignmentBuffer(
+					pallet_coretime_assignment_buffer::Event::CoreAssigned {
+						core: 0,
+						assignments: 80,
+						..
+					}
 				) => {},
 			]
 		);
@@ -226,3 +239,64 @@ fn transact_hardcoded_weights_are_sane() {
 		);
 	});
 }
+
+#[test]
+fn purchase_credit_credits_account_on_relay() {
+	// RuntimeEvent aliases to avoid warning from usage of qualified paths in assertions due to
+	// <https://github.com/rust-lang/rust/issues/86935>
+	type CoretimeEvent = <CoretimePolkadot as Chain>::RuntimeEvent;
+	type CoretimeRuntime = <CoretimePolkadot as Chain>::Runtime;
+	type PolkadotRuntime = <Polkadot as Chain>::Runtime;
+
+	Polkadot::execute_with(|| {
+		Dmp::make_parachain_reachable(CoretimePolkadot::para_id());
+	});
+
+	let buyer = CoretimePolkadotSender::get();
+	let beneficiary = PolkadotReceiver::get();
+	let amount =
+		<<CoretimePolkadot as Chain>::Runtime as pallet_broker::Config>::MinimumCreditPurchase::get(
+		) * 10;
+
+	Polkadot::execute_with(|| {
+		assert_eq!(runtime_parachains::on_demand::Credits::<PolkadotRuntime>::get(&beneficiary), 0);
+	});
+
+	CoretimePolkadot::execute_with(|| {
+		let buyer_balance_before =
+			<CoretimePolkadot as CoretimePolkadotPallet>::Balances::free_balance(&buyer);
+
+		assert_ok!(<CoretimePolkadot as CoretimePolkadotPallet>::Broker::purchase_credit(
+			<CoretimePolkadot as Chain>::RuntimeOrigin::signed(buyer.clone()),
+			amount,
+			beneficiary.clone(),
+		));
+
+		assert_eq!(
+			<CoretimePolkadot as CoretimePolkadotPallet>::Balances::free_balance(&buyer),
+			buyer_balance_before - amount
+		);
+		assert_expected_events!(
+			CoretimePolkadot,
+			vec![
+				CoretimeEvent::Broker(
+					pallet_broker::Event::CreditPurchased { .. }
+				) => {},
+				CoretimeEvent::ParachainSystem(
+					cumulus_pallet_parachain_system::Event::UpwardMessageSent { .. }
+				) => {},
+			]
+		);
+	});
+
+	// The `credit_account` transact is executed on the relay and credits the beneficiary. This
+	// will fail if the hardcoded weight is misconfigured.
+	Polkadot::execute_with(|| {
+		Polkadot::assert_ump_queue_processed(true, Some(CoretimePolkadot::para_id()), None);
+
+		assert_eq!(
+			runtime_parachains::on_demand::Credits::<PolkadotRuntime>::get(&beneficiary),
+			amount
+		);
+	});
+}
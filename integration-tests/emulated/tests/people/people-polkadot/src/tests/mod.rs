@@ -15,4 +15,5 @@
 
 mod claim_assets;
 mod governance;
+mod recovery;
 mod teleport;
@@ -0,0 +1,182 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use emulated_integration_tests_common::accounts::{ALICE, BOB, CHARLIE, DAVE};
+use frame_support::{
+	sp_runtime::traits::{BlakeTwo256, Dispatchable, Hash},
+	traits::{fungible::Inspect as FungibleInspect, ConstBool, Contains, Get},
+};
+use pallet_identity::{Data, Judgement};
+use pallet_recovery::WeightInfo as _;
+use people_polkadot_runtime::{
+	people::IdentityInfo,
+	recovery::{RecoveryFriendsFilter, RecoveryFriendsMustBeKnownGood, RecoveryWeightInfo},
+};
+
+type PeopleRuntime = <PeoplePolkadot as Chain>::Runtime;
+type PeopleCall = <PeoplePolkadot as Chain>::RuntimeCall;
+type PeopleOrigin = <PeoplePolkadot as Chain>::RuntimeOrigin;
+type PeopleEvent = <PeoplePolkadot as Chain>::RuntimeEvent;
+type Identity = <PeoplePolkadot as PeoplePolkadotPallet>::Identity;
+type Recovery = <PeoplePolkadot as PeoplePolkadotPallet>::Recovery;
+type Balances = <PeoplePolkadot as PeoplePolkadotPallet>::Balances;
+
+/// The recovery friends filter of the runtime, but enabled.
+type KnownGoodFriends = RecoveryFriendsFilter<ConstBool<true>>;
+
+/// The sorted friends of the lost account.
+fn friends() -> Vec<AccountId> {
+	let mut friends =
+		vec![PeoplePolkadot::account_id_of(BOB), PeoplePolkadot::account_id_of(CHARLIE)];
+	friends.sort();
+	friends
+}
+
+fn create_recovery_call(friends: Vec<AccountId>) -> PeopleCall {
+	PeopleCall::Recovery(pallet_recovery::Call::create_recovery {
+		friends,
+		threshold: 2,
+		delay_period: 0,
+	})
+}
+
+/// Set an identity for `who` and give it a `KnownGood` judgement of the first registrar.
+fn set_known_good_identity(registrar: &AccountId, who: &AccountId) {
+	let info = IdentityInfo {
+		display: Data::Raw(b"friend".to_vec().try_into().unwrap()),
+		..Default::default()
+	};
+	let hash = BlakeTwo256::hash_of(&info);
+
+	assert_ok!(Identity::set_identity(PeopleOrigin::signed(who.clone()), Box::new(info)));
+	assert_ok!(Identity::provide_judgement(
+		PeopleOrigin::signed(registrar.clone()),
+		0,
+		who.clone().into(),
+		Judgement::KnownGood,
+		hash,
+	));
+}
+
+#[test]
+fn full_recovery_works() {
+	let lost = PeoplePolkadot::account_id_of(ALICE);
+	let rescuer = PeoplePolkadot::account_id_of(DAVE);
+
+	PeoplePolkadot::execute_with(|| {
+		let rescuer_balance = Balances::balance(&rescuer);
+
+		assert_ok!(create_recovery_call(friends()).dispatch(PeopleOrigin::signed(lost.clone())));
+		assert_ok!(Recovery::initiate_recovery(
+			PeopleOrigin::signed(rescuer.clone()),
+			lost.clone().into()
+		));
+		for friend in friends() {
+			assert_ok!(Recovery::vouch_recovery(
+				PeopleOrigin::signed(friend),
+				lost.clone().into(),
+				rescuer.clone().into()
+			));
+		}
+		assert_ok!(Recovery::claim_recovery(
+			PeopleOrigin::signed(rescuer.clone()),
+			lost.clone().into()
+		));
+
+		// The rescuer cleans up the recovery of the lost account and takes all of its funds.
+		let calls = [
+			PeopleCall::Recovery(pallet_recovery::Call::close_recovery {
+				rescuer: rescuer.clone().into(),
+			}),
+			PeopleCall::Recovery(pallet_recovery::Call::remove_recovery {}),
+			PeopleCall::Balances(pallet_balances::Call::transfer_all {
+				dest: rescuer.clone().into(),
+				keep_alive: false,
+			}),
+		];
+		for call in calls {
+			assert_ok!(Recovery::as_recovered(
+				PeopleOrigin::signed(rescuer.clone()),
+				lost.clone().into(),
+				Box::new(call)
+			));
+		}
+
+		assert_expected_events!(
+			PeoplePolkadot,
+			vec![
+				PeopleEvent::Recovery(pallet_recovery::Event::RecoveryCreated { .. }) => {},
+				PeopleEvent::Recovery(pallet_recovery::Event::AccountRecovered { .. }) => {},
+				PeopleEvent::Recovery(pallet_recovery::Event::RecoveryClosed { .. }) => {},
+				PeopleEvent::Recovery(pallet_recovery::Event::RecoveryRemoved { .. }) => {},
+			]
+		);
+		assert_eq!(Balances::balance(&lost), 0);
+		assert!(Balances::balance(&rescuer) > rescuer_balance);
+	});
+}
+
+#[test]
+fn recovery_friends_must_be_known_good_if_enabled() {
+	let lost = PeoplePolkadot::account_id_of(ALICE);
+	let registrar = PeoplePolkadot::account_id_of(DAVE);
+
+	PeoplePolkadot::execute_with(|| {
+		assert_ok!(Identity::add_registrar(PeopleOrigin::root(), registrar.clone().into()));
+
+		let create_recovery = create_recovery_call(friends());
+		let nested = PeopleCall::Recovery(pallet_recovery::Call::as_recovered {
+			account: lost.clone().into(),
+			call: Box::new(create_recovery.clone()),
+		});
+
+		// The filter is disabled in the runtime.
+		assert!(RecoveryFriendsFilter::<RecoveryFriendsMustBeKnownGood>::contains(
+			&create_recovery
+		));
+		assert!(<PeopleRuntime as frame_system::Config>::BaseCallFilter::contains(
+			&create_recovery
+		));
+
+		// If enabled, `create_recovery` accounts for one identity read per friend.
+		type RuntimeWeights = <PeopleRuntime as pallet_recovery::Config>::WeightInfo;
+		assert_eq!(
+			RecoveryWeightInfo::<RuntimeWeights, ConstBool<true>>::create_recovery(2),
+			RuntimeWeights::create_recovery(2) +
+				<PeopleRuntime as frame_system::Config>::DbWeight::get().reads(2)
+		);
+
+		// None of the friends has a judgement.
+		assert!(!KnownGoodFriends::contains(&create_recovery));
+		// Calls wrapping it pass, the filter applies once the nested call is dispatched.
+		assert!(KnownGoodFriends::contains(&nested));
+
+		// Only one of the friends has a `KnownGood` judgement.
+		set_known_good_identity(&registrar, &friends()[0]);
+		assert!(!KnownGoodFriends::contains(&create_recovery));
+
+		set_known_good_identity(&registrar, &friends()[1]);
+		assert!(KnownGoodFriends::contains(&create_recovery));
+
+		assert_ok!(create_recovery.dispatch(PeopleOrigin::signed(lost.clone())));
+		assert_expected_events!(
+			PeoplePolkadot,
+			vec![
+				PeopleEvent::Recovery(pallet_recovery::Event::RecoveryCreated { .. }) => {},
+			]
+		);
+	});
+}
@@ -0,0 +1,79 @@
+// Copyright (C) Polkadot Fellows.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Benchmarks for Coretime Assignment Buffer Pallet
+
+use super::*;
+use crate::Pallet as CoretimeAssignmentBuffer;
+use alloc::vec::Vec;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{EnsureOrigin, Get};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+/// Build an assignment with `n` tasks that covers the complete core.
+fn full_assignment(n: u32) -> Vec<(CoreAssignment, PartsOf57600)> {
+	let parts = FULL_CORE / n as PartsOf57600;
+	let mut assignment: Vec<_> =
+		(0..n).map(|task| (CoreAssignment::Task(task + 2000), parts)).collect();
+	if let Some(last) = assignment.last_mut() {
+		last.1 += FULL_CORE - parts * n as PartsOf57600;
+	}
+	assignment
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	/// The worst case is the last part that completes an assignment with
+	/// [`Config::MaxAssignments`] entries, which is then forwarded and removed from a full
+	/// [`ExpiryQueue`].
+	#[benchmark]
+	fn assign_core_part(s: Linear<1, { T::MaxAssignments::get() }>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::AssignOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let core: CoreIndex = 0;
+		let begin: BlockNumberFor<T> = 1u32.into();
+
+		let mut assignment = full_assignment(T::MaxAssignments::get());
+		let part = assignment.split_off(assignment.len() - s as usize);
+		let mut queue = ExpiryQueue::<T>::get();
+		if !assignment.is_empty() {
+			PendingAssignments::<T>::insert(
+				(core, begin),
+				AssignmentOf::<T>::truncate_from(assignment),
+			);
+			queue.try_insert((begin, core)).map_err(|_| BenchmarkError::Weightless)?;
+		}
+		for other in 1..T::MaxPendingAssignments::get() {
+			let _ = queue.try_insert((begin, other as CoreIndex));
+		}
+		ExpiryQueue::<T>::put(queue);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, core, begin, part, None);
+
+		assert!(PendingAssignments::<T>::get((core, begin)).is_none());
+		assert!(!ExpiryQueue::<T>::get().contains(&(begin, core)));
+
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(
+		CoretimeAssignmentBuffer,
+		crate::tests::new_test_ext(),
+		crate::tests::Test
+	);
+}
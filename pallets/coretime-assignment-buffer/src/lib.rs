@@ -0,0 +1,239 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Coretime assignment buffer pallet
+//!
+//! The relay chain only accepts core assignments that cover the complete core, i.e. whose parts
+//! sum up to `57600`, in a single `assign_core` call. The Coretime chain however can produce
+//! assignments with up to 80 entries for pooled or interlaced regions, which do not fit into a
+//! single message. This pallet allows the Coretime chain to send such an assignment in several
+//! parts for the same core and `begin`.
+//!
+//! The parts are buffered in [`PendingAssignments`] in the order they are received. As soon as
+//! the buffered parts cover the complete core, the full assignment is forwarded to
+//! [`Config::AssignCore`] and the buffer is cleared. Parts of assignments that are still
+//! incomplete once their `begin` has passed, e.g. because a message got lost, are dropped in
+//! [`Hooks::on_idle`]. The keys of incomplete assignments are kept ordered by `begin` in
+//! [`ExpiryQueue`], so that only the expired ones are read.
+//!
+//! ## Functions
+//!
+//! - [`Pallet::assign_core_part`]: Buffer a part of the assignment of a core and forward the full
+//!   assignment once it is complete.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+mod weight;
+
+use alloc::vec::Vec;
+use sp_runtime::DispatchResult;
+
+pub use pallet::*;
+pub use pallet_broker::{CoreAssignment, CoreIndex, PartsOf57600};
+pub use weight::WeightInfo;
+
+/// The parts of a core that need to be assigned for the assignment to be complete.
+pub const FULL_CORE: PartsOf57600 = 57_600;
+
+/// Something that assigns a complete core, e.g. the `assigner_coretime` pallet of the relay
+/// chain.
+pub trait AssignCore<BlockNumber> {
+	/// Assign `core` from `begin` on to the given `assignment`.
+	///
+	/// The parts of the `assignment` sum up to [`FULL_CORE`].
+	fn assign_core(
+		core: CoreIndex,
+		begin: BlockNumber,
+		assignment: Vec<(CoreAssignment, PartsOf57600)>,
+		end_hint: Option<BlockNumber>,
+	) -> DispatchResult;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, weights::WeightMeter, BoundedBTreeSet};
+	use frame_system::pallet_prelude::*;
+
+	pub(crate) type AssignmentOf<T> =
+		BoundedVec<(CoreAssignment, PartsOf57600), <T as Config>::MaxAssignments>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The parts of the assignment of a core starting at `begin` received so far.
+	#[pallet::storage]
+	pub type PendingAssignments<T: Config> =
+		StorageMap<_, Twox64Concat, (CoreIndex, BlockNumberFor<T>), AssignmentOf<T>, OptionQuery>;
+
+	/// The `begin` and core of all incomplete assignments in [`PendingAssignments`], ordered by
+	/// `begin`.
+	#[pallet::storage]
+	pub type ExpiryQueue<T: Config> = StorageValue<
+		_,
+		BoundedBTreeSet<(BlockNumberFor<T>, CoreIndex), T::MaxPendingAssignments>,
+		ValueQuery,
+	>;
+
+	/// Configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The origin that is allowed to assign cores, usually the Coretime chain.
+		type AssignOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Where complete assignments are forwarded to.
+		type AssignCore: AssignCore<BlockNumberFor<Self>>;
+
+		/// The maximum number of entries of a single core assignment.
+		#[pallet::constant]
+		type MaxAssignments: Get<u32>;
+
+		/// The maximum number of incomplete assignments that are buffered at the same time.
+		#[pallet::constant]
+		type MaxPendingAssignments: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A part of the assignment of `core` was buffered. `parts` is the total of all buffered
+		/// parts so far.
+		AssignmentPartBuffered { core: CoreIndex, begin: BlockNumberFor<T>, parts: PartsOf57600 },
+		/// The complete assignment of `core` with `assignments` entries was forwarded.
+		CoreAssigned { core: CoreIndex, begin: BlockNumberFor<T>, assignments: u32 },
+		/// The incomplete assignment of `core` was dropped since `begin` has passed. `parts` is
+		/// the total of the parts that were received.
+		AssignmentExpired { core: CoreIndex, begin: BlockNumberFor<T>, parts: PartsOf57600 },
+	}
+
+	#[pallet::error]
+	#[derive(PartialEq)]
+	pub enum Error<T> {
+		/// The assignment part is empty.
+		AssignmentsEmpty,
+		/// The assignment has more than [`Config::MaxAssignments`] entries.
+		TooManyAssignments,
+		/// The parts of the assignment sum up to more than [`FULL_CORE`].
+		OverScheduled,
+		/// There are already [`Config::MaxPendingAssignments`] incomplete assignments.
+		TooManyPendingAssignments,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			let (read, write) = (T::DbWeight::get().reads(1), T::DbWeight::get().writes(1));
+			let remove = read.saturating_add(write);
+
+			// The queue is written back once something expired.
+			if !meter.can_consume(remove) {
+				return meter.consumed()
+			}
+			meter.consume(read);
+			let mut queue = ExpiryQueue::<T>::get();
+
+			let mut expired = false;
+			while let Some(&(begin, core)) = queue.first() {
+				if begin >= now || !meter.can_consume(remove.saturating_add(write)) {
+					break
+				}
+				meter.consume(remove);
+				queue.remove(&(begin, core));
+				expired = true;
+
+				if let Some(assignment) = PendingAssignments::<T>::take((core, begin)) {
+					let parts = assignment.iter().map(|(_, parts)| *parts).sum();
+					Self::deposit_event(Event::AssignmentExpired { core, begin, parts });
+				}
+			}
+
+			if expired {
+				meter.consume(write);
+				ExpiryQueue::<T>::put(queue);
+			}
+
+			meter.consumed()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Buffer a part of the assignment of `core` starting at `begin`.
+		///
+		/// The parts are appended to the ones received before for the same `core` and `begin`.
+		/// Once they sum up to [`FULL_CORE`], the complete assignment is forwarded to
+		/// [`Config::AssignCore`] together with the `end_hint` of the last part.
+		///
+		/// The dispatch origin for this call must be [`Config::AssignOrigin`].
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::assign_core_part(assignment.len() as u32))]
+		pub fn assign_core_part(
+			origin: OriginFor<T>,
+			core: CoreIndex,
+			begin: BlockNumberFor<T>,
+			assignment: Vec<(CoreAssignment, PartsOf57600)>,
+			end_hint: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::AssignOrigin::ensure_origin(origin)?;
+			ensure!(!assignment.is_empty(), Error::<T>::AssignmentsEmpty);
+
+			let mut pending = PendingAssignments::<T>::get((core, begin)).unwrap_or_default();
+			let queued = !pending.is_empty();
+			pending
+				.try_extend(assignment.into_iter())
+				.map_err(|_| Error::<T>::TooManyAssignments)?;
+
+			let parts = pending
+				.iter()
+				.try_fold(0 as PartsOf57600, |sum, (_, parts)| sum.checked_add(*parts))
+				.filter(|parts| *parts <= FULL_CORE)
+				.ok_or(Error::<T>::OverScheduled)?;
+
+			if parts < FULL_CORE {
+				if !queued {
+					ExpiryQueue::<T>::try_mutate(|queue| queue.try_insert((begin, core)))
+						.map_err(|_| Error::<T>::TooManyPendingAssignments)?;
+				}
+				PendingAssignments::<T>::insert((core, begin), pending);
+				Self::deposit_event(Event::AssignmentPartBuffered { core, begin, parts });
+				return Ok(())
+			}
+
+			PendingAssignments::<T>::remove((core, begin));
+			if queued {
+				ExpiryQueue::<T>::mutate(|queue| queue.remove(&(begin, core)));
+			}
+			let assignments = pending.len() as u32;
+			T::AssignCore::assign_core(core, begin, pending.into_inner(), end_hint)?;
+			Self::deposit_event(Event::CoreAssigned { core, begin, assignments });
+
+			Ok(())
+		}
+	}
+}
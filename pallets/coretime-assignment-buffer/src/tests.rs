@@ -0,0 +1,393 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+// Tests for Coretime Assignment Buffer Pallet
+
+use super::*;
+use crate as coretime_assignment_buffer;
+use core::cell::RefCell;
+use frame_support::{
+	assert_noop, assert_ok, construct_runtime, derive_impl,
+	traits::{Get, Hooks},
+	weights::Weight,
+};
+use frame_system::EnsureRoot;
+use sp_core::ConstU32;
+use sp_io::TestExternalities;
+use sp_runtime::{BuildStorage, DispatchError};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+type Assignment = Vec<(CoreAssignment, PartsOf57600)>;
+
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		CoretimeAssignmentBuffer: coretime_assignment_buffer,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type DbWeight = frame_support::weights::constants::RocksDbWeight;
+}
+
+thread_local! {
+	static ASSIGNED: RefCell<Vec<(CoreIndex, u64, Assignment, Option<u64>)>> =
+		RefCell::new(Vec::new());
+}
+
+/// Records the forwarded assignments.
+pub struct RecordAssignCore;
+
+impl AssignCore<u64> for RecordAssignCore {
+	fn assign_core(
+		core: CoreIndex,
+		begin: u64,
+		assignment: Assignment,
+		end_hint: Option<u64>,
+	) -> DispatchResult {
+		ASSIGNED.with(|a| a.borrow_mut().push((core, begin, assignment, end_hint)));
+		Ok(())
+	}
+}
+
+fn assigned() -> Vec<(CoreIndex, u64, Assignment, Option<u64>)> {
+	ASSIGNED.with(|a| a.borrow().clone())
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssignOrigin = EnsureRoot<u64>;
+	type AssignCore = RecordAssignCore;
+	type MaxAssignments = ConstU32<100>;
+	type MaxPendingAssignments = ConstU32<3>;
+	type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// The worst case assignment of the Coretime chain: every of the 80 bits of the core mask is
+/// assigned to a different task.
+fn eighty_tasks() -> Assignment {
+	(0..80).map(|i| (CoreAssignment::Task(2000 + i), FULL_CORE / 80)).collect()
+}
+
+#[test]
+fn complete_assignment_is_forwarded_directly() {
+	new_test_ext().execute_with(|| {
+		let assignment = vec![(CoreAssignment::Pool, FULL_CORE)];
+
+		assert_ok!(CoretimeAssignmentBuffer::assign_core_part(
+			RuntimeOrigin::root(),
+			1,
+			10,
+			assignment.clone(),
+			Some(20),
+		));
+
+		assert_eq!(assigned(), vec![(1, 10, assignment, Some(20))]);
+		assert!(PendingAssignments::<Test>::get((1, 10)).is_none());
+		System::assert_last_event(
+			Event::CoreAssigned { core: 1, begin: 10, assignments: 1 }.into(),
+		);
+	});
+}
+
+#[test]
+fn split_assignment_of_eighty_tasks_survives_intact() {
+	new_test_ext().execute_with(|| {
+		let assignment = eighty_tasks();
+		assert_eq!(assignment.iter().map(|(_, parts)| parts).sum::<PartsOf57600>(), FULL_CORE);
+
+		let mut chunks = assignment.chunks(28).peekable();
+		while let Some(chunk) = chunks.next() {
+			assert_ok!(CoretimeAssignmentBuffer::assign_core_part(
+				RuntimeOrigin::root(),
+				0,
+				10,
+				chunk.to_vec(),
+				None,
+			));
+
+			if chunks.peek().is_some() {
+				assert!(assigned().is_empty());
+			}
+		}
+
+		assert_eq!(assigned(), vec![(0, 10, assignment, None)]);
+		assert!(PendingAssignments::<Test>::get((0, 10)).is_none());
+		System::assert_last_event(
+			Event::CoreAssigned { core: 0, begin: 10, assignments: 80 }.into(),
+		);
+	});
+}
+
+#[test]
+fn parts_are_buffered_per_core_and_begin() {
+	new_test_ext().execute_with(|| {
+		let half = vec![(CoreAssignment::Task(2000), FULL_CORE / 2)];
+		let other_half = vec![(CoreAssignment::Task(2001), FULL_CORE / 2)];
+
+		assert_ok!(CoretimeAssignmentBuffer::assign_core_part(
+			RuntimeOrigin::root(),
+			0,
+			10,
+			half.clone(),
+			None,
+		));
+		System::assert_last_event(
+			Event::AssignmentPartBuffered { core: 0, begin: 10, parts: FULL_CORE / 2 }.into(),
+		);
+		assert_ok!(CoretimeAssignmentBuffer::assign_core_part(
+			RuntimeOrigin::root(),
+			1,
+			10,
+			half.clone(),
+			None,
+		));
+		assert_ok!(CoretimeAssignmentBuffer::assign_core_part(
+			RuntimeOrigin::root(),
+			0,
+			20,
+			half.clone(),
+			None,
+		));
+		assert!(assigned().is_empty());
+
+		assert_ok!(CoretimeAssignmentBuffer::assign_core_part(
+			RuntimeOrigin::root(),
+			1,
+			10,
+			other_half.clone(),
+			Some(30),
+		));
+
+		let mut expected = half.clone();
+		expected.extend(other_half);
+		assert_eq!(assigned(), vec![(1, 10, expected, Some(30))]);
+		assert_eq!(PendingAssignments::<Test>::get((0, 10)).unwrap().into_inner(), half);
+		assert_eq!(PendingAssignments::<Test>::get((0, 20)).unwrap().into_inner(), half);
+		assert!(PendingAssignments::<Test>::get((1, 10)).is_none());
+		assert_eq!(
+			ExpiryQueue::<Test>::get().into_iter().collect::<Vec<_>>(),
+			vec![(10, 0), (20, 0)]
+		);
+	});
+}
+
+#[test]
+fn invalid_parts_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let half = vec![(CoreAssignment::Task(2000), FULL_CORE / 2)];
+
+		assert_noop!(
+			CoretimeAssignmentBuffer::assign_core_part(
+				RuntimeOrigin::signed(1),
+				0,
+				10,
+				half.clone(),
+				None,
+			),
+			DispatchError::BadOrigin,
+		);
+		assert_noop!(
+			CoretimeAssignmentBuffer::assign_core_part(RuntimeOrigin::root(), 0, 10, vec![], None),
+			Error::<Test>::AssignmentsEmpty,
+		);
+
+		assert_ok!(CoretimeAssignmentBuffer::assign_core_part(
+			RuntimeOrigin::root(),
+			0,
+			10,
+			half.clone(),
+			None,
+		));
+		assert_noop!(
+			CoretimeAssignmentBuffer::assign_core_part(
+				RuntimeOrigin::root(),
+				0,
+				10,
+				vec![(CoreAssignment::Idle, FULL_CORE)],
+				None,
+			),
+			Error::<Test>::OverScheduled,
+		);
+		assert_noop!(
+			CoretimeAssignmentBuffer::assign_core_part(
+				RuntimeOrigin::root(),
+				0,
+				10,
+				vec![(CoreAssignment::Idle, 1); 100],
+				None,
+			),
+			Error::<Test>::TooManyAssignments,
+		);
+		assert!(assigned().is_empty());
+	});
+}
+
+#[test]
+fn incomplete_assignment_expires_once_begin_has_passed() {
+	new_test_ext().execute_with(|| {
+		let half = vec![(CoreAssignment::Task(2000), FULL_CORE / 2)];
+		let other_half = vec![(CoreAssignment::Task(2001), FULL_CORE / 2)];
+
+		// The second part of the assignment starting at 10 gets lost.
+		assert_ok!(CoretimeAssignmentBuffer::assign_core_part(
+			RuntimeOrigin::root(),
+			0,
+			10,
+			half.clone(),
+			None,
+		));
+		assert_ok!(CoretimeAssignmentBuffer::assign_core_part(
+			RuntimeOrigin::root(),
+			0,
+			20,
+			half.clone(),
+			None,
+		));
+
+		// Nothing expires up to and including `begin`, only the queue is read.
+		let db_weight = <Test as frame_system::Config>::DbWeight::get();
+		assert_eq!(CoretimeAssignmentBuffer::on_idle(10, Weight::MAX), db_weight.reads(1));
+		assert!(PendingAssignments::<Test>::get((0, 10)).is_some());
+
+		System::reset_events();
+		let consumed = CoretimeAssignmentBuffer::on_idle(11, Weight::MAX);
+		assert_eq!(consumed, db_weight.reads_writes(2, 2));
+		assert!(PendingAssignments::<Test>::get((0, 10)).is_none());
+		assert_eq!(PendingAssignments::<Test>::get((0, 20)).unwrap().into_inner(), half);
+		assert_eq!(
+			System::events().into_iter().map(|r| r.event).collect::<Vec<_>>(),
+			vec![Event::AssignmentExpired { core: 0, begin: 10, parts: FULL_CORE / 2 }.into()],
+		);
+
+		// A late part does not complete the dropped assignment.
+		assert_ok!(CoretimeAssignmentBuffer::assign_core_part(
+			RuntimeOrigin::root(),
+			0,
+			10,
+			other_half.clone(),
+			None,
+		));
+		assert!(assigned().is_empty());
+		assert_eq!(PendingAssignments::<Test>::get((0, 10)).unwrap().into_inner(), other_half);
+
+		// The other assignment is still completed.
+		assert_ok!(CoretimeAssignmentBuffer::assign_core_part(
+			RuntimeOrigin::root(),
+			0,
+			20,
+			other_half.clone(),
+			None,
+		));
+		let mut expected = half;
+		expected.extend(other_half);
+		assert_eq!(assigned(), vec![(0, 20, expected, None)]);
+	});
+}
+
+#[test]
+fn expiry_respects_the_remaining_weight() {
+	new_test_ext().execute_with(|| {
+		let half = vec![(CoreAssignment::Task(2000), FULL_CORE / 2)];
+		for core in 0..3 {
+			assert_ok!(CoretimeAssignmentBuffer::assign_core_part(
+				RuntimeOrigin::root(),
+				core,
+				10,
+				half.clone(),
+				None,
+			));
+		}
+
+		// Reading and writing the queue costs as much as removing an entry.
+		let per_entry = <Test as frame_system::Config>::DbWeight::get().reads_writes(1, 1);
+		assert_eq!(CoretimeAssignmentBuffer::on_idle(11, per_entry * 3), per_entry * 3);
+		assert_eq!(PendingAssignments::<Test>::iter().count(), 1);
+		assert_eq!(ExpiryQueue::<Test>::get().into_iter().collect::<Vec<_>>(), vec![(10, 2)]);
+
+		assert_eq!(CoretimeAssignmentBuffer::on_idle(12, Weight::zero()), Weight::zero());
+		assert_eq!(PendingAssignments::<Test>::iter().count(), 1);
+
+		CoretimeAssignmentBuffer::on_idle(12, Weight::MAX);
+		assert_eq!(PendingAssignments::<Test>::iter().count(), 0);
+		assert!(ExpiryQueue::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn number_of_incomplete_assignments_is_bounded() {
+	new_test_ext().execute_with(|| {
+		let half = vec![(CoreAssignment::Task(2000), FULL_CORE / 2)];
+		for core in 0..3 {
+			assert_ok!(CoretimeAssignmentBuffer::assign_core_part(
+				RuntimeOrigin::root(),
+				core,
+				10,
+				half.clone(),
+				None,
+			));
+		}
+
+		assert_noop!(
+			CoretimeAssignmentBuffer::assign_core_part(
+				RuntimeOrigin::root(),
+				3,
+				10,
+				half.clone(),
+				None,
+			),
+			Error::<Test>::TooManyPendingAssignments,
+		);
+		// Further parts of buffered assignments and complete assignments are still accepted.
+		assert_ok!(CoretimeAssignmentBuffer::assign_core_part(
+			RuntimeOrigin::root(),
+			3,
+			10,
+			vec![(CoreAssignment::Pool, FULL_CORE)],
+			None,
+		));
+		assert_ok!(CoretimeAssignmentBuffer::assign_core_part(
+			RuntimeOrigin::root(),
+			0,
+			10,
+			half.clone(),
+			None,
+		));
+
+		// Completing an assignment makes room for another one.
+		assert_eq!(ExpiryQueue::<Test>::get().len(), 2);
+		assert_ok!(CoretimeAssignmentBuffer::assign_core_part(
+			RuntimeOrigin::root(),
+			3,
+			20,
+			half,
+			None,
+		));
+		assert_eq!(
+			ExpiryQueue::<Test>::get().into_iter().collect::<Vec<_>>(),
+			vec![(10, 1), (10, 2), (20, 3)]
+		);
+	});
+}
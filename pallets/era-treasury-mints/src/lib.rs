@@ -0,0 +1,118 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Era treasury mints pallet
+//!
+//! `pallet_staking` keeps the payouts to the stakers of the last eras in `ErasValidatorReward`,
+//! but hands the remainder of the era payout to `RewardRemainder` without storing it. This
+//! pallet records that remainder per era, so that the amounts minted to the treasury can be
+//! queried next to the payouts to the stakers.
+//!
+//! The mints are recorded by wrapping the `RewardRemainder` handler of `pallet_staking` in
+//! [`RecordEraMint`]. Only the mints of the last [`Config::HistoryDepth`] eras are kept in
+//! [`Mints`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod tests;
+
+use core::marker::PhantomData;
+use frame_support::traits::{
+	fungible::{Balanced, Credit},
+	Imbalance, OnUnbalanced,
+};
+use sp_runtime::traits::Saturating;
+use sp_staking::EraIndex;
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The balance type of the minted amounts.
+		type Balance: Parameter + MaxEncodedLen + Copy + Default + Saturating;
+
+		/// The currently active era, e.g. `pallet_staking::ActiveEra`.
+		type ActiveEra: Get<Option<EraIndex>>;
+
+		/// The number of eras to keep the mints of, usually the `HistoryDepth` of
+		/// `pallet_staking`.
+		#[pallet::constant]
+		type HistoryDepth: Get<u32>;
+	}
+
+	/// The amounts minted to the treasury in the last [`Config::HistoryDepth`] eras, oldest
+	/// first.
+	#[pallet::storage]
+	pub type Mints<T: Config> =
+		StorageValue<_, BoundedVec<(EraIndex, T::Balance), T::HistoryDepth>, ValueQuery>;
+
+	impl<T: Config> Pallet<T> {
+		/// The amount minted to the treasury in `era`, if it is still known.
+		pub fn mint_of(era: EraIndex) -> Option<T::Balance> {
+			Mints::<T>::get()
+				.into_iter()
+				.find_map(|(mint_era, amount)| (mint_era == era).then_some(amount))
+		}
+
+		/// Record that `amount` was minted to the treasury in `era`.
+		///
+		/// Several mints in the same era are summed up. Mints of eras that are
+		/// [`Config::HistoryDepth`] or more eras older than `era` are dropped.
+		pub fn note_mint(era: EraIndex, amount: T::Balance) {
+			Mints::<T>::mutate(|mints| {
+				let oldest = era.saturating_sub(T::HistoryDepth::get().saturating_sub(1));
+				mints.retain(|(mint_era, _)| *mint_era >= oldest && *mint_era <= era);
+
+				match mints.last_mut() {
+					Some((last, total)) if *last == era => total.saturating_accrue(amount),
+					_ => {
+						// Only fails for a zero `HistoryDepth`, where nothing is kept.
+						let _ = mints.try_push((era, amount));
+					},
+				}
+			});
+		}
+	}
+}
+
+/// Records the credit in [`Mints`] for the active era before passing it on to `Inner`.
+///
+/// Meant to wrap the `RewardRemainder` of `pallet_staking`.
+pub struct RecordEraMint<T, Inner>(PhantomData<(T, Inner)>);
+
+impl<T, AccountId, F, Inner> OnUnbalanced<Credit<AccountId, F>> for RecordEraMint<T, Inner>
+where
+	T: Config,
+	F: Balanced<AccountId, Balance = T::Balance>,
+	Inner: OnUnbalanced<Credit<AccountId, F>>,
+{
+	fn on_nonzero_unbalanced(amount: Credit<AccountId, F>) {
+		if let Some(era) = T::ActiveEra::get() {
+			Pallet::<T>::note_mint(era, amount.peek());
+		}
+		Inner::on_unbalanced(amount);
+	}
+}
@@ -0,0 +1,112 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+// Tests for Era Treasury Mints Pallet
+
+use super::*;
+use crate as era_treasury_mints;
+use frame_support::{
+	construct_runtime, derive_impl, parameter_types,
+	traits::{fungible::Inspect, tokens::imbalance::ResolveTo},
+};
+use sp_core::ConstU32;
+use sp_io::TestExternalities;
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		Balances: pallet_balances,
+		EraTreasuryMints: era_treasury_mints,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+parameter_types! {
+	pub storage ActiveEra: Option<EraIndex> = None;
+	pub const TreasuryAccount: u64 = 1;
+}
+
+impl Config for Test {
+	type Balance = u64;
+	type ActiveEra = ActiveEra;
+	type HistoryDepth = ConstU32<3>;
+}
+
+type RecordToTreasury = RecordEraMint<Test, ResolveTo<TreasuryAccount, Balances>>;
+
+fn new_test_ext() -> TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	TestExternalities::new(t)
+}
+
+fn mint_in_era(era: EraIndex, amount: u64) {
+	ActiveEra::set(&Some(era));
+	RecordToTreasury::on_unbalanced(Balances::issue(amount));
+}
+
+#[test]
+fn mints_are_recorded_and_passed_on() {
+	new_test_ext().execute_with(|| {
+		mint_in_era(1, 10);
+		mint_in_era(2, 20);
+		// A second mint in the same era is added up.
+		mint_in_era(2, 5);
+
+		assert_eq!(EraTreasuryMints::mint_of(1), Some(10));
+		assert_eq!(EraTreasuryMints::mint_of(2), Some(25));
+		assert_eq!(EraTreasuryMints::mint_of(3), None);
+		assert_eq!(Balances::balance(&TreasuryAccount::get()), 35);
+	});
+}
+
+#[test]
+fn only_the_last_history_depth_eras_are_kept() {
+	new_test_ext().execute_with(|| {
+		for era in 0..10 {
+			mint_in_era(era, era as u64);
+		}
+		assert_eq!(Mints::<Test>::get().into_inner(), vec![(7, 7), (8, 8), (9, 9)]);
+
+		// Eras without a mint leave gaps, older eras are still dropped.
+		mint_in_era(11, 11);
+		assert_eq!(Mints::<Test>::get().into_inner(), vec![(9, 9), (11, 11)]);
+		mint_in_era(20, 20);
+		assert_eq!(Mints::<Test>::get().into_inner(), vec![(20, 20)]);
+	});
+}
+
+#[test]
+fn nothing_is_recorded_without_an_active_era() {
+	new_test_ext().execute_with(|| {
+		RecordToTreasury::on_unbalanced(Balances::issue(10));
+
+		assert!(Mints::<Test>::get().is_empty());
+		assert_eq!(Balances::balance(&TreasuryAccount::get()), 10);
+	});
+}
@@ -0,0 +1,224 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API for the tracks of `pallet_referenda`.
+//!
+//! UIs can use [`ReferendaTracksApi`] to draw the approval and support curves of a track or to
+//! predict when a referendum passes, without re-implementing [`pallet_referenda::Curve`].
+//!
+//! A runtime can have several referenda pallets, which are selected by their pallet index. The
+//! runtime implements the API by forwarding to [`ReferendaTracks`], which is implemented for
+//! every referenda pallet and for tuples of them.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub mod testing;
+
+use alloc::vec::Vec;
+use codec::{Codec, Decode, Encode};
+use frame_support::traits::PalletInfoAccess;
+use pallet_referenda::{
+	BalanceOf, BlockNumberFor, ReferendumIndex, ReferendumInfo, ReferendumInfoFor, Track,
+	TrackIdOf, TracksInfo,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, BlockNumberProvider, One, Saturating, Zero},
+	Perbill,
+};
+
+/// The maximum number of [`ThresholdsAt`] returned by
+/// [`ReferendaTracksApi::referendum_thresholds`].
+pub const MAX_THRESHOLDS: u32 = 1_000;
+
+/// The approval and support a referendum needs to pass.
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Thresholds {
+	/// The minimum share of aye votes.
+	pub approval: Perbill,
+	/// The minimum turnout of aye votes relative to the total issuance.
+	pub support: Perbill,
+}
+
+/// The [`Thresholds`] of a referendum at a given block.
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ThresholdsAt<BlockNumber> {
+	/// The block the thresholds apply to.
+	pub block: BlockNumber,
+	/// The thresholds at `block`.
+	pub thresholds: Thresholds,
+}
+
+sp_api::decl_runtime_apis! {
+	/// API to inspect the tracks of the referenda pallets of a runtime.
+	pub trait ReferendaTracksApi<TrackId, Balance, BlockNumber>
+	where
+		TrackId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Returns all tracks of the referenda pallet at `pallet_index`.
+		///
+		/// Returns `None` if there is no referenda pallet at `pallet_index`.
+		fn tracks(pallet_index: u8) -> Option<Vec<Track<TrackId, Balance, BlockNumber>>>;
+
+		/// Returns the thresholds of `track` after `elapsed` of its decision period.
+		///
+		/// Returns `None` if the pallet or the track does not exist.
+		fn track_thresholds(pallet_index: u8, track: TrackId, elapsed: Perbill) -> Option<Thresholds>;
+
+		/// Returns the projected thresholds of the ongoing referendum `index` every `step` blocks,
+		/// from the start of its decision period until its end.
+		///
+		/// At most [`MAX_THRESHOLDS`] thresholds are returned, a smaller `step` is raised
+		/// accordingly. A `step` of one thus returns the thresholds block by block only for
+		/// decision periods shorter than [`MAX_THRESHOLDS`] blocks.
+		///
+		/// If the referendum is not deciding yet, its decision period is assumed to start once its
+		/// prepare period is over, but not before the current block.
+		///
+		/// Returns `None` if the pallet does not exist or the referendum is not ongoing.
+		fn referendum_thresholds(
+			pallet_index: u8,
+			index: ReferendumIndex,
+			step: BlockNumber,
+		) -> Option<Vec<ThresholdsAt<BlockNumber>>>;
+	}
+}
+
+/// The implementation of [`ReferendaTracksApi`] for one or more referenda pallets.
+pub trait ReferendaTracks<TrackId, Balance, BlockNumber> {
+	/// See [`ReferendaTracksApi::tracks`].
+	fn tracks(pallet_index: u8) -> Option<Vec<Track<TrackId, Balance, BlockNumber>>>;
+
+	/// See [`ReferendaTracksApi::track_thresholds`].
+	fn track_thresholds(pallet_index: u8, track: TrackId, elapsed: Perbill) -> Option<Thresholds>;
+
+	/// See [`ReferendaTracksApi::referendum_thresholds`].
+	fn referendum_thresholds(
+		pallet_index: u8,
+		index: ReferendumIndex,
+		step: BlockNumber,
+	) -> Option<Vec<ThresholdsAt<BlockNumber>>>;
+}
+
+impl<T: pallet_referenda::Config<I>, I: 'static>
+	ReferendaTracks<TrackIdOf<T, I>, BalanceOf<T, I>, BlockNumberFor<T, I>>
+	for pallet_referenda::Pallet<T, I>
+where
+	Self: PalletInfoAccess,
+{
+	fn tracks(
+		pallet_index: u8,
+	) -> Option<Vec<Track<TrackIdOf<T, I>, BalanceOf<T, I>, BlockNumberFor<T, I>>>> {
+		(Self::index() == pallet_index as usize)
+			.then(|| T::Tracks::tracks().map(|track| track.into_owned()).collect())
+	}
+
+	fn track_thresholds(
+		pallet_index: u8,
+		track: TrackIdOf<T, I>,
+		elapsed: Perbill,
+	) -> Option<Thresholds> {
+		if Self::index() != pallet_index as usize {
+			return None
+		}
+		let info = T::Tracks::info(track)?;
+		Some(Thresholds {
+			approval: info.min_approval.threshold(elapsed),
+			support: info.min_support.threshold(elapsed),
+		})
+	}
+
+	fn referendum_thresholds(
+		pallet_index: u8,
+		index: ReferendumIndex,
+		step: BlockNumberFor<T, I>,
+	) -> Option<Vec<ThresholdsAt<BlockNumberFor<T, I>>>> {
+		if Self::index() != pallet_index as usize {
+			return None
+		}
+		let ReferendumInfo::Ongoing(status) = ReferendumInfoFor::<T, I>::get(index)? else {
+			return None
+		};
+		let info = T::Tracks::info(status.track)?;
+
+		let since = match status.deciding {
+			Some(deciding) => deciding.since,
+			None => status
+				.submitted
+				.saturating_add(info.prepare_period)
+				.max(T::BlockNumberProvider::current_block_number()),
+		};
+		let step = step.max(min_threshold_step(info.decision_period));
+		let end = since.saturating_add(info.decision_period);
+
+		let mut thresholds = Vec::new();
+		let mut elapsed = Zero::zero();
+		loop {
+			let fraction = Perbill::from_rational(elapsed, info.decision_period);
+			thresholds.push(ThresholdsAt {
+				block: since.saturating_add(elapsed),
+				thresholds: Thresholds {
+					approval: info.min_approval.threshold(fraction),
+					support: info.min_support.threshold(fraction),
+				},
+			});
+			if since.saturating_add(elapsed) >= end {
+				break
+			}
+			elapsed = elapsed.saturating_add(step).min(info.decision_period);
+		}
+		Some(thresholds)
+	}
+}
+
+/// The smallest step of [`ReferendaTracksApi::referendum_thresholds`] for a decision period of
+/// `period` blocks, such that at most [`MAX_THRESHOLDS`] thresholds are returned.
+pub fn min_threshold_step<BlockNumber: AtLeast32BitUnsigned>(period: BlockNumber) -> BlockNumber {
+	// The first and the last block of the period are both included.
+	let steps = BlockNumber::from(MAX_THRESHOLDS - 1);
+	(period.saturating_add(steps.clone() - One::one()) / steps).max(One::one())
+}
+
+impl<TrackId, Balance, BlockNumber, A, B> ReferendaTracks<TrackId, Balance, BlockNumber> for (A, B)
+where
+	TrackId: Clone,
+	BlockNumber: Clone,
+	A: ReferendaTracks<TrackId, Balance, BlockNumber>,
+	B: ReferendaTracks<TrackId, Balance, BlockNumber>,
+{
+	fn tracks(pallet_index: u8) -> Option<Vec<Track<TrackId, Balance, BlockNumber>>> {
+		A::tracks(pallet_index).or_else(|| B::tracks(pallet_index))
+	}
+
+	fn track_thresholds(pallet_index: u8, track: TrackId, elapsed: Perbill) -> Option<Thresholds> {
+		A::track_thresholds(pallet_index, track.clone(), elapsed)
+			.or_else(|| B::track_thresholds(pallet_index, track, elapsed))
+	}
+
+	fn referendum_thresholds(
+		pallet_index: u8,
+		index: ReferendumIndex,
+		step: BlockNumber,
+	) -> Option<Vec<ThresholdsAt<BlockNumber>>> {
+		A::referendum_thresholds(pallet_index, index, step.clone())
+			.or_else(|| B::referendum_thresholds(pallet_index, index, step))
+	}
+}
@@ -0,0 +1,139 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Checks of the [`ReferendaTracks`] implementation of a runtime, to be used in its tests.
+
+use super::*;
+use frame_support::traits::{schedule::DispatchTime, Bounded, VoteTally};
+use pallet_referenda::{
+	DecidingStatus, Deposit, PalletsOriginOf, ReferendumInfoOf, ReferendumStatus,
+	ReferendumStatusOf, TallyOf,
+};
+use sp_runtime::traits::{TrailingZeroInput, UniqueSaturatedInto};
+
+/// Insert the ongoing referendum `index` on `track`, deciding since `deciding` if given.
+pub fn insert_ongoing_referendum<T: pallet_referenda::Config<I>, I: 'static>(
+	index: ReferendumIndex,
+	track: TrackIdOf<T, I>,
+	origin: PalletsOriginOf<T>,
+	submitted: BlockNumberFor<T, I>,
+	deciding: Option<BlockNumberFor<T, I>>,
+) {
+	let who = T::AccountId::decode(&mut TrailingZeroInput::zeroes()).expect("infinite input; qed");
+	let status: ReferendumStatusOf<T, I> = ReferendumStatus {
+		track,
+		origin,
+		proposal: Bounded::Inline(Default::default()),
+		enactment: DispatchTime::After(Zero::zero()),
+		submitted,
+		submission_deposit: Deposit { who, amount: Zero::zero() },
+		decision_deposit: None,
+		deciding: deciding.map(|since| DecidingStatus { since, confirming: None }),
+		tally: <TallyOf<T, I> as VoteTally<_, _>>::new(track),
+		in_queue: false,
+		alarm: None,
+	};
+	ReferendumInfoFor::<T, I>::insert(index, ReferendumInfoOf::<T, I>::Ongoing(status));
+}
+
+/// Check [`ReferendaTracks::referendum_thresholds`] of `Pallets` for ongoing referenda of the
+/// referenda pallet `(T, I)` on `track`.
+///
+/// The referenda `0..=3` of the pallet are overwritten. Needs to run in externalities where the
+/// current block of the pallet is after the prepare period of `track`.
+pub fn check_referendum_thresholds<T, I, Pallets>(
+	track: TrackIdOf<T, I>,
+	origin: PalletsOriginOf<T>,
+) where
+	T: pallet_referenda::Config<I>,
+	I: 'static,
+	pallet_referenda::Pallet<T, I>: PalletInfoAccess,
+	Pallets: ReferendaTracks<TrackIdOf<T, I>, BalanceOf<T, I>, BlockNumberFor<T, I>>,
+{
+	let pallet_index = pallet_referenda::Pallet::<T, I>::index() as u8;
+	let info = T::Tracks::info(track).expect("track exists");
+	let period = info.decision_period;
+	let now = T::BlockNumberProvider::current_block_number();
+	assert!(now > info.prepare_period, "the current block must be after the prepare period");
+	let thresholds_at = |block: BlockNumberFor<T, I>, elapsed: Perbill| ThresholdsAt {
+		block,
+		thresholds: Thresholds {
+			approval: info.min_approval.threshold(elapsed),
+			support: info.min_support.threshold(elapsed),
+		},
+	};
+
+	// Deciding: the curve starts at `since` and ends after the decision period, where the last
+	// step is cut short.
+	let since = now - One::one();
+	insert_ongoing_referendum::<T, I>(0, track, origin.clone(), Zero::zero(), Some(since));
+	let step = period / 3u32.into() + One::one();
+	let thresholds = Pallets::referendum_thresholds(pallet_index, 0, step).unwrap();
+	assert_eq!(thresholds.len(), 4);
+	assert_eq!(thresholds[0], thresholds_at(since, Perbill::zero()));
+	assert_eq!(thresholds[1].block, since + step);
+	assert_eq!(thresholds[1], thresholds_at(since + step, Perbill::from_rational(step, period)));
+	assert_eq!(thresholds[2].block, since + step + step);
+	assert_eq!(thresholds[3], thresholds_at(since + period, Perbill::one()));
+
+	// A step beyond the decision period only yields its first and last block.
+	let thresholds = Pallets::referendum_thresholds(pallet_index, 0, period * 2u32.into());
+	assert_eq!(
+		thresholds,
+		Some(vec![
+			thresholds_at(since, Perbill::zero()),
+			thresholds_at(since + period, Perbill::one())
+		]),
+	);
+
+	// Small steps are raised so that at most `MAX_THRESHOLDS` thresholds are returned, a zero
+	// step yields the same.
+	let min_step = min_threshold_step(period);
+	let finest = Pallets::referendum_thresholds(pallet_index, 0, One::one()).unwrap();
+	assert!(finest.len() <= MAX_THRESHOLDS as usize);
+	assert_eq!(
+		finest.len(),
+		UniqueSaturatedInto::<usize>::unique_saturated_into(
+			(period + min_step - One::one()) / min_step
+		) + 1
+	);
+	assert_eq!(finest[1].block, since + min_step);
+	assert_eq!(finest.last(), Some(&thresholds_at(since + period, Perbill::one())));
+	assert_eq!(Pallets::referendum_thresholds(pallet_index, 0, Zero::zero()), Some(finest));
+
+	// Not deciding yet: the decision period starts once the prepare period is over.
+	insert_ongoing_referendum::<T, I>(1, track, origin.clone(), now, None);
+	let since = now + info.prepare_period;
+	let thresholds = Pallets::referendum_thresholds(pallet_index, 1, period).unwrap();
+	assert_eq!(
+		thresholds,
+		vec![thresholds_at(since, Perbill::zero()), thresholds_at(since + period, Perbill::one())],
+	);
+
+	// Not deciding yet and the prepare period is over: the decision period starts now at the
+	// earliest.
+	insert_ongoing_referendum::<T, I>(2, track, origin, Zero::zero(), None);
+	let thresholds = Pallets::referendum_thresholds(pallet_index, 2, period).unwrap();
+	assert_eq!(
+		thresholds,
+		vec![thresholds_at(now, Perbill::zero()), thresholds_at(now + period, Perbill::one())],
+	);
+
+	// Unknown referenda and pallets.
+	ReferendumInfoFor::<T, I>::remove(3);
+	assert!(Pallets::referendum_thresholds(pallet_index, 3, One::one()).is_none());
+	assert!(Pallets::referendum_thresholds(u8::MAX, 0, One::one()).is_none());
+}
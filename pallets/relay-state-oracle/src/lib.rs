@@ -0,0 +1,237 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Relay state oracle pallet
+//!
+//! The pallet keeps the storage roots of the latest relay chain blocks, as given by the
+//! `relay_parent_storage_root` of the [`PersistedValidationData`], and verifies storage proofs of
+//! the relay chain against them. Other pallets use it through [`VerifyRelayStorage`] to read relay
+//! chain state, e.g. the proxies of an account (`pallet_remote_proxy`), its balance or its staking
+//! ledger (see [`keys`]).
+//!
+//! The pallet needs to be configured as `OnSystemEvent` of `cumulus_pallet_parachain_system` to
+//! learn about new storage roots.
+//!
+//! ## Security considerations
+//!
+//! A proof is accepted for any block whose storage root is still known. When configuring
+//! [`MaxStorageRootsToKeep`](Config::MaxStorageRootsToKeep) it should be considered that
+//! proven state can be up to [`MaxStorageRootsToKeep`](Config::MaxStorageRootsToKeep) old. So,
+//! a change of the state at the relay chain only takes effect for all users of this pallet after
+//! this time.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(test)]
+mod tests;
+
+use alloc::vec::Vec;
+use cumulus_pallet_parachain_system::OnSystemEvent;
+use cumulus_primitives_core::{relay_chain, PersistedValidationData};
+use frame_support::traits::Get;
+use sp_core::Hasher;
+
+pub use pallet::*;
+
+/// Verifies storage proofs of the relay chain.
+pub trait VerifyRelayStorage {
+	/// The relay chain block number.
+	type BlockNumber;
+	/// The hash type of the relay chain.
+	type Hash;
+
+	/// Returns the `(block, storage_root)` pairs of the relay chain that are currently known.
+	fn known_storage_roots() -> Vec<(Self::BlockNumber, Self::Hash)>;
+
+	/// Returns the storage root of the relay chain at `block`, if it is known.
+	fn storage_root_at(block: &Self::BlockNumber) -> Option<Self::Hash>;
+
+	/// Read the value at `key` from the given `proof` that is anchored at `storage_root`.
+	///
+	/// The `storage_root` does not need to be one of the relay chain, e.g. it can be taken from a
+	/// para head that was proven before. Returns `None` if the proof is invalid or does not contain
+	/// a value at `key`.
+	fn read_storage_proof(
+		storage_root: &Self::Hash,
+		key: &[u8],
+		proof: Vec<Vec<u8>>,
+	) -> Option<Vec<u8>>;
+
+	/// Read the value at `key` from the given `proof` that is anchored at the relay chain `block`.
+	///
+	/// Returns `None` if the storage root of `block` is unknown, the proof is invalid or it does
+	/// not contain a value at `key`.
+	fn verify_relay_storage(
+		block: Self::BlockNumber,
+		key: &[u8],
+		proof: Vec<Vec<u8>>,
+	) -> Option<Vec<u8>> {
+		Self::read_storage_proof(&Self::storage_root_at(&block)?, key, proof)
+	}
+
+	/// Make `storage_root` known as the storage root of the relay chain at `block`.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_storage_root(block: Self::BlockNumber, storage_root: Self::Hash);
+}
+
+/// Storage keys of relay chain state that is commonly proven.
+pub mod keys {
+	use alloc::vec::Vec;
+	use codec::Encode;
+	use frame_support::{storage::storage_prefix, Blake2_128Concat, StorageHasher};
+
+	fn map_key(pallet: &[u8], storage: &[u8], key: impl Encode) -> Vec<u8> {
+		let mut final_key = storage_prefix(pallet, storage).to_vec();
+		key.using_encoded(|k| final_key.extend(Blake2_128Concat::hash(k)));
+		final_key
+	}
+
+	/// The key of the `System::Account` entry of `who`, which contains its balances.
+	pub fn system_account(who: impl Encode) -> Vec<u8> {
+		map_key(b"System", b"Account", who)
+	}
+
+	/// The key of the `Staking::Ledger` entry of `controller`.
+	pub fn staking_ledger(controller: impl Encode) -> Vec<u8> {
+		map_key(b"Staking", b"Ledger", controller)
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use sp_runtime::traits::Saturating;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The block number of the relay chain.
+		type RelayBlockNumber: Parameter
+			+ MaxEncodedLen
+			+ Saturating
+			+ Ord
+			+ From<relay_chain::BlockNumber>;
+
+		/// The hash type of the relay chain.
+		type RelayHash: Parameter + MaxEncodedLen + From<relay_chain::Hash>;
+
+		/// The hasher used by the relay chain.
+		type RelayHasher: Hasher<Out = Self::RelayHash>;
+
+		/// The maximum number of storage roots to keep.
+		///
+		/// The storage roots are used to validate the relay chain proofs. The more we keep in
+		/// storage, the older the proof can be. This is not only seen as a maximum number, but
+		/// also as the maximum difference between the latest and the oldest storage root stored.
+		/// This means that if the chain for example did not progress for `MaxStorageRootsToKeep`
+		/// blocks, only the latest added storage root will be available for validating proofs.
+		type MaxStorageRootsToKeep: Get<u32>;
+	}
+
+	/// Stores the last [`Config::MaxStorageRootsToKeep`] block to storage root mappings of the
+	/// relay chain.
+	#[pallet::storage]
+	pub type BlockToRoot<T: Config> = StorageValue<
+		_,
+		BoundedVec<(T::RelayBlockNumber, T::RelayHash), T::MaxStorageRootsToKeep>,
+		ValueQuery,
+	>;
+
+	impl<T: Config> Pallet<T> {
+		/// Returns the `(block, storage_root)` pairs of the relay chain that are currently known.
+		pub fn known_storage_roots() -> Vec<(T::RelayBlockNumber, T::RelayHash)> {
+			BlockToRoot::<T>::get().into_inner()
+		}
+
+		/// Returns the relay chain block that should be used to anchor a new proof.
+		///
+		/// This is the latest known block, as it will be available for the longest time.
+		pub fn recommended_anchor_block() -> Option<T::RelayBlockNumber> {
+			BlockToRoot::<T>::get().last().map(|(block, _)| block.clone())
+		}
+
+		/// Make `storage_root` known as the storage root of `block`.
+		///
+		/// Removes all storage roots that are [`Config::MaxStorageRootsToKeep`] or more blocks
+		/// older than `block`.
+		pub(crate) fn note_storage_root(block: T::RelayBlockNumber, storage_root: T::RelayHash) {
+			BlockToRoot::<T>::mutate(|roots| {
+				let delete_up_to =
+					block.clone().saturating_sub(T::MaxStorageRootsToKeep::get().into());
+
+				while roots.first().is_some_and(|f| f.0 <= delete_up_to) {
+					roots.remove(0);
+				}
+
+				// We always remove all the old items before, thus there should always be space in
+				// the vector.
+				let _res = roots.try_push((block, storage_root));
+				debug_assert!(_res.is_ok());
+			});
+		}
+	}
+}
+
+impl<T: Config> OnSystemEvent for Pallet<T> {
+	fn on_validation_data(validation_data: &PersistedValidationData) {
+		Self::note_storage_root(
+			validation_data.relay_parent_number.into(),
+			validation_data.relay_parent_storage_root.into(),
+		);
+	}
+
+	fn on_validation_code_applied() {}
+}
+
+impl<T: Config> VerifyRelayStorage for Pallet<T> {
+	type BlockNumber = T::RelayBlockNumber;
+	type Hash = T::RelayHash;
+
+	fn known_storage_roots() -> Vec<(Self::BlockNumber, Self::Hash)> {
+		BlockToRoot::<T>::get().into_inner()
+	}
+
+	fn storage_root_at(block: &Self::BlockNumber) -> Option<Self::Hash> {
+		let roots = BlockToRoot::<T>::get();
+
+		roots
+			.binary_search_by(|(b, _)| b.cmp(block))
+			.ok()
+			.map(|pos| roots[pos].1.clone())
+	}
+
+	fn read_storage_proof(
+		storage_root: &Self::Hash,
+		key: &[u8],
+		proof: Vec<Vec<u8>>,
+	) -> Option<Vec<u8>> {
+		let db = sp_trie::StorageProof::new(proof).into_memory_db::<T::RelayHasher>();
+		sp_trie::read_trie_value::<sp_trie::LayoutV1<_>, _>(&db, storage_root, key, None, None)
+			.ok()
+			.flatten()
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_storage_root(block: Self::BlockNumber, storage_root: Self::Hash) {
+		Self::note_storage_root(block, storage_root);
+	}
+}
@@ -0,0 +1,143 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+// Tests for Relay State Oracle Pallet
+
+use super::*;
+use crate as relay_state_oracle;
+use codec::Encode;
+use frame_support::{construct_runtime, derive_impl, traits::ConstU32, BoundedVec};
+use sp_core::H256;
+use sp_io::TestExternalities;
+use sp_runtime::{traits::BlakeTwo256, BuildStorage};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		RelayStateOracle: relay_state_oracle,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+}
+
+impl Config for Test {
+	type RelayBlockNumber = u64;
+	type RelayHash = H256;
+	type RelayHasher = BlakeTwo256;
+	type MaxStorageRootsToKeep = ConstU32<10>;
+}
+
+fn new_test_ext() -> TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	TestExternalities::new(t)
+}
+
+fn note_relay_block(block: u32, storage_root: H256) {
+	RelayStateOracle::on_validation_data(&PersistedValidationData {
+		parent_head: vec![].into(),
+		relay_parent_number: block,
+		relay_parent_storage_root: storage_root,
+		max_pov_size: 5000000,
+	});
+}
+
+/// Create a relay chain trie with the given `values` and return its proof and storage root.
+fn prove(values: &[(Vec<u8>, Vec<u8>)]) -> (Vec<Vec<u8>>, H256) {
+	use sp_trie::TrieMut;
+
+	let (mut db, mut root) = sp_trie::MemoryDB::<BlakeTwo256>::default_with_root();
+	let mut trie =
+		sp_trie::TrieDBMutBuilder::<sp_trie::LayoutV1<_>>::new(&mut db, &mut root).build();
+	for (key, value) in values {
+		trie.insert(key, value).unwrap();
+	}
+	drop(trie);
+
+	(db.drain().into_values().map(|d| d.0).collect(), root)
+}
+
+#[test]
+fn clean_up_works() {
+	new_test_ext().execute_with(|| {
+		let root = H256::zero();
+
+		BlockToRoot::<Test>::set(BoundedVec::truncate_from(vec![
+			(0, root),
+			(10, root),
+			(20, root),
+			(29, root),
+		]));
+
+		note_relay_block(30, root);
+		assert_eq!(RelayStateOracle::known_storage_roots(), vec![(29, root), (30, root)]);
+		assert_eq!(<RelayStateOracle as VerifyRelayStorage>::storage_root_at(&5), None);
+
+		for i in 31..=40 {
+			note_relay_block(i, root);
+		}
+
+		RelayStateOracle::known_storage_roots()
+			.iter()
+			.for_each(|(b, _)| assert!(*b >= 31 && *b <= 40));
+		assert_eq!(RelayStateOracle::recommended_anchor_block(), Some(40));
+	});
+}
+
+#[test]
+fn verify_relay_storage_works() {
+	let account_key = keys::system_account(1u64);
+	let ledger_key = keys::staking_ledger(1u64);
+	let (proof, root) =
+		prove(&[(account_key.clone(), 100u128.encode()), (ledger_key.clone(), 50u128.encode())]);
+
+	new_test_ext().execute_with(|| {
+		note_relay_block(1, root);
+		note_relay_block(2, H256::zero());
+
+		assert_eq!(
+			RelayStateOracle::verify_relay_storage(1, &account_key, proof.clone()),
+			Some(100u128.encode())
+		);
+		assert_eq!(
+			RelayStateOracle::verify_relay_storage(1, &ledger_key, proof.clone()),
+			Some(50u128.encode())
+		);
+
+		// The proof does not contain the account.
+		assert_eq!(
+			RelayStateOracle::verify_relay_storage(1, &keys::system_account(2u64), proof.clone()),
+			None
+		);
+		// The proof was not made for the storage root of the block.
+		assert_eq!(RelayStateOracle::verify_relay_storage(2, &account_key, proof.clone()), None);
+		// The storage root of the block is unknown.
+		assert_eq!(RelayStateOracle::verify_relay_storage(3, &account_key, proof), None);
+		// The proof is invalid.
+		assert_eq!(RelayStateOracle::verify_relay_storage(1, &account_key, vec![vec![1]]), None);
+	});
+}
+
+#[test]
+fn keys_match_the_storage_layout() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(keys::system_account(1u64), frame_system::Account::<Test>::hashed_key_for(1u64));
+	});
+}
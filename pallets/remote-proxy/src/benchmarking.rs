@@ -19,14 +19,11 @@ use super::*;
 use crate::Pallet as RemoteProxy;
 use alloc::{boxed::Box, vec};
 use frame_benchmarking::v2::{
-	account, impl_test_function, instance_benchmarks, whitelisted_caller,
+	account, impl_test_function, instance_benchmarks, whitelisted_caller, BenchmarkError,
 };
 use frame_support::traits::Currency;
 use frame_system::RawOrigin;
-use sp_runtime::{
-	traits::{Bounded, StaticLookup},
-	BoundedVec,
-};
+use sp_runtime::traits::{Bounded, Hash, StaticLookup};
 
 const SEED: u32 = 0;
 
@@ -40,10 +37,50 @@ fn assert_last_event<T: pallet_proxy::Config>(
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+fn assert_last_remote_proxy_event<T: Config<I>, I: 'static>(generic_event: Event<T, I>) {
+	frame_system::Pallet::<T>::assert_last_event(
+		<T as Config<I>>::RuntimeEvent::from(generic_event).into(),
+	);
+}
+
+/// Fund `caller`, make a remote proxy proof for `caller` as proxy of `real` known and return it.
+fn setup_remote_proxy<T: Config<I>, I: 'static>(
+	caller: &T::AccountId,
+	real: &T::AccountId,
+) -> RemoteProxyProof<RemoteBlockNumberOf<T, I>> {
+	<T as pallet_proxy::Config>::Currency::make_free_balance_be(
+		caller,
+		BalanceOf::<T>::max_value() / 2u32.into(),
+	);
+	let (proof, block_number, storage_root) =
+		T::RemoteProxy::create_remote_proxy_proof(caller, real);
+	T::RelayStateOracle::set_storage_root(block_number, storage_root);
+
+	proof
+}
+
+/// Add `n` announcements of `caller` for `real`.
+fn add_announcements<T: Config<I>, I: 'static>(
+	n: u32,
+	caller: &T::AccountId,
+	real: &T::AccountId,
+	proof: &RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
+) -> Result<(), BenchmarkError> {
+	for i in 0..n {
+		RemoteProxy::<T, I>::announce(
+			RawOrigin::Signed(caller.clone()).into(),
+			T::Lookup::unlookup(real.clone()),
+			T::CallHasher::hash_of(&("announcement", i)),
+			proof.clone(),
+		)?;
+	}
+
+	Ok(())
+}
+
 #[instance_benchmarks]
 mod benchmarks {
 	use super::*;
-	use frame_benchmarking::BenchmarkError;
 
 	#[benchmark]
 	fn remote_proxy() -> Result<(), BenchmarkError> {
@@ -60,7 +97,7 @@ mod benchmarks {
 			frame_system::Call::<T>::remark { remark: vec![] }.into();
 		let (proof, block_number, storage_root) =
 			T::RemoteProxy::create_remote_proxy_proof(&caller, &real);
-		BlockToRoot::<T, I>::set(BoundedVec::truncate_from(vec![(block_number, storage_root)]));
+		T::RelayStateOracle::set_storage_root(block_number, storage_root);
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller), real_lookup, None, Box::new(call), proof);
@@ -82,7 +119,7 @@ mod benchmarks {
 		let real: T::AccountId = whitelisted_caller();
 		let (proof, block_number, storage_root) =
 			T::RemoteProxy::create_remote_proxy_proof(&caller, &real);
-		BlockToRoot::<T, I>::set(BoundedVec::truncate_from(vec![(block_number, storage_root)]));
+		T::RelayStateOracle::set_storage_root(block_number, storage_root);
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller), proof);
@@ -105,7 +142,7 @@ mod benchmarks {
 			frame_system::Call::<T>::remark { remark: vec![] }.into();
 		let (proof, block_number, storage_root) =
 			T::RemoteProxy::create_remote_proxy_proof(&caller, &real);
-		BlockToRoot::<T, I>::set(BoundedVec::truncate_from(vec![(block_number, storage_root)]));
+		T::RelayStateOracle::set_storage_root(block_number, storage_root);
 
 		#[block]
 		{
@@ -132,5 +169,117 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn announce(
+		a: Linear<0, { <T as pallet_proxy::Config>::MaxPending::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		// In this case the caller is the "target" proxy
+		let caller: T::AccountId = account("target", 0, SEED);
+		// ... and "real" is the traditional caller. This is not a typo.
+		let real: T::AccountId = whitelisted_caller();
+		let real_lookup = T::Lookup::unlookup(real.clone());
+		let proof = setup_remote_proxy::<T, I>(&caller, &real);
+		add_announcements::<T, I>(a, &caller, &real, &proof)?;
+		let call: <T as pallet_proxy::Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let call_hash = T::CallHasher::hash_of(&call);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), real_lookup, call_hash, proof);
+
+		assert_last_remote_proxy_event::<T, I>(Event::Announced { real, proxy: caller, call_hash });
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_announcement(
+		a: Linear<0, { <T as pallet_proxy::Config>::MaxPending::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		// In this case the caller is the "target" proxy
+		let caller: T::AccountId = account("target", 0, SEED);
+		// ... and "real" is the traditional caller. This is not a typo.
+		let real: T::AccountId = whitelisted_caller();
+		let real_lookup = T::Lookup::unlookup(real.clone());
+		let proof = setup_remote_proxy::<T, I>(&caller, &real);
+		add_announcements::<T, I>(a, &caller, &real, &proof)?;
+		let call: <T as pallet_proxy::Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let call_hash = T::CallHasher::hash_of(&call);
+		RemoteProxy::<T, I>::announce(
+			RawOrigin::Signed(caller.clone()).into(),
+			real_lookup.clone(),
+			call_hash,
+			proof,
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), real_lookup, call_hash);
+
+		let (announcements, _) = Announcements::<T, I>::get(&caller);
+		assert_eq!(announcements.len() as u32, a);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reject_announcement(
+		a: Linear<0, { <T as pallet_proxy::Config>::MaxPending::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		// In this case the caller is the "target" proxy
+		let caller: T::AccountId = account("target", 0, SEED);
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		// ... and "real" is the traditional caller. This is not a typo.
+		let real: T::AccountId = whitelisted_caller();
+		let proof = setup_remote_proxy::<T, I>(&caller, &real);
+		add_announcements::<T, I>(a, &caller, &real, &proof)?;
+		let call: <T as pallet_proxy::Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let call_hash = T::CallHasher::hash_of(&call);
+		RemoteProxy::<T, I>::announce(
+			RawOrigin::Signed(caller.clone()).into(),
+			T::Lookup::unlookup(real.clone()),
+			call_hash,
+			proof,
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(real), caller_lookup, call_hash);
+
+		let (announcements, _) = Announcements::<T, I>::get(&caller);
+		assert_eq!(announcements.len() as u32, a);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remote_proxy_announced(
+		a: Linear<0, { <T as pallet_proxy::Config>::MaxPending::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		// In this case the caller is the "target" proxy
+		let caller: T::AccountId = account("target", 0, SEED);
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		// ... and "real" is the traditional caller. This is not a typo.
+		let real: T::AccountId = whitelisted_caller();
+		let real_lookup = T::Lookup::unlookup(real.clone());
+		let proof = setup_remote_proxy::<T, I>(&caller, &real);
+		add_announcements::<T, I>(a, &caller, &real, &proof)?;
+		let call: <T as pallet_proxy::Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+		RemoteProxy::<T, I>::announce(
+			RawOrigin::Signed(caller.clone()).into(),
+			real_lookup.clone(),
+			T::CallHasher::hash_of(&call),
+			proof.clone(),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), caller_lookup, real_lookup, None, Box::new(call), proof);
+
+		assert_last_event::<T>(pallet_proxy::Event::ProxyExecuted { result: Ok(()) }.into());
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(RemoteProxy, crate::tests::new_test_ext(), crate::tests::Test);
 }
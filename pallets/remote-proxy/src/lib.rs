@@ -20,9 +20,16 @@
 //! location of the proxy depends on the [`RemoteProxyInterface`] implementation provided to this
 //! pallet. The underlying implementation works by verifying proofs from the remote location that
 //! prove the existence of a proxy. The remote proof is verified against a storage root from the
-//! remote location. These storage roots are extracted from the relay chain. So, the security
-//! of the proxy depends on the remote location. This means that the remote location should be a
-//! trusted chain that for example doesn't create fake proxies.
+//! remote location. These storage roots are provided by the [`Config::RelayStateOracle`], which
+//! tracks the storage roots of the relay chain (see [`pallet_relay_state_oracle`]). So, the
+//! security of the proxy depends on the remote location. This means that the remote location should
+//! be a trusted chain that for example doesn't create fake proxies.
+//!
+//! The remote location is either the relay chain itself or a sibling parachain (see
+//! [`RemoteProxyInterface::sibling_para_id`]). For a sibling parachain the proof first proves the
+//! head of the sibling parachain against the relay chain storage root and then the proxy
+//! definition against the storage root found in this head. [`SiblingParachainRemoteProxy`]
+//! implements the interface for sibling parachains that share the account ids of the local chain.
 //!
 //! ## Functions
 //!
@@ -36,16 +43,55 @@
 //! - [`Pallet::remote_proxy_with_registered_proof`]: Use a previously registered `proof` to
 //!   dispatch the wrapped call.
 //!
+//! - [`Pallet::announce`]: Announce the hash of a call that will be dispatched through a remote
+//!   proxy with a delay.
+//!
+//! - [`Pallet::remove_announcement`]: Remove an announcement made by the proxy.
+//!
+//! - [`Pallet::reject_announcement`]: Reject an announcement as the proxied account.
+//!
+//! - [`Pallet::remote_pure_proxy`]: Dispatch a wrapped call from a remote pure proxy that is
+//!   identified by the parameters of its creation.
+//!
+//! - [`Pallet::remote_proxy_announced`]: Dispatch a previously announced call once the delay of the
+//!   remote proxy has passed.
+//!
+//! ## Time-delayed proxies
+//!
+//! Remote proxies with a non-zero `delay` can not be used directly. They follow the same
+//! announce/execute flow as [`pallet_proxy`]: the proxy first announces the call hash on the
+//! local chain and can dispatch the call after the delay has passed. The announcements are
+//! tracked locally and the remote delay is converted into local blocks by
+//! [`RemoteProxyInterface::remote_to_local_delay`]. In the meantime the proxied account is able
+//! to reject the announcement. The deposits and limits for announcements are taken from the
+//! [`pallet_proxy::Config`].
+//!
+//! ## Pure proxies
+//!
+//! Pure proxies at the remote location can be used like any other account with
+//! [`Pallet::remote_proxy`]. Alternatively, [`Pallet::remote_pure_proxy`] derives the account of
+//! the pure proxy from its spawner, the block and extrinsic index it was created at and the
+//! disambiguation index (see [`RemoteProxyInterface::remote_pure_account`]). The derived account is
+//! converted to the local account by [`RemoteProxyInterface::remote_to_local_account_id`].
+//!
+//! Remote proxies can also be used to create and clean up pure proxies on the local chain.
+//! Same as for [`pallet_proxy`], removing all proxies or killing a pure proxy requires a remote
+//! proxy that maps to the default (`Any`) proxy type.
+//!
+//! ## Runtime API
+//!
+//! The [`runtime_api::RemoteProxyApi`] exposes the storage roots known to the oracle and allows
+//! verifying a proof without dispatching a call. Wallets can use it to pick an anchor block that
+//! is still known and to check a proof before paying any fees.
+//!
 //! ## Security considerations
 //!
 //! As explained above the security of the proxy depends on the remote location. So, if the remote
-//! location is not trusted, it should not be configured as remote location. When configuring
-//! [`MaxStorageRootsToKeep`](Config::MaxStorageRootsToKeep) it should be considered that the
-//! lifetime of a proxy will be [`MaxStorageRootsToKeep`](Config::MaxStorageRootsToKeep) in the
-//! past. This means when deleting a proxy at the remote location at X, it will take
-//! [`MaxStorageRootsToKeep`](Config::MaxStorageRootsToKeep) time until the proxy can not be used
-//! anymore. The reason for this is that the caller will be able to provide an old `proof` at which
-//! the proxy was still available.
+//! location is not trusted, it should not be configured as remote location. The lifetime of a
+//! proxy will be the number of storage roots kept by the [`Config::RelayStateOracle`] in the past.
+//! This means when deleting a proxy at the remote location at X, it will take this time until the
+//! proxy can not be used anymore. The reason for this is that the caller will be able to provide
+//! an old `proof` at which the proxy was still available.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -53,20 +99,22 @@ extern crate alloc;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod runtime_api;
 #[cfg(test)]
 mod tests;
 mod weight;
 
 use alloc::{boxed::Box, vec::Vec};
-use codec::{Encode, MaxEncodedLen};
-use frame_support::{storage::storage_prefix, Parameter, StorageHasher, Twox64Concat};
+use codec::{Decode, Encode, MaxEncodedLen};
+use core::marker::PhantomData;
+use frame_support::{storage::storage_prefix, traits::Get, Parameter, StorageHasher, Twox64Concat};
 use scale_info::TypeInfo;
-use sp_core::Hasher;
-use sp_runtime::traits::Saturating;
+use sp_runtime::traits::{Convert, Header as HeaderT, Saturating, TrailingZeroInput, Zero};
 
-pub use cumulus_primitives_core::PersistedValidationData;
+pub use cumulus_primitives_core::{ParaId, PersistedValidationData};
 pub use pallet::*;
 pub use pallet_proxy::ProxyDefinition;
+pub use pallet_relay_state_oracle::VerifyRelayStorage;
 pub use weight::WeightInfo;
 
 /// The remote proxy interface.
@@ -85,13 +133,6 @@ pub trait RemoteProxyInterface<AccountId, ProxyType, BlockNumber> {
 		+ From<u32>;
 	/// The hash type used by the remote chain.
 	type RemoteHash: Parameter + MaxEncodedLen;
-	/// The hasher used by the remote chain.
-	type RemoteHasher: Hasher<Out = Self::RemoteHash>;
-
-	/// Get the latest block to storage root mapping.
-	fn block_to_storage_root(
-		validation_data: &PersistedValidationData,
-	) -> Option<(Self::RemoteBlockNumber, <Self::RemoteHasher as Hasher>::Out)>;
 
 	/// The storage key where to find the [`ProxyDefinition`] for the given proxy account in the
 	/// remote chain.
@@ -103,11 +144,61 @@ pub trait RemoteProxyInterface<AccountId, ProxyType, BlockNumber> {
 		key
 	}
 
+	/// The para id of the sibling parachain that is used as remote location.
+	///
+	/// Returns `None` if the relay chain itself is the remote location. If this returns `Some(_)`,
+	/// only [`RemoteProxyProof::SiblingParachain`] proofs are accepted and otherwise only
+	/// [`RemoteProxyProof::RelayChain`] proofs.
+	fn sibling_para_id() -> Option<ParaId> {
+		None
+	}
+
+	/// The storage key where to find the head of the given `para_id` in the relay chain.
+	fn para_head_storage_key(para_id: ParaId) -> Vec<u8> {
+		let mut key = storage_prefix(b"Paras", b"Heads").to_vec();
+		para_id.using_encoded(|p| {
+			key.extend(Twox64Concat::hash(p));
+		});
+		key
+	}
+
+	/// Extract the storage root of the sibling parachain from the given para `head`.
+	///
+	/// The `head` is the raw value stored at [`Self::para_head_storage_key`] in the relay chain.
+	/// It is assumed that the relay chain and the sibling parachain use the same hasher.
+	fn para_head_to_storage_root(_head: &[u8]) -> Option<Self::RemoteHash> {
+		None
+	}
+
 	/// Convert the local account id to the remote account id.
 	///
 	/// If the conversion is not possible, return `None`.
 	fn local_to_remote_account_id(local: &AccountId) -> Option<Self::RemoteAccountId>;
 
+	/// Convert the remote account id to the local account id.
+	///
+	/// This is the inverse of [`Self::local_to_remote_account_id`]. If the conversion is not
+	/// possible, return `None`.
+	fn remote_to_local_account_id(remote: &Self::RemoteAccountId) -> Option<AccountId>;
+
+	/// Calculate the account id of a pure proxy at the remote location.
+	///
+	/// The pure proxy was spawned by `spawner` at `height` in the extrinsic with the given
+	/// `ext_index`. The default implementation uses the same derivation as
+	/// [`pallet_proxy::Pallet::pure_account`].
+	fn remote_pure_account(
+		spawner: &Self::RemoteAccountId,
+		proxy_type: &Self::RemoteProxyType,
+		index: u16,
+		height: Self::RemoteBlockNumber,
+		ext_index: u32,
+	) -> Self::RemoteAccountId {
+		let entropy = (b"modlpy/proxy____", spawner, height, ext_index, proxy_type, index)
+			.using_encoded(sp_core::blake2_256);
+		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+
 	/// Convert the remote proxy definition to the local proxy definition.
 	///
 	/// If the conversion is not possible, return `None`.
@@ -119,6 +210,13 @@ pub trait RemoteProxyInterface<AccountId, ProxyType, BlockNumber> {
 		>,
 	) -> Option<ProxyDefinition<AccountId, ProxyType, BlockNumber>>;
 
+	/// Convert the `delay` of a remote proxy definition to a delay in local blocks.
+	///
+	/// The converted delay replaces the `delay` of the proxy definition returned by
+	/// [`Self::remote_to_local_proxy_defintion`]. If the conversion is not possible, return
+	/// `None`. The proxy definition can not be used in this case.
+	fn remote_to_local_delay(delay: Self::RemoteBlockNumber) -> Option<BlockNumber>;
+
 	/// Create a remote proxy proof to be used in benchmarking.
 	///
 	/// Returns the `proof`, `block_number` and `storage_root`. The later are required to validate
@@ -130,68 +228,266 @@ pub trait RemoteProxyInterface<AccountId, ProxyType, BlockNumber> {
 	) -> (RemoteProxyProof<Self::RemoteBlockNumber>, Self::RemoteBlockNumber, Self::RemoteHash);
 }
 
+/// Converts a delay in remote blocks into a delay in local blocks.
+///
+/// The conversion is based on the expected block times in milliseconds of the remote and the
+/// local chain. The converted delay is rounded up, so it is never shorter than the remote delay.
+pub struct BlockTimeDelayConverter<RemoteBlockTime, LocalBlockTime>(
+	PhantomData<(RemoteBlockTime, LocalBlockTime)>,
+);
+
+impl<RemoteBlockNumber, LocalBlockNumber, RemoteBlockTime, LocalBlockTime>
+	Convert<RemoteBlockNumber, Option<LocalBlockNumber>>
+	for BlockTimeDelayConverter<RemoteBlockTime, LocalBlockTime>
+where
+	RemoteBlockNumber: TryInto<u64>,
+	LocalBlockNumber: TryFrom<u64>,
+	RemoteBlockTime: Get<u64>,
+	LocalBlockTime: Get<u64>,
+{
+	fn convert(delay: RemoteBlockNumber) -> Option<LocalBlockNumber> {
+		let local_block_time = LocalBlockTime::get();
+
+		if local_block_time.is_zero() {
+			return None;
+		}
+
+		let delay = delay.try_into().ok()?.checked_mul(RemoteBlockTime::get())?;
+		delay.div_ceil(local_block_time).try_into().ok()
+	}
+}
+
+/// [`RemoteProxyInterface`] that uses a sibling parachain as remote location.
+///
+/// The sibling parachain `SiblingParaId` is expected to use the same account ids as the local
+/// chain, headers of type `Header` and to store its proxies of type `RemoteProxyType` in the
+/// [`pallet_proxy`] instance named `ProxyPallet`. The remote proxy definitions are converted by
+/// `ProxyDefinitionConverter` and their delays by `DelayConverter`.
+pub struct SiblingParachainRemoteProxy<
+	SiblingParaId,
+	ProxyPallet,
+	RemoteProxyType,
+	Header,
+	LocalProxyType,
+	ProxyDefinitionConverter,
+	DelayConverter,
+>(
+	PhantomData<(
+		SiblingParaId,
+		ProxyPallet,
+		RemoteProxyType,
+		Header,
+		LocalProxyType,
+		ProxyDefinitionConverter,
+		DelayConverter,
+	)>,
+);
+
+impl<
+		AccountId,
+		BlockNumber,
+		SiblingParaId,
+		ProxyPallet,
+		RemoteProxyType,
+		Header,
+		LocalProxyType,
+		ProxyDefinitionConverter,
+		DelayConverter,
+	> RemoteProxyInterface<AccountId, LocalProxyType, BlockNumber>
+	for SiblingParachainRemoteProxy<
+		SiblingParaId,
+		ProxyPallet,
+		RemoteProxyType,
+		Header,
+		LocalProxyType,
+		ProxyDefinitionConverter,
+		DelayConverter,
+	>
+where
+	AccountId: Parameter + MaxEncodedLen,
+	SiblingParaId: Get<u32>,
+	ProxyPallet: Get<&'static str>,
+	RemoteProxyType: Parameter + MaxEncodedLen + Default,
+	Header: HeaderT,
+	Header::Number: Parameter + MaxEncodedLen,
+	Header::Hash: Parameter + MaxEncodedLen,
+	ProxyDefinitionConverter: Convert<
+		ProxyDefinition<AccountId, RemoteProxyType, Header::Number>,
+		Option<ProxyDefinition<AccountId, LocalProxyType, BlockNumber>>,
+	>,
+	DelayConverter: Convert<Header::Number, Option<BlockNumber>>,
+{
+	type RemoteAccountId = AccountId;
+
+	type RemoteProxyType = RemoteProxyType;
+
+	type RemoteBlockNumber = Header::Number;
+
+	type RemoteHash = Header::Hash;
+
+	fn proxy_definition_storage_key(proxy: &Self::RemoteAccountId) -> Vec<u8> {
+		let mut key = storage_prefix(ProxyPallet::get().as_bytes(), b"Proxies").to_vec();
+		proxy.using_encoded(|p| {
+			key.extend(Twox64Concat::hash(p));
+		});
+		key
+	}
+
+	fn sibling_para_id() -> Option<ParaId> {
+		Some(SiblingParaId::get().into())
+	}
+
+	fn para_head_to_storage_root(head: &[u8]) -> Option<Self::RemoteHash> {
+		// The relay chain stores the `HeadData`, which wraps the encoded header.
+		let head = Vec::<u8>::decode(&mut &head[..]).ok()?;
+		Header::decode(&mut &head[..]).ok().map(|header| *header.state_root())
+	}
+
+	fn local_to_remote_account_id(local: &AccountId) -> Option<Self::RemoteAccountId> {
+		Some(local.clone())
+	}
+
+	fn remote_to_local_account_id(remote: &Self::RemoteAccountId) -> Option<AccountId> {
+		Some(remote.clone())
+	}
+
+	fn remote_to_local_proxy_defintion(
+		remote: ProxyDefinition<
+			Self::RemoteAccountId,
+			Self::RemoteProxyType,
+			Self::RemoteBlockNumber,
+		>,
+	) -> Option<ProxyDefinition<AccountId, LocalProxyType, BlockNumber>> {
+		ProxyDefinitionConverter::convert(remote)
+	}
+
+	fn remote_to_local_delay(delay: Self::RemoteBlockNumber) -> Option<BlockNumber> {
+		DelayConverter::convert(delay)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_remote_proxy_proof(
+		caller: &AccountId,
+		proxy: &AccountId,
+	) -> (RemoteProxyProof<Self::RemoteBlockNumber>, Self::RemoteBlockNumber, Self::RemoteHash) {
+		use sp_trie::TrieMut;
+
+		let (mut para_db, mut para_root) =
+			sp_trie::MemoryDB::<Header::Hashing>::default_with_root();
+		let mut trie =
+			sp_trie::TrieDBMutBuilder::<sp_trie::LayoutV1<_>>::new(&mut para_db, &mut para_root)
+				.build();
+
+		let proxy_definition =
+			alloc::vec![ProxyDefinition::<AccountId, RemoteProxyType, Header::Number> {
+				delegate: caller.clone(),
+				proxy_type: RemoteProxyType::default(),
+				delay: Zero::zero(),
+			}];
+
+		trie.insert(&Self::proxy_definition_storage_key(proxy), &proxy_definition.encode())
+			.unwrap();
+		drop(trie);
+
+		let (mut relay_db, mut relay_root) =
+			sp_trie::MemoryDB::<Header::Hashing>::default_with_root();
+		let mut trie =
+			sp_trie::TrieDBMutBuilder::<sp_trie::LayoutV1<_>>::new(&mut relay_db, &mut relay_root)
+				.build();
+
+		let head = Header::new(
+			1u32.into(),
+			Default::default(),
+			para_root,
+			Default::default(),
+			Default::default(),
+		);
+
+		trie.insert(
+			&Self::para_head_storage_key(SiblingParaId::get().into()),
+			&head.encode().encode(),
+		)
+		.unwrap();
+		drop(trie);
+
+		(
+			RemoteProxyProof::SiblingParachain {
+				para_head_proof: relay_db.drain().into_values().map(|d| d.0).collect(),
+				proof: para_db.drain().into_values().map(|d| d.0).collect(),
+				block: 1u32.into(),
+			},
+			1u32.into(),
+			relay_root,
+		)
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use cumulus_pallet_parachain_system::OnSystemEvent;
-	use cumulus_primitives_core::PersistedValidationData;
-	use frame_support::{dispatch_context, pallet_prelude::*, traits::IsSubType};
+	use frame_support::{
+		dispatch_context,
+		pallet_prelude::*,
+		traits::{Currency, IsSubType, ReservableCurrency},
+	};
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::traits::{Dispatchable, StaticLookup, Zero};
+	use sp_runtime::traits::{Dispatchable, Hash, StaticLookup};
 
 	type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+	type BalanceOf<T> = <<T as pallet_proxy::Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
+	pub(crate) type CallHashOf<T> = <<T as pallet_proxy::Config>::CallHasher as Hash>::Output;
+	type AnnouncementOf<T> =
+		Announcement<<T as frame_system::Config>::AccountId, CallHashOf<T>, BlockNumberFor<T>>;
 
-	pub(crate) type RemoteBlockNumberOf<T, I> =
-		<<T as Config<I>>::RemoteProxy as RemoteProxyInterface<
-			<T as frame_system::Config>::AccountId,
-			<T as pallet_proxy::Config>::ProxyType,
-			BlockNumberFor<T>,
-		>>::RemoteBlockNumber;
-	type RemoteAccountIdOf<T, I> = <<T as Config<I>>::RemoteProxy as RemoteProxyInterface<
+	pub type RemoteBlockNumberOf<T, I> = <<T as Config<I>>::RemoteProxy as RemoteProxyInterface<
 		<T as frame_system::Config>::AccountId,
 		<T as pallet_proxy::Config>::ProxyType,
 		BlockNumberFor<T>,
-	>>::RemoteAccountId;
-	type RemoteHasherOf<T, I> = <<T as Config<I>>::RemoteProxy as RemoteProxyInterface<
+	>>::RemoteBlockNumber;
+	pub type RemoteAccountIdOf<T, I> = <<T as Config<I>>::RemoteProxy as RemoteProxyInterface<
 		<T as frame_system::Config>::AccountId,
 		<T as pallet_proxy::Config>::ProxyType,
 		BlockNumberFor<T>,
-	>>::RemoteHasher;
-	type RemoteHashOf<T, I> = <<T as Config<I>>::RemoteProxy as RemoteProxyInterface<
+	>>::RemoteAccountId;
+	pub type RemoteHashOf<T, I> = <<T as Config<I>>::RemoteProxy as RemoteProxyInterface<
 		<T as frame_system::Config>::AccountId,
 		<T as pallet_proxy::Config>::ProxyType,
 		BlockNumberFor<T>,
 	>>::RemoteHash;
-	type RemoteProxyTypeOf<T, I> = <<T as Config<I>>::RemoteProxy as RemoteProxyInterface<
+	pub type RemoteProxyTypeOf<T, I> = <<T as Config<I>>::RemoteProxy as RemoteProxyInterface<
 		<T as frame_system::Config>::AccountId,
 		<T as pallet_proxy::Config>::ProxyType,
 		BlockNumberFor<T>,
 	>>::RemoteProxyType;
+	pub type RemotePureProxyOf<T, I> = RemotePureProxy<
+		RemoteAccountIdOf<T, I>,
+		RemoteProxyTypeOf<T, I>,
+		RemoteBlockNumberOf<T, I>,
+	>;
 	type WeightInfoOf<T, I> = <T as Config<I>>::WeightInfo;
 
 	#[pallet::pallet]
 	pub struct Pallet<T, I = ()>(_);
 
-	/// Stores the last [`Config::MaxStorageRootsToKeep`] block to storage root mappings of the
-	/// target chain.
+	/// The announcements made by the proxy (key) that are waiting for the delay of the remote
+	/// proxy to pass.
 	#[pallet::storage]
-	pub type BlockToRoot<T: Config<I>, I: 'static = ()> = StorageValue<
+	pub type Announcements<T: Config<I>, I: 'static = ()> = StorageMap<
 		_,
-		BoundedVec<(RemoteBlockNumberOf<T, I>, RemoteHashOf<T, I>), T::MaxStorageRootsToKeep>,
+		Twox64Concat,
+		T::AccountId,
+		(BoundedVec<AnnouncementOf<T>, <T as pallet_proxy::Config>::MaxPending>, BalanceOf<T>),
 		ValueQuery,
 	>;
 
 	/// Configuration trait.
 	#[pallet::config]
 	pub trait Config<I: 'static = ()>: frame_system::Config + pallet_proxy::Config {
-		/// The maximum number of storage roots to keep.
-		///
-		/// The storage roots are used to validate the remote proofs. The more we keep in storage,
-		/// the older the proof can be. This is not only seen as a maximum number, but also as the
-		/// maximum difference between the latest and the oldest storage root stored. This means
-		/// that if the chain for example did not progress for `MaxStorageRootsToKeep` blocks, only
-		/// the latest added storage root will be available for validating proofs.
-		type MaxStorageRootsToKeep: Get<u32>;
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// The interface for interacting with the remote proxy.
 		type RemoteProxy: RemoteProxyInterface<
@@ -200,33 +496,22 @@ pub mod pallet {
 			BlockNumberFor<Self>,
 		>;
 
+		/// The oracle providing the relay chain storage roots the remote proofs are verified
+		/// against.
+		type RelayStateOracle: VerifyRelayStorage<
+			BlockNumber = RemoteBlockNumberOf<Self, I>,
+			Hash = RemoteHashOf<Self, I>,
+		>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
 
-	impl<T: Config<I>, I: 'static> OnSystemEvent for Pallet<T, I> {
-		fn on_validation_data(validation_data: &PersistedValidationData) {
-			let Some((block, hash)) = T::RemoteProxy::block_to_storage_root(validation_data) else {
-				return;
-			};
-
-			// Update the block to root mappings.
-			BlockToRoot::<T, I>::mutate(|roots| {
-				let delete_up_to =
-					block.clone().saturating_sub(T::MaxStorageRootsToKeep::get().into());
-
-				while roots.first().is_some_and(|f| f.0 <= delete_up_to) {
-					roots.remove(0);
-				}
-
-				// We always remove all the old items before, thus there should always be space in
-				// the vector.
-				let _res = roots.try_push((block, hash));
-				debug_assert!(_res.is_ok());
-			});
-		}
-
-		fn on_validation_code_applied() {}
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// An announcement was placed to make a call in the future.
+		Announced { real: T::AccountId, proxy: T::AccountId, call_hash: CallHashOf<T> },
 	}
 
 	#[pallet::error]
@@ -246,6 +531,16 @@ pub mod pallet {
 		DidNotFindMatchingProxyDefinition,
 		/// Proxy proof not registered.
 		ProxyProofNotRegistered,
+		/// The kind of proof is not supported by the configured remote location.
+		UnsupportedProofKind,
+		/// Failed to extract the storage root from the head of the sibling parachain.
+		InvalidParaHead,
+		/// There are too many pending announcements.
+		TooMany,
+		/// The announcement was not found.
+		NotFound,
+		/// The remote account id could not be converted to the local account id.
+		CouldNotConvertRemoteToLocalAccountId,
 	}
 
 	/// The remote proxy proof to prove the existence of a proxy account.
@@ -255,6 +550,65 @@ pub mod pallet {
 	pub enum RemoteProxyProof<RemoteBlockNumber> {
 		/// Assumes the default proxy storage layout.
 		RelayChain { proof: Vec<Vec<u8>>, block: RemoteBlockNumber },
+		/// Assumes the default proxy and paras storage layout.
+		///
+		/// The `para_head_proof` proves the head of the sibling parachain against the relay chain
+		/// storage root at `block`. The `proof` proves the proxy definition against the storage
+		/// root of this head.
+		SiblingParachain {
+			para_head_proof: Vec<Vec<u8>>,
+			proof: Vec<Vec<u8>>,
+			block: RemoteBlockNumber,
+		},
+	}
+
+	/// Identifies a pure proxy at the remote location by the parameters of its creation.
+	///
+	/// These are the parameters passed to and emitted by [`pallet_proxy::Pallet::create_pure`] at
+	/// the remote location.
+	#[derive(
+		core::fmt::Debug,
+		Clone,
+		Decode,
+		DecodeWithMemTracking,
+		Encode,
+		MaxEncodedLen,
+		TypeInfo,
+		PartialEq,
+		Eq,
+	)]
+	pub struct RemotePureProxy<RemoteAccountId, RemoteProxyType, RemoteBlockNumber> {
+		/// The account that spawned the pure proxy.
+		pub spawner: RemoteAccountId,
+		/// The proxy type the pure proxy was created with.
+		pub proxy_type: RemoteProxyType,
+		/// The disambiguation index the pure proxy was created with.
+		pub index: u16,
+		/// The remote block at which the pure proxy was created.
+		pub height: RemoteBlockNumber,
+		/// The index of the extrinsic in the remote block that created the pure proxy.
+		pub ext_index: u32,
+	}
+
+	/// An announcement of a call that will be dispatched through a time-delayed remote proxy.
+	#[derive(
+		core::fmt::Debug,
+		Clone,
+		Decode,
+		DecodeWithMemTracking,
+		Encode,
+		MaxEncodedLen,
+		TypeInfo,
+		PartialEq,
+		Eq,
+	)]
+	pub struct Announcement<AccountId, Hash, BlockNumber> {
+		/// The account on whose behalf the call will be dispatched.
+		pub real: AccountId,
+		/// The hash of the call to be made.
+		pub call_hash: Hash,
+		/// The local block number at which the announcement was made.
+		pub height: BlockNumber,
 	}
 
 	/// The dispatch context to keep track of registered proofs.
@@ -307,7 +661,8 @@ pub mod pallet {
 		/// a batch of `register_remote_proxy_proof` and the multisig call that uses
 		/// `remote_proxy_with_registered_proof`. This way the final approver can use a recent proof
 		/// to prove the existence of the remote proxy. Otherwise it would require the multisig
-		/// members to approve the call in [`Config::MaxStorageRootsToKeep`] amount of time.
+		/// members to approve the call while the storage root of the proof is still known to the
+		/// [`Config::RelayStateOracle`].
 		///
 		/// It is supported to register multiple proofs, but the proofs need to be consumed in the
 		/// reverse order as they were registered. Basically this means last in, first out.
@@ -383,9 +738,245 @@ pub mod pallet {
 
 			Self::do_remote_proxy(who, real, force_proxy_type, *call, proof)
 		}
+
+		/// Publish the hash of a proxy-call that will be made in the future.
+		///
+		/// This must be called some number of blocks before the corresponding
+		/// [`Self::remote_proxy_announced`] if the delay associated with the remote proxy
+		/// relationship is greater than zero. The delay is converted into local blocks using
+		/// [`RemoteProxyInterface::remote_to_local_delay`].
+		///
+		/// No more than [`pallet_proxy::Config::MaxPending`] announcements may be made at any one
+		/// time.
+		///
+		/// This will take a deposit of [`pallet_proxy::Config::AnnouncementDepositFactor`] as well
+		/// as [`pallet_proxy::Config::AnnouncementDepositBase`] if there are no other pending
+		/// announcements.
+		///
+		/// The dispatch origin for this call must be _Signed_ and a remote proxy of `real`.
+		///
+		/// Parameters:
+		/// - `real`: The account that the proxy will make a call on behalf of.
+		/// - `call_hash`: The hash of the call to be made by the `real` account.
+		/// - `proof`: The proof from the remote chain about the existence of the proxy.
+		#[pallet::call_index(3)]
+		#[pallet::weight(WeightInfoOf::<T, I>::announce(<T as pallet_proxy::Config>::MaxPending::get()))]
+		pub fn announce(
+			origin: OriginFor<T>,
+			real: AccountIdLookupOf<T>,
+			call_hash: CallHashOf<T>,
+			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let real = T::Lookup::lookup(real)?;
+
+			Self::find_remote_proxy(&real, &who, None, proof)?;
+
+			let announcement = Announcement {
+				real: real.clone(),
+				call_hash,
+				height: frame_system::Pallet::<T>::block_number(),
+			};
+
+			Announcements::<T, I>::try_mutate(&who, |(ref mut pending, ref mut deposit)| {
+				pending.try_push(announcement).map_err(|_| Error::<T, I>::TooMany)?;
+				Self::rejig_deposit(&who, *deposit, pending.len())
+					.map(|d| {
+						d.expect("Just pushed; pending.len() > 0; rejig_deposit returns Some; qed")
+					})
+					.map(|d| *deposit = d)
+			})?;
+
+			Self::deposit_event(Event::Announced { real, proxy: who, call_hash });
+
+			Ok(())
+		}
+
+		/// Remove a given announcement.
+		///
+		/// May be called by a proxy account to remove a call they previously announced and return
+		/// the deposit.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `real`: The account that the proxy will make a call on behalf of.
+		/// - `call_hash`: The hash of the call to be made by the `real` account.
+		#[pallet::call_index(4)]
+		#[pallet::weight(WeightInfoOf::<T, I>::remove_announcement(
+			<T as pallet_proxy::Config>::MaxPending::get()
+		))]
+		pub fn remove_announcement(
+			origin: OriginFor<T>,
+			real: AccountIdLookupOf<T>,
+			call_hash: CallHashOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let real = T::Lookup::lookup(real)?;
+
+			Self::edit_announcements(&who, |ann| ann.real != real || ann.call_hash != call_hash)
+		}
+
+		/// Remove the given announcement of a delegate.
+		///
+		/// May be called by a target (proxied) account to remove a call that one of their remote
+		/// proxies has announced they want to execute. The deposit is returned.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `delegate`: The account that previously announced the call.
+		/// - `call_hash`: The hash of the call to be made.
+		#[pallet::call_index(5)]
+		#[pallet::weight(WeightInfoOf::<T, I>::reject_announcement(
+			<T as pallet_proxy::Config>::MaxPending::get()
+		))]
+		pub fn reject_announcement(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			call_hash: CallHashOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+
+			Self::edit_announcements(&delegate, |ann| ann.real != who || ann.call_hash != call_hash)
+		}
+
+		/// Dispatch the given `call` from an account that the sender is authorized for through
+		/// a remote proxy.
+		///
+		/// Removes any corresponding announcement(s). The announcement must be older than the
+		/// delay of the remote proxy converted into local blocks.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `delegate`: The account that previously announced the call.
+		/// - `real`: The account that the proxy will make a call on behalf of.
+		/// - `force_proxy_type`: Specify the exact proxy type to be used and checked for this call.
+		/// - `call`: The call to be made by the `real` account.
+		/// - `proof`: The proof from the remote chain about the existence of the proxy.
+		#[pallet::call_index(6)]
+		#[pallet::weight({
+			let di = call.get_dispatch_info();
+			(WeightInfoOf::<T, I>::remote_proxy_announced(
+				<T as pallet_proxy::Config>::MaxPending::get()
+			)
+				// AccountData for inner call origin accountdata.
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(di.call_weight),
+			di.class)
+		})]
+		pub fn remote_proxy_announced(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			real: AccountIdLookupOf<T>,
+			force_proxy_type: Option<T::ProxyType>,
+			call: Box<<T as pallet_proxy::Config>::RuntimeCall>,
+			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			let real = T::Lookup::lookup(real)?;
+
+			let def = Self::find_remote_proxy(&real, &delegate, force_proxy_type, proof)?;
+
+			let call_hash = T::CallHasher::hash_of(&call);
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::edit_announcements(&delegate, |ann| {
+				ann.real != real ||
+					ann.call_hash != call_hash ||
+					now.saturating_sub(ann.height) < def.delay
+			})
+			.map_err(|_| Error::<T, I>::Unannounced)?;
+
+			Self::do_proxy(def, real, *call);
+
+			Ok(())
+		}
+
+		/// Dispatch the given `call` from a remote pure proxy that the sender is authorised for.
+		///
+		/// The account of the pure proxy is derived from `pure` using
+		/// [`RemoteProxyInterface::remote_pure_account`] and then converted to the local account
+		/// id. Apart from that this works the same as [`Self::remote_proxy`].
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `pure`: The parameters the pure proxy was created with at the remote location.
+		/// - `force_proxy_type`: Specify the exact proxy type to be used and checked for this call.
+		/// - `call`: The call to be made by the pure proxy.
+		/// - `proof`: The proof from the remote chain about the existence of the proxy.
+		#[pallet::call_index(7)]
+		#[pallet::weight({
+			let di = call.get_dispatch_info();
+			// Deriving the pure account is negligible compared to verifying the proof.
+			(WeightInfoOf::<T, I>::remote_proxy()
+				// AccountData for inner call origin accountdata.
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(di.call_weight),
+			di.class)
+		})]
+		pub fn remote_pure_proxy(
+			origin: OriginFor<T>,
+			pure: RemotePureProxyOf<T, I>,
+			force_proxy_type: Option<T::ProxyType>,
+			call: Box<<T as pallet_proxy::Config>::RuntimeCall>,
+			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let real = Self::remote_pure_account(pure)?;
+
+			Self::do_remote_proxy(who, real, force_proxy_type, *call, proof)
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Returns the local account id of the remote pure proxy identified by `pure`.
+		pub fn remote_pure_account(
+			pure: RemotePureProxyOf<T, I>,
+		) -> Result<T::AccountId, DispatchError> {
+			let RemotePureProxy { spawner, proxy_type, index, height, ext_index } = pure;
+			let remote = T::RemoteProxy::remote_pure_account(
+				&spawner,
+				&proxy_type,
+				index,
+				height,
+				ext_index,
+			);
+
+			T::RemoteProxy::remote_to_local_account_id(&remote)
+				.ok_or_else(|| Error::<T, I>::CouldNotConvertRemoteToLocalAccountId.into())
+		}
+
+		/// Returns the `(block, storage_root)` pairs of the remote location that are currently
+		/// known.
+		pub fn known_storage_roots() -> Vec<(RemoteBlockNumberOf<T, I>, RemoteHashOf<T, I>)> {
+			T::RelayStateOracle::known_storage_roots()
+		}
+
+		/// Returns the block of the remote location that should be used to anchor a new proof.
+		///
+		/// This is the latest known block, as it will be available for the longest time.
+		pub fn recommended_anchor_block() -> Option<RemoteBlockNumberOf<T, I>> {
+			T::RelayStateOracle::known_storage_roots().pop().map(|(block, _)| block)
+		}
+
+		/// Verify the given `proof` without dispatching anything.
+		///
+		/// Returns the matching proxy definition of `delegate` for `real`. If `proxy_type` is
+		/// `None`, any proxy type matches.
+		pub fn verify_remote_proxy_proof(
+			real: T::AccountId,
+			delegate: T::AccountId,
+			proxy_type: Option<T::ProxyType>,
+			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
+		) -> Result<ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>, DispatchError>
+		{
+			Self::find_remote_proxy(&real, &delegate, proxy_type, proof)
+		}
+
 		fn do_remote_proxy(
 			who: T::AccountId,
 			real: T::AccountId,
@@ -393,68 +984,142 @@ pub mod pallet {
 			call: <T as pallet_proxy::Config>::RuntimeCall,
 			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
 		) -> DispatchResult {
+			let def = Self::find_remote_proxy(&real, &who, force_proxy_type, proof)?;
+
+			ensure!(def.delay.is_zero(), Error::<T, I>::Unannounced);
+
+			Self::do_proxy(def, real, call);
+
+			Ok(())
+		}
+
+		/// Find the proxy definition of `delegate` for `real` in the given remote `proof`.
+		///
+		/// The returned proxy definition is converted to the local chain, including its `delay`.
+		fn find_remote_proxy(
+			real: &T::AccountId,
+			delegate: &T::AccountId,
+			force_proxy_type: Option<T::ProxyType>,
+			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
+		) -> Result<ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>, DispatchError>
+		{
 			let Some(real_remote) = T::RemoteProxy::local_to_remote_account_id(&real) else {
 				return Err(Error::<T, I>::CouldNotConvertLocalToRemoteAccountId.into());
 			};
 
-			let def = match proof {
+			let (storage_root, proof) = match proof {
 				RemoteProxyProof::RelayChain { proof, block } => {
-					let roots = BlockToRoot::<T, I>::get();
-
-					let Ok(storage_root) = roots
-						.binary_search_by(|(b, _)| b.cmp(&block))
-						.map(|pos| roots[pos].1.clone())
-					else {
-						return Err(Error::<T, I>::UnknownProofAnchorBlock.into());
-					};
-
-					let key = T::RemoteProxy::proxy_definition_storage_key(&real_remote);
-
-					let db =
-						sp_trie::StorageProof::new(proof).into_memory_db::<RemoteHasherOf<T, I>>();
-					let value = sp_trie::read_trie_value::<sp_trie::LayoutV1<_>, _>(
-						&db,
-						&storage_root,
-						&key,
-						None,
-						None,
-					)
-					.ok()
-					.flatten()
-					.ok_or(Error::<T, I>::InvalidProof)?;
-
-					let proxy_definitions = alloc::vec::Vec::<
-						ProxyDefinition<
-							RemoteAccountIdOf<T, I>,
-							RemoteProxyTypeOf<T, I>,
-							RemoteBlockNumberOf<T, I>,
-						>,
-					>::decode(&mut &value[..])
-					.map_err(|_| Error::<T, I>::ProxyDefinitionDecodingFailed)?;
-
-					let f = |x: &ProxyDefinition<
-						T::AccountId,
-						T::ProxyType,
-						BlockNumberFor<T>,
-					>|
-					 -> bool {
-						x.delegate == who &&
-							force_proxy_type.as_ref().is_none_or(|y| &x.proxy_type == y)
-					};
-
-					proxy_definitions
-						.into_iter()
-						.filter_map(T::RemoteProxy::remote_to_local_proxy_defintion)
-						.find(f)
-						.ok_or(Error::<T, I>::DidNotFindMatchingProxyDefinition)?
+					ensure!(
+						T::RemoteProxy::sibling_para_id().is_none(),
+						Error::<T, I>::UnsupportedProofKind
+					);
+
+					(Self::storage_root_at(&block)?, proof)
+				},
+				RemoteProxyProof::SiblingParachain { para_head_proof, proof, block } => {
+					let para_id = T::RemoteProxy::sibling_para_id()
+						.ok_or(Error::<T, I>::UnsupportedProofKind)?;
+
+					let head = Self::read_proof_value(
+						para_head_proof,
+						&Self::storage_root_at(&block)?,
+						&T::RemoteProxy::para_head_storage_key(para_id),
+					)?;
+
+					let para_storage_root = T::RemoteProxy::para_head_to_storage_root(&head)
+						.ok_or(Error::<T, I>::InvalidParaHead)?;
+
+					(para_storage_root, proof)
 				},
 			};
 
-			ensure!(def.delay.is_zero(), Error::<T, I>::Unannounced);
+			let key = T::RemoteProxy::proxy_definition_storage_key(&real_remote);
+			let value = Self::read_proof_value(proof, &storage_root, &key)?;
 
-			Self::do_proxy(def, real, call);
+			let proxy_definitions = alloc::vec::Vec::<
+				ProxyDefinition<
+					RemoteAccountIdOf<T, I>,
+					RemoteProxyTypeOf<T, I>,
+					RemoteBlockNumberOf<T, I>,
+				>,
+			>::decode(&mut &value[..])
+			.map_err(|_| Error::<T, I>::ProxyDefinitionDecodingFailed)?;
 
-			Ok(())
+			let f = |x: &ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>| -> bool {
+				&x.delegate == delegate &&
+					force_proxy_type.as_ref().is_none_or(|y| &x.proxy_type == y)
+			};
+
+			proxy_definitions
+				.into_iter()
+				.filter_map(|remote| {
+					let delay = T::RemoteProxy::remote_to_local_delay(remote.delay.clone())?;
+					T::RemoteProxy::remote_to_local_proxy_defintion(remote)
+						.map(|def| ProxyDefinition { delay, ..def })
+				})
+				.find(f)
+				.ok_or_else(|| Error::<T, I>::DidNotFindMatchingProxyDefinition.into())
+		}
+
+		/// Remove the announcements of `delegate` for which `f` returns `false`.
+		///
+		/// Returns an error if no announcement was removed.
+		fn edit_announcements<F: FnMut(&AnnouncementOf<T>) -> bool>(
+			delegate: &T::AccountId,
+			f: F,
+		) -> DispatchResult {
+			Announcements::<T, I>::try_mutate_exists(delegate, |x| {
+				let (mut pending, old_deposit) = x.take().ok_or(Error::<T, I>::NotFound)?;
+				let orig_pending_len = pending.len();
+				pending.retain(f);
+				ensure!(orig_pending_len > pending.len(), Error::<T, I>::NotFound);
+				*x = Self::rejig_deposit(delegate, old_deposit, pending.len())?
+					.map(|deposit| (pending, deposit));
+				Ok(())
+			})
+		}
+
+		/// Adjust the announcement deposit of `who` to `len` pending announcements.
+		///
+		/// Returns the new deposit or `None` if there are no pending announcements anymore.
+		fn rejig_deposit(
+			who: &T::AccountId,
+			old_deposit: BalanceOf<T>,
+			len: usize,
+		) -> Result<Option<BalanceOf<T>>, DispatchError> {
+			let new_deposit = if len == 0 {
+				BalanceOf::<T>::zero()
+			} else {
+				<T as pallet_proxy::Config>::AnnouncementDepositBase::get() +
+					<T as pallet_proxy::Config>::AnnouncementDepositFactor::get() *
+						(len as u32).into()
+			};
+
+			if new_deposit > old_deposit {
+				<T as pallet_proxy::Config>::Currency::reserve(who, new_deposit - old_deposit)?;
+			} else if new_deposit < old_deposit {
+				<T as pallet_proxy::Config>::Currency::unreserve(who, old_deposit - new_deposit);
+			}
+
+			Ok((len > 0).then_some(new_deposit))
+		}
+
+		/// Returns the storage root of the remote location at the given `block`.
+		fn storage_root_at(
+			block: &RemoteBlockNumberOf<T, I>,
+		) -> Result<RemoteHashOf<T, I>, DispatchError> {
+			T::RelayStateOracle::storage_root_at(block)
+				.ok_or_else(|| Error::<T, I>::UnknownProofAnchorBlock.into())
+		}
+
+		/// Read the value at `key` from the given `proof` that is anchored at `storage_root`.
+		fn read_proof_value(
+			proof: Vec<Vec<u8>>,
+			storage_root: &RemoteHashOf<T, I>,
+			key: &[u8],
+		) -> Result<Vec<u8>, DispatchError> {
+			T::RelayStateOracle::read_storage_proof(storage_root, key, proof)
+				.ok_or_else(|| Error::<T, I>::InvalidProof.into())
 		}
 
 		// TODO: Make upstream public and use that one.
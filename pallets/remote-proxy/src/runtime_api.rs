@@ -0,0 +1,56 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the remote proxy pallet.
+
+use crate::{ProxyDefinition, RemoteProxyProof};
+use alloc::vec::Vec;
+use codec::Codec;
+use sp_runtime::DispatchError;
+
+sp_api::decl_runtime_apis! {
+	/// API to support building and checking remote proxy calls off-chain.
+	pub trait RemoteProxyApi<AccountId, ProxyType, BlockNumber, RemoteBlockNumber, RemoteHash>
+	where
+		AccountId: Codec,
+		ProxyType: Codec,
+		BlockNumber: Codec,
+		RemoteBlockNumber: Codec,
+		RemoteHash: Codec,
+	{
+		/// Returns the `(block, storage_root)` pairs of the remote location that are currently
+		/// known and can be used to anchor a proof.
+		fn known_storage_roots() -> Vec<(RemoteBlockNumber, RemoteHash)>;
+
+		/// Returns the block of the remote location that should be used to anchor a new proof.
+		///
+		/// This is the latest known block, as its storage root will be kept the longest.
+		/// Returns `None` if no storage root is known yet.
+		fn recommended_anchor_block() -> Option<RemoteBlockNumber>;
+
+		/// Verify the given `proof` without dispatching anything.
+		///
+		/// Returns the matching proxy definition of `delegate` for `real` converted to the local
+		/// chain, including the `delay` in local blocks. If `proxy_type` is `None`, any proxy type
+		/// matches.
+		fn verify_remote_proxy_proof(
+			real: AccountId,
+			delegate: AccountId,
+			proxy_type: Option<ProxyType>,
+			proof: RemoteProxyProof<RemoteBlockNumber>,
+		) -> Result<ProxyDefinition<AccountId, ProxyType, BlockNumber>, DispatchError>;
+	}
+}
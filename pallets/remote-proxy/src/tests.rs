@@ -21,7 +21,7 @@ use crate as remote_proxy;
 use codec::{Decode, DecodeWithMemTracking};
 use cumulus_pallet_parachain_system::OnSystemEvent;
 use frame_support::{
-	assert_err, assert_ok, construct_runtime, derive_impl,
+	assert_err, assert_ok, construct_runtime, derive_impl, parameter_types,
 	traits::{Contains, Currency},
 };
 use frame_system::Call as SystemCall;
@@ -31,7 +31,8 @@ use pallet_utility::Call as UtilityCall;
 use sp_core::{ConstU32, ConstU64, H256};
 use sp_io::TestExternalities;
 use sp_runtime::{
-	traits::{BlakeTwo256, Dispatchable},
+	generic::Header,
+	traits::{BlakeTwo256, Convert, ConvertInto, Dispatchable, Hash as _, Header as _},
 	BoundedVec, BuildStorage,
 };
 
@@ -43,7 +44,9 @@ construct_runtime!(
 		Balances: pallet_balances,
 		Proxy: pallet_proxy,
 		Utility: pallet_utility,
+		RelayStateOracle: pallet_relay_state_oracle,
 		RemoteProxy: remote_proxy,
+		RemoteProxySibling: remote_proxy<Instance1>,
 	}
 );
 
@@ -142,18 +145,15 @@ impl crate::RemoteProxyInterface<u64, ProxyType, u64> for RemoteProxyImpl {
 	type RemoteProxyType = ProxyType;
 	type RemoteBlockNumber = u64;
 	type RemoteHash = H256;
-	type RemoteHasher = BlakeTwo256;
-
-	fn block_to_storage_root(
-		validation_data: &PersistedValidationData,
-	) -> Option<(Self::RemoteBlockNumber, <Self::RemoteHasher as Hasher>::Out)> {
-		Some((validation_data.relay_parent_number as _, validation_data.relay_parent_storage_root))
-	}
 
 	fn local_to_remote_account_id(local: &u64) -> Option<Self::RemoteAccountId> {
 		Some(*local)
 	}
 
+	fn remote_to_local_account_id(remote: &Self::RemoteAccountId) -> Option<u64> {
+		Some(*remote)
+	}
+
 	fn remote_to_local_proxy_defintion(
 		remote: ProxyDefinition<
 			Self::RemoteAccountId,
@@ -164,6 +164,10 @@ impl crate::RemoteProxyInterface<u64, ProxyType, u64> for RemoteProxyImpl {
 		Some(remote)
 	}
 
+	fn remote_to_local_delay(delay: Self::RemoteBlockNumber) -> Option<u64> {
+		Some(delay)
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	fn create_remote_proxy_proof(
 		caller: &u64,
@@ -196,12 +200,44 @@ impl crate::RemoteProxyInterface<u64, ProxyType, u64> for RemoteProxyImpl {
 	}
 }
 
-impl Config for Test {
+impl pallet_relay_state_oracle::Config for Test {
+	type RelayBlockNumber = u64;
+	type RelayHash = H256;
+	type RelayHasher = BlakeTwo256;
 	type MaxStorageRootsToKeep = ConstU32<10>;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RelayStateOracle = RelayStateOracle;
 	type RemoteProxy = RemoteProxyImpl;
 	type WeightInfo = ();
 }
 
+const SIBLING_PARA_ID: u32 = 2000;
+
+parameter_types! {
+	pub const SiblingParaId: u32 = SIBLING_PARA_ID;
+	pub const ProxyPalletName: &'static str = "Proxy";
+}
+
+pub type SiblingRemoteProxyImpl = crate::SiblingParachainRemoteProxy<
+	SiblingParaId,
+	ProxyPalletName,
+	ProxyType,
+	Header<u64, BlakeTwo256>,
+	ProxyType,
+	ConvertInto,
+	ConvertInto,
+>;
+
+impl Config<frame_support::instances::Instance1> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RelayStateOracle = RelayStateOracle;
+	type RemoteProxy = SiblingRemoteProxyImpl;
+	type WeightInfo = ();
+}
+
 pub fn new_test_ext() -> TestExternalities {
 	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 	pallet_balances::GenesisConfig::<Test> {
@@ -256,7 +292,7 @@ fn remote_proxy_works() {
 		);
 		assert_eq!(Balances::free_balance(6), 0);
 
-		RemoteProxy::on_validation_data(&PersistedValidationData {
+		RelayStateOracle::on_validation_data(&PersistedValidationData {
 			parent_head: vec![].into(),
 			relay_parent_number: 1,
 			relay_parent_storage_root: root,
@@ -337,7 +373,7 @@ fn remote_proxy_register_works() {
 		);
 		assert_eq!(Balances::free_balance(6), 0);
 
-		RemoteProxy::on_validation_data(&PersistedValidationData {
+		RelayStateOracle::on_validation_data(&PersistedValidationData {
 			parent_head: vec![].into(),
 			relay_parent_number: 1,
 			relay_parent_storage_root: root,
@@ -481,7 +517,7 @@ fn remote_proxy_multiple_register_works() {
 		);
 		assert_eq!(Balances::free_balance(6), 0);
 
-		RemoteProxy::on_validation_data(&PersistedValidationData {
+		RelayStateOracle::on_validation_data(&PersistedValidationData {
 			parent_head: vec![].into(),
 			relay_parent_number: 1,
 			relay_parent_storage_root: root,
@@ -592,27 +628,24 @@ fn remote_proxy_multiple_register_works() {
 }
 
 #[test]
-fn clean_up_works_and_old_blocks_are_rejected() {
+fn old_blocks_are_rejected() {
 	new_test_ext().execute_with(|| {
 		let root = H256::zero();
 		let call = Box::new(call_transfer(6, 1));
 
-		BlockToRoot::<Test>::set(BoundedVec::truncate_from(vec![
+		pallet_relay_state_oracle::BlockToRoot::<Test>::set(BoundedVec::truncate_from(vec![
 			(0, root),
 			(10, root),
 			(20, root),
 			(29, root),
 		]));
 
-		RemoteProxy::on_validation_data(&PersistedValidationData {
+		RelayStateOracle::on_validation_data(&PersistedValidationData {
 			parent_head: vec![].into(),
 			relay_parent_number: 30,
 			relay_parent_storage_root: root,
 			max_pov_size: 5000000,
 		});
-		BlockToRoot::<Test>::get()
-			.iter()
-			.for_each(|(b, _)| assert!(*b == 29 || *b == 30));
 		assert_err!(
 			RemoteProxy::remote_proxy(
 				RuntimeOrigin::signed(1),
@@ -623,18 +656,525 @@ fn clean_up_works_and_old_blocks_are_rejected() {
 			),
 			Error::<Test>::UnknownProofAnchorBlock
 		);
+	});
+}
+
+#[test]
+fn remote_proxy_sibling_parachain_works() {
+	let mut para_ext = new_test_ext();
+
+	let anon = para_ext.execute_with(|| {
+		Balances::make_free_balance_be(&1, 11); // An extra one for the ED.
+		assert_ok!(Proxy::create_pure(RuntimeOrigin::signed(1), ProxyType::Any, 0, 0));
+		Proxy::pure_account(&1, &ProxyType::Any, 0, None)
+	});
+
+	let proof = sp_state_machine::prove_read(
+		para_ext.as_backend(),
+		[pallet_proxy::Proxies::<Test>::hashed_key_for(anon)],
+	)
+	.unwrap();
+	let para_root = *para_ext.as_backend().root();
+
+	// The relay chain only needs to know about the head of the sibling parachain.
+	let head_key = SiblingRemoteProxyImpl::para_head_storage_key(SIBLING_PARA_ID.into());
+	let mut relay_ext = TestExternalities::default();
+	relay_ext.execute_with(|| {
+		let head = Header::<u64, BlakeTwo256>::new(
+			1,
+			Default::default(),
+			para_root,
+			Default::default(),
+			Default::default(),
+		);
+		sp_io::storage::set(&head_key, &head.encode().encode());
+	});
+
+	let para_head_proof =
+		sp_state_machine::prove_read(relay_ext.as_backend(), [&head_key]).unwrap();
+	let relay_root = *relay_ext.as_backend().root();
+
+	new_test_ext().execute_with(|| {
+		let call = Box::new(call_transfer(6, 1));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), anon, 5));
+		assert_eq!(Balances::free_balance(6), 0);
+
+		let validation_data = PersistedValidationData {
+			parent_head: vec![].into(),
+			relay_parent_number: 1,
+			relay_parent_storage_root: relay_root,
+			max_pov_size: 5000000,
+		};
+		RelayStateOracle::on_validation_data(&validation_data);
+
+		let sibling_proof = RemoteProxyProof::SiblingParachain {
+			para_head_proof: para_head_proof.clone().into_iter_nodes().collect(),
+			proof: proof.clone().into_iter_nodes().collect(),
+			block: 1,
+		};
+
+		assert_ok!(RemoteProxySibling::remote_proxy(
+			RuntimeOrigin::signed(1),
+			anon,
+			None,
+			call.clone(),
+			sibling_proof.clone(),
+		));
+
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_eq!(Balances::free_balance(6), 1);
+
+		// The relay chain instance does not accept sibling parachain proofs.
+		assert_err!(
+			RemoteProxy::remote_proxy(
+				RuntimeOrigin::signed(1),
+				anon,
+				None,
+				call.clone(),
+				sibling_proof
+			),
+			Error::<Test>::UnsupportedProofKind
+		);
+
+		// The sibling parachain instance does not accept relay chain proofs.
+		assert_err!(
+			RemoteProxySibling::remote_proxy(
+				RuntimeOrigin::signed(1),
+				anon,
+				None,
+				call.clone(),
+				RemoteProxyProof::RelayChain {
+					proof: proof.clone().into_iter_nodes().collect(),
+					block: 1
+				}
+			),
+			Error::<Test, frame_support::instances::Instance1>::UnsupportedProofKind
+		);
+
+		assert_err!(
+			RemoteProxySibling::remote_proxy(
+				RuntimeOrigin::signed(1),
+				anon,
+				None,
+				call.clone(),
+				RemoteProxyProof::SiblingParachain {
+					para_head_proof: Vec::new(),
+					proof: proof.clone().into_iter_nodes().collect(),
+					block: 1,
+				}
+			),
+			Error::<Test, frame_support::instances::Instance1>::InvalidProof
+		);
+
+		assert_err!(
+			RemoteProxySibling::remote_proxy(
+				RuntimeOrigin::signed(1),
+				anon,
+				None,
+				call,
+				RemoteProxyProof::SiblingParachain {
+					para_head_proof: para_head_proof.into_iter_nodes().collect(),
+					proof: Vec::new(),
+					block: 1,
+				}
+			),
+			Error::<Test, frame_support::instances::Instance1>::InvalidProof
+		);
+	});
+}
+
+/// Add a proxy with the given `delay` for `real` on the "remote" chain and prove it.
+///
+/// Returns the `proof` and the storage root of the "remote" chain.
+fn add_delayed_proxy_and_prove(real: u64, delegate: u64, delay: u64) -> (Vec<Vec<u8>>, H256) {
+	add_proxies_and_prove(real, &[(delegate, ProxyType::Any, delay)])
+}
+
+/// Add the given `(delegate, proxy_type, delay)` proxies for `real` on the "remote" chain and
+/// prove them.
+///
+/// Returns the `proof` and the storage root of the "remote" chain.
+fn add_proxies_and_prove(real: u64, proxies: &[(u64, ProxyType, u64)]) -> (Vec<Vec<u8>>, H256) {
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		for (delegate, proxy_type, delay) in proxies {
+			assert_ok!(Proxy::add_proxy(
+				RuntimeOrigin::signed(real),
+				*delegate,
+				*proxy_type,
+				*delay
+			));
+		}
+	});
+
+	let proof = sp_state_machine::prove_read(
+		ext.as_backend(),
+		[pallet_proxy::Proxies::<Test>::hashed_key_for(real)],
+	)
+	.unwrap();
+
+	(proof.into_iter_nodes().collect(), *ext.as_backend().root())
+}
+
+#[test]
+fn remote_proxy_announced_works() {
+	let (proof, root) = add_delayed_proxy_and_prove(1, 2, 2);
+
+	new_test_ext().execute_with(|| {
+		let call = Box::new(call_transfer(6, 1));
+		let call_hash = BlakeTwo256::hash_of(&call);
+		let proof = RemoteProxyProof::RelayChain { proof, block: 1 };
+
+		RelayStateOracle::on_validation_data(&PersistedValidationData {
+			parent_head: vec![].into(),
+			relay_parent_number: 1,
+			relay_parent_storage_root: root,
+			max_pov_size: 5000000,
+		});
+
+		// Delayed proxies can not be used directly.
+		assert_err!(
+			RemoteProxy::remote_proxy(
+				RuntimeOrigin::signed(2),
+				1,
+				None,
+				call.clone(),
+				proof.clone()
+			),
+			Error::<Test>::Unannounced
+		);
+
+		// Only remote proxies of `real` can announce.
+		assert_err!(
+			RemoteProxy::announce(RuntimeOrigin::signed(3), 1, call_hash, proof.clone()),
+			Error::<Test>::DidNotFindMatchingProxyDefinition
+		);
+
+		assert_ok!(RemoteProxy::announce(RuntimeOrigin::signed(2), 1, call_hash, proof.clone()));
+		System::assert_last_event(Event::<Test>::Announced { real: 1, proxy: 2, call_hash }.into());
+		assert_eq!(Balances::reserved_balance(2), 2);
+		assert_eq!(Announcements::<Test>::get(2).0.len(), 1);
+
+		// The delay did not pass yet.
+		assert_err!(
+			RemoteProxy::remote_proxy_announced(
+				RuntimeOrigin::signed(3),
+				2,
+				1,
+				None,
+				call.clone(),
+				proof.clone()
+			),
+			Error::<Test>::Unannounced
+		);
+
+		System::set_block_number(3);
+
+		// Only the announced call can be dispatched.
+		assert_err!(
+			RemoteProxy::remote_proxy_announced(
+				RuntimeOrigin::signed(3),
+				2,
+				1,
+				None,
+				Box::new(call_transfer(6, 2)),
+				proof.clone()
+			),
+			Error::<Test>::Unannounced
+		);
 
-		for i in 31u32..=40u32 {
-			RemoteProxy::on_validation_data(&PersistedValidationData {
+		assert_ok!(RemoteProxy::remote_proxy_announced(
+			RuntimeOrigin::signed(3),
+			2,
+			1,
+			None,
+			call.clone(),
+			proof.clone()
+		));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_eq!(Balances::free_balance(6), 1);
+
+		// The announcement is consumed and the deposit returned.
+		assert!(!Announcements::<Test>::contains_key(2));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_err!(
+			RemoteProxy::remote_proxy_announced(RuntimeOrigin::signed(3), 2, 1, None, call, proof),
+			Error::<Test>::Unannounced
+		);
+	});
+}
+
+#[test]
+fn remove_and_reject_announcement_works() {
+	let (proof, root) = add_delayed_proxy_and_prove(1, 2, 2);
+
+	new_test_ext().execute_with(|| {
+		let call = Box::new(call_transfer(6, 1));
+		let call_hash = BlakeTwo256::hash_of(&call);
+		let proof = RemoteProxyProof::RelayChain { proof, block: 1 };
+
+		RelayStateOracle::on_validation_data(&PersistedValidationData {
+			parent_head: vec![].into(),
+			relay_parent_number: 1,
+			relay_parent_storage_root: root,
+			max_pov_size: 5000000,
+		});
+
+		assert_ok!(RemoteProxy::announce(RuntimeOrigin::signed(2), 1, call_hash, proof.clone()));
+		assert_ok!(RemoteProxy::announce(RuntimeOrigin::signed(2), 1, H256::zero(), proof.clone()));
+		assert_err!(
+			RemoteProxy::announce(RuntimeOrigin::signed(2), 1, call_hash, proof.clone()),
+			Error::<Test>::TooMany
+		);
+		assert_eq!(Balances::reserved_balance(2), 3);
+
+		// Only the announcing proxy can remove its announcement.
+		assert_err!(
+			RemoteProxy::remove_announcement(RuntimeOrigin::signed(1), 1, H256::zero()),
+			Error::<Test>::NotFound
+		);
+		assert_ok!(RemoteProxy::remove_announcement(RuntimeOrigin::signed(2), 1, H256::zero()));
+		assert_eq!(Balances::reserved_balance(2), 2);
+
+		// Only the real account can reject the announcement.
+		assert_err!(
+			RemoteProxy::reject_announcement(RuntimeOrigin::signed(3), 2, call_hash),
+			Error::<Test>::NotFound
+		);
+		assert_ok!(RemoteProxy::reject_announcement(RuntimeOrigin::signed(1), 2, call_hash));
+		assert!(!Announcements::<Test>::contains_key(2));
+		assert_eq!(Balances::reserved_balance(2), 0);
+
+		System::set_block_number(3);
+
+		assert_err!(
+			RemoteProxy::remote_proxy_announced(RuntimeOrigin::signed(2), 2, 1, None, call, proof),
+			Error::<Test>::Unannounced
+		);
+		assert_eq!(Balances::free_balance(6), 0);
+	});
+}
+
+#[test]
+fn block_time_delay_converter_works() {
+	type SlowerLocal = BlockTimeDelayConverter<ConstU64<6_000>, ConstU64<12_000>>;
+	type FasterLocal = BlockTimeDelayConverter<ConstU64<12_000>, ConstU64<6_000>>;
+
+	assert_eq!(<SlowerLocal as Convert<u32, Option<u32>>>::convert(0), Some(0));
+	assert_eq!(<SlowerLocal as Convert<u32, Option<u32>>>::convert(4), Some(2));
+	// The delay is rounded up.
+	assert_eq!(<SlowerLocal as Convert<u32, Option<u32>>>::convert(5), Some(3));
+	assert_eq!(<FasterLocal as Convert<u32, Option<u32>>>::convert(5), Some(10));
+	// The converted delay does not fit into the local block number.
+	assert_eq!(<FasterLocal as Convert<u32, Option<u32>>>::convert(u32::MAX), None);
+	assert_eq!(
+		<BlockTimeDelayConverter<ConstU64<6_000>, ConstU64<0>> as Convert<u32, Option<u32>>>::convert(1),
+		None
+	);
+}
+
+#[test]
+fn runtime_api_helpers_work() {
+	let (proof, root) = add_delayed_proxy_and_prove(1, 2, 2);
+
+	new_test_ext().execute_with(|| {
+		assert!(RemoteProxy::known_storage_roots().is_empty());
+		assert_eq!(RemoteProxy::recommended_anchor_block(), None);
+
+		for (block, root) in [(1, root), (2, H256::zero())] {
+			RelayStateOracle::on_validation_data(&PersistedValidationData {
 				parent_head: vec![].into(),
-				relay_parent_number: dbg!(i),
+				relay_parent_number: block,
 				relay_parent_storage_root: root,
 				max_pov_size: 5000000,
 			});
 		}
 
-		BlockToRoot::<Test>::get()
-			.iter()
-			.for_each(|(b, _)| assert!(*b >= 31 && *b <= 40));
+		assert_eq!(RemoteProxy::known_storage_roots(), vec![(1, root), (2, H256::zero())]);
+		assert_eq!(RemoteProxy::recommended_anchor_block(), Some(2));
+
+		let proof = RemoteProxyProof::RelayChain { proof, block: 1 };
+
+		assert_eq!(
+			RemoteProxy::verify_remote_proxy_proof(1, 2, None, proof.clone()),
+			Ok(ProxyDefinition { delegate: 2, proxy_type: ProxyType::Any, delay: 2 })
+		);
+		assert_eq!(
+			RemoteProxy::verify_remote_proxy_proof(1, 2, Some(ProxyType::Any), proof.clone()),
+			Ok(ProxyDefinition { delegate: 2, proxy_type: ProxyType::Any, delay: 2 })
+		);
+		assert_err!(
+			RemoteProxy::verify_remote_proxy_proof(
+				1,
+				2,
+				Some(ProxyType::JustTransfer),
+				proof.clone()
+			),
+			Error::<Test>::DidNotFindMatchingProxyDefinition
+		);
+		assert_err!(
+			RemoteProxy::verify_remote_proxy_proof(1, 3, None, proof),
+			Error::<Test>::DidNotFindMatchingProxyDefinition
+		);
+		assert_err!(
+			RemoteProxy::verify_remote_proxy_proof(
+				1,
+				2,
+				None,
+				RemoteProxyProof::RelayChain { proof: Vec::new(), block: 3 }
+			),
+			Error::<Test>::UnknownProofAnchorBlock
+		);
+
+		// Nothing was dispatched.
+		assert!(System::events().is_empty());
+	});
+}
+
+#[test]
+fn remote_pure_proxy_works() {
+	let mut ext = new_test_ext();
+
+	let anon = ext.execute_with(|| {
+		Balances::make_free_balance_be(&1, 11); // An extra one for the ED.
+		assert_ok!(Proxy::create_pure(RuntimeOrigin::signed(1), ProxyType::Any, 0, 0));
+		Proxy::pure_account(&1, &ProxyType::Any, 0, None)
+	});
+
+	let proof = sp_state_machine::prove_read(
+		ext.as_backend(),
+		[pallet_proxy::Proxies::<Test>::hashed_key_for(anon)],
+	)
+	.unwrap();
+	let root = *ext.as_backend().root();
+
+	new_test_ext().execute_with(|| {
+		let call = Box::new(call_transfer(6, 1));
+		let proof =
+			RemoteProxyProof::RelayChain { proof: proof.into_iter_nodes().collect(), block: 1 };
+		let pure = RemotePureProxy {
+			spawner: 1,
+			proxy_type: ProxyType::Any,
+			index: 0,
+			height: 1,
+			ext_index: 0,
+		};
+		assert_eq!(RemoteProxy::remote_pure_account(pure.clone()), Ok(anon));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), anon, 5));
+
+		RelayStateOracle::on_validation_data(&PersistedValidationData {
+			parent_head: vec![].into(),
+			relay_parent_number: 1,
+			relay_parent_storage_root: root,
+			max_pov_size: 5000000,
+		});
+
+		// Only the proxies of the pure proxy can use it.
+		assert_err!(
+			RemoteProxy::remote_pure_proxy(
+				RuntimeOrigin::signed(2),
+				pure.clone(),
+				None,
+				call.clone(),
+				proof.clone()
+			),
+			Error::<Test>::DidNotFindMatchingProxyDefinition
+		);
+
+		// A different pure proxy is not part of the proof.
+		assert_err!(
+			RemoteProxy::remote_pure_proxy(
+				RuntimeOrigin::signed(1),
+				RemotePureProxy { index: 1, ..pure.clone() },
+				None,
+				call.clone(),
+				proof.clone()
+			),
+			Error::<Test>::InvalidProof
+		);
+
+		assert_ok!(RemoteProxy::remote_pure_proxy(
+			RuntimeOrigin::signed(1),
+			pure,
+			None,
+			call,
+			proof
+		));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_eq!(Balances::free_balance(6), 1);
+		assert_eq!(Balances::free_balance(anon), 4);
+	});
+}
+
+#[test]
+fn local_pure_proxy_creation_and_cleanup_works() {
+	let (proof, root) =
+		add_proxies_and_prove(1, &[(2, ProxyType::Any, 0), (3, ProxyType::JustUtility, 0)]);
+
+	new_test_ext().execute_with(|| {
+		let proof = RemoteProxyProof::RelayChain { proof, block: 1 };
+
+		RelayStateOracle::on_validation_data(&PersistedValidationData {
+			parent_head: vec![].into(),
+			relay_parent_number: 1,
+			relay_parent_storage_root: root,
+			max_pov_size: 5000000,
+		});
+
+		assert_ok!(RemoteProxy::remote_proxy(
+			RuntimeOrigin::signed(2),
+			1,
+			None,
+			Box::new(RuntimeCall::Proxy(pallet_proxy::Call::create_pure {
+				proxy_type: ProxyType::Any,
+				delay: 0,
+				index: 0,
+			})),
+			proof.clone()
+		));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		let pure = Proxy::pure_account(&1, &ProxyType::Any, 0, None);
+		assert_eq!(pallet_proxy::Proxies::<Test>::get(pure).0.len(), 1);
+		assert_eq!(Balances::reserved_balance(1), 2);
+
+		// Removing all proxies requires an `Any` proxy.
+		assert_ok!(RemoteProxy::remote_proxy(
+			RuntimeOrigin::signed(3),
+			1,
+			None,
+			Box::new(RuntimeCall::Utility(UtilityCall::batch {
+				calls: vec![RuntimeCall::Proxy(pallet_proxy::Call::remove_proxies {})]
+			})),
+			proof.clone()
+		));
+		System::assert_has_event(
+			pallet_utility::Event::BatchInterrupted {
+				index: 0,
+				error: frame_system::Error::<Test>::CallFiltered.into(),
+			}
+			.into(),
+		);
+
+		assert_ok!(RemoteProxy::remote_proxy(
+			RuntimeOrigin::signed(2),
+			1,
+			None,
+			Box::new(RuntimeCall::Proxy(pallet_proxy::Call::proxy {
+				real: pure,
+				force_proxy_type: None,
+				call: Box::new(RuntimeCall::Proxy(pallet_proxy::Call::kill_pure {
+					spawner: 1,
+					proxy_type: ProxyType::Any,
+					index: 0,
+					height: 1,
+					ext_index: 0,
+				})),
+			})),
+			proof
+		));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert!(pallet_proxy::Proxies::<Test>::get(pure).0.is_empty());
+		assert_eq!(Balances::reserved_balance(1), 0);
 	});
 }
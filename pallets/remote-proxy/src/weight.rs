@@ -21,6 +21,10 @@ pub trait WeightInfo {
 	fn remote_proxy_with_registered_proof() -> Weight;
 	fn register_remote_proxy_proof() -> Weight;
 	fn remote_proxy() -> Weight;
+	fn announce(a: u32) -> Weight;
+	fn remove_announcement(a: u32) -> Weight;
+	fn reject_announcement(a: u32) -> Weight;
+	fn remote_proxy_announced(a: u32) -> Weight;
 }
 
 impl WeightInfo for () {
@@ -35,4 +39,20 @@ impl WeightInfo for () {
 	fn remote_proxy() -> Weight {
 		Weight::MAX
 	}
+
+	fn announce(_: u32) -> Weight {
+		Weight::MAX
+	}
+
+	fn remove_announcement(_: u32) -> Weight {
+		Weight::MAX
+	}
+
+	fn reject_announcement(_: u32) -> Weight {
+		Weight::MAX
+	}
+
+	fn remote_proxy_announced(_: u32) -> Weight {
+		Weight::MAX
+	}
 }
@@ -0,0 +1,462 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Treasury spend tracker pallet
+//!
+//! Once a spend of `pallet_treasury` is paid out, somebody needs to call `check_status` to learn
+//! whether the payment succeeded and, if it failed, `payout` again. With `PayOverXcm` a payment
+//! fails for example if the beneficiary on Asset Hub would end up below the existential deposit.
+//! This pallet automates that follow-up from [`Hooks::on_idle`]:
+//!
+//! - Attempted payments are checked every [`Config::CheckInterval`] blocks. Payments without a
+//!   result after [`Config::PayoutTimeout`] are reported and queried again up to
+//!   [`Config::MaxRetries`] times. Afterwards they are considered failed.
+//! - Failed payments are retried up to [`Config::MaxRetries`] times.
+//! - Failed spends that expired are removed from the treasury.
+//!
+//! The spends are visited in batches that fit into the remaining weight of a block, the position
+//! is kept in [`NextCursor`]. After all spends were visited, the tracked spends that were processed
+//! elsewhere are pruned. [`Pallet::spends`] lists the pending, failed and recently succeeded
+//! spends together with the query ids of their payments, see [`runtime_api`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod runtime_api;
+#[cfg(test)]
+mod tests;
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	storage::with_storage_layer,
+	traits::tokens::Pay,
+	weights::{Weight, WeightMeter},
+	PalletId,
+};
+use pallet_treasury::{PaymentState, SpendIndex, Spends, WeightInfo as _};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AccountIdConversion, BlockNumberProvider, Saturating},
+	DispatchError,
+};
+
+pub use pallet::*;
+
+/// The id of a payment of the treasury, e.g. the XCM query id for `PayOverXcm`.
+pub type QueryIdOf<T> = <<T as pallet_treasury::Config>::Paymaster as Pay>::Id;
+
+/// The follow-up state of a paid out spend.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct TrackedSpend<QueryId, BlockNumber> {
+	/// The query id of the latest payment, if known.
+	pub query_id: Option<QueryId>,
+	/// The block the latest payment was first seen.
+	pub attempted_at: BlockNumber,
+	/// The block the spend was last checked or retried.
+	pub checked_at: BlockNumber,
+	/// The number of payments retried by this pallet.
+	pub retries: u32,
+	/// Whether the latest payment is known to have failed.
+	pub failed: bool,
+	/// Whether the latest payment got no result within [`Config::PayoutTimeout`].
+	pub timed_out: bool,
+	/// The number of times the latest payment was queried again after it timed out.
+	pub requeries: u32,
+}
+
+/// The position of [`Hooks::on_idle`].
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, Debug, Default)]
+pub enum Cursor {
+	/// Following up on the treasury spends after the given one.
+	#[default]
+	Spends(Option<SpendIndex>),
+	/// Removing the [`Tracked`] spends after the given one that were processed elsewhere.
+	Tracked(Option<SpendIndex>),
+}
+
+/// The state of a treasury spend as reported by [`runtime_api::TreasurySpendsApi`].
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub enum SpendState<QueryId> {
+	/// The spend is not paid out yet or the result of its payment is not known yet.
+	Pending {
+		/// The query id of the latest payment, if any.
+		query_id: Option<QueryId>,
+		/// The number of retried payments.
+		retries: u32,
+		/// Whether the latest payment got no result within [`Config::PayoutTimeout`].
+		timed_out: bool,
+	},
+	/// The latest payment failed.
+	Failed {
+		/// The query id of the failed payment, if known.
+		query_id: Option<QueryId>,
+		/// The number of retried payments.
+		retries: u32,
+	},
+	/// The spend was paid out successfully.
+	Succeeded {
+		/// The query id of the successful payment.
+		query_id: QueryId,
+	},
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	pub(crate) type TrackedSpendOf<T> = TrackedSpend<QueryIdOf<T>, BlockNumberFor<T>>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_treasury::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The id of the account that checks and retries the payments.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The maximum number of times a failed payment is retried.
+		#[pallet::constant]
+		type MaxRetries: Get<u32>;
+
+		/// The number of blocks between two checks of the same spend.
+		#[pallet::constant]
+		type CheckInterval: Get<BlockNumberFor<Self>>;
+
+		/// The number of blocks after which a payment without a result is reported.
+		///
+		/// Should match the timeout of the treasury `Paymaster`. A payment that still has no result
+		/// after [`Config::MaxRetries`] further checks is considered failed and paid out again,
+		/// so the timeout must be long enough for a payment to be executed.
+		#[pallet::constant]
+		type PayoutTimeout: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of succeeded spends kept in [`Succeeded`].
+		#[pallet::constant]
+		type MaxSucceeded: Get<u32>;
+	}
+
+	/// The follow-up state of the paid out spends.
+	#[pallet::storage]
+	pub type Tracked<T: Config> =
+		StorageMap<_, Twox64Concat, SpendIndex, TrackedSpendOf<T>, OptionQuery>;
+
+	/// The most recently succeeded spends with the query ids of their payments, oldest first.
+	#[pallet::storage]
+	pub type Succeeded<T: Config> =
+		StorageValue<_, BoundedVec<(SpendIndex, QueryIdOf<T>), T::MaxSucceeded>, ValueQuery>;
+
+	/// Where [`Hooks::on_idle`] continues.
+	#[pallet::storage]
+	pub type NextCursor<T: Config> = StorageValue<_, Cursor, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The payment of spend `index` succeeded.
+		PayoutSucceeded { index: SpendIndex, query_id: QueryIdOf<T> },
+		/// The payment of spend `index` failed after `retries` retries.
+		PayoutFailed { index: SpendIndex, query_id: QueryIdOf<T>, retries: u32 },
+		/// The payment of spend `index` got no result within [`Config::PayoutTimeout`].
+		PayoutTimedOut { index: SpendIndex, query_id: QueryIdOf<T> },
+		/// The timed out payment of spend `index` was queried again for the `attempt`th time
+		/// without a result.
+		PayoutRequeried { index: SpendIndex, attempt: u32, query_id: QueryIdOf<T> },
+		/// The timed out payment of spend `index` got no result after [`Config::MaxRetries`]
+		/// further queries and is considered failed.
+		PayoutAbandoned { index: SpendIndex, query_id: QueryIdOf<T> },
+		/// The failed payment of spend `index` was retried for the `attempt`th time.
+		PayoutRetried { index: SpendIndex, attempt: u32, query_id: QueryIdOf<T> },
+		/// Retrying the failed payment of spend `index` for the `attempt`th time failed.
+		RetryFailed { index: SpendIndex, attempt: u32, error: DispatchError },
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			if meter.try_consume(T::DbWeight::get().reads_writes(1, 1)).is_err() {
+				return Weight::zero()
+			}
+
+			let mut cursor = NextCursor::<T>::get();
+			if let Cursor::Spends(last) = cursor {
+				cursor = Self::follow_up_spends(now, last, &mut meter);
+			}
+			if let Cursor::Tracked(last) = cursor {
+				cursor = Self::prune_tracked(last, &mut meter);
+			}
+			NextCursor::<T>::put(cursor);
+
+			meter.consumed()
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account that checks and retries the payments.
+		pub fn account_id() -> T::AccountId {
+			<T as Config>::PalletId::get().into_account_truncating()
+		}
+
+		/// All pending and failed spends of the treasury and the most recently succeeded ones.
+		pub fn spends() -> Vec<(SpendIndex, SpendState<QueryIdOf<T>>)> {
+			let mut spends: Vec<_> = Spends::<T>::iter()
+				.map(|(index, spend)| {
+					let tracked = Tracked::<T>::get(index);
+					let retries = tracked.as_ref().map_or(0, |tracked| tracked.retries);
+					let state = match spend.status {
+						PaymentState::Pending =>
+							SpendState::Pending { query_id: None, retries, timed_out: false },
+						PaymentState::Attempted { id } => SpendState::Pending {
+							query_id: Some(id),
+							retries,
+							timed_out: tracked
+								.filter(|tracked| tracked.query_id == Some(id))
+								.is_some_and(|tracked| tracked.timed_out),
+						},
+						PaymentState::Failed => SpendState::Failed {
+							query_id: tracked.and_then(|tracked| tracked.query_id),
+							retries,
+						},
+					};
+					(index, state)
+				})
+				.collect();
+			spends.extend(
+				Succeeded::<T>::get()
+					.into_iter()
+					.map(|(index, query_id)| (index, SpendState::Succeeded { query_id })),
+			);
+			spends
+		}
+
+		/// The worst case weight of following up on a single spend.
+		fn follow_up_weight() -> Weight {
+			let call = <T as pallet_treasury::Config>::WeightInfo::check_status()
+				.max(<T as pallet_treasury::Config>::WeightInfo::payout());
+			T::DbWeight::get().reads_writes(3, 3).saturating_add(call)
+		}
+
+		/// Follow up on the spends after `last` as long as `meter` allows.
+		///
+		/// Returns where to continue.
+		fn follow_up_spends(
+			now: BlockNumberFor<T>,
+			mut last: Option<SpendIndex>,
+			meter: &mut WeightMeter,
+		) -> Cursor {
+			let weight = Self::follow_up_weight();
+			let mut spends = match last {
+				Some(last) => Spends::<T>::iter_from(Spends::<T>::hashed_key_for(last)),
+				None => Spends::<T>::iter(),
+			};
+
+			loop {
+				if meter.try_consume(weight).is_err() {
+					return Cursor::Spends(last)
+				}
+				let Some((index, spend)) = spends.next() else { return Cursor::Tracked(None) };
+				let expired =
+					<T as pallet_treasury::Config>::BlockNumberProvider::current_block_number() >
+						spend.expire_at;
+				match spend.status {
+					PaymentState::Pending => {},
+					PaymentState::Attempted { id } => Self::check_attempted(now, index, id),
+					PaymentState::Failed if expired => Self::remove_expired(index),
+					PaymentState::Failed => Self::retry_failed(now, index),
+				}
+				last = Some(index);
+			}
+		}
+
+		/// Check the status of the attempted payment `query_id` of spend `index`.
+		fn check_attempted(now: BlockNumberFor<T>, index: SpendIndex, query_id: QueryIdOf<T>) {
+			let mut tracked = match Tracked::<T>::get(index) {
+				Some(tracked) if tracked.query_id == Some(query_id) => {
+					if now < tracked.checked_at.saturating_add(T::CheckInterval::get()) {
+						return
+					}
+					tracked
+				},
+				// The payment was attempted outside of this pallet.
+				tracked => TrackedSpend {
+					query_id: Some(query_id),
+					attempted_at: now,
+					checked_at: now,
+					retries: tracked.map_or(0, |tracked| tracked.retries),
+					failed: false,
+					timed_out: false,
+					requeries: 0,
+				},
+			};
+
+			let _ = with_storage_layer(|| {
+				pallet_treasury::Pallet::<T>::check_status(Self::origin(), index)
+			});
+			tracked.checked_at = now;
+
+			match Spends::<T>::get(index).map(|spend| spend.status) {
+				None => {
+					Tracked::<T>::remove(index);
+					Self::note_succeeded(index, query_id);
+				},
+				Some(PaymentState::Failed) => {
+					tracked.failed = true;
+					Self::deposit_event(Event::PayoutFailed {
+						index,
+						query_id,
+						retries: tracked.retries,
+					});
+					Tracked::<T>::insert(index, tracked);
+				},
+				Some(_) if now < tracked.attempted_at.saturating_add(T::PayoutTimeout::get()) =>
+					Tracked::<T>::insert(index, tracked),
+				Some(_) if !tracked.timed_out => {
+					tracked.timed_out = true;
+					Self::deposit_event(Event::PayoutTimedOut { index, query_id });
+					Tracked::<T>::insert(index, tracked);
+				},
+				Some(_) if tracked.requeries < T::MaxRetries::get() => {
+					tracked.requeries.saturating_inc();
+					Self::deposit_event(Event::PayoutRequeried {
+						index,
+						attempt: tracked.requeries,
+						query_id,
+					});
+					Tracked::<T>::insert(index, tracked);
+				},
+				Some(_) => {
+					// Mark the payment as failed in the treasury, so that it can be paid out again.
+					Spends::<T>::mutate(index, |spend| {
+						if let Some(spend) = spend {
+							spend.status = PaymentState::Failed;
+						}
+					});
+					tracked.failed = true;
+					Self::deposit_event(Event::PayoutAbandoned { index, query_id });
+					Tracked::<T>::insert(index, tracked);
+				},
+			}
+		}
+
+		/// Retry the failed payment of spend `index`.
+		fn retry_failed(now: BlockNumberFor<T>, index: SpendIndex) {
+			let mut tracked = match Tracked::<T>::get(index) {
+				Some(tracked) => {
+					if now < tracked.checked_at.saturating_add(T::CheckInterval::get()) {
+						return
+					}
+					tracked
+				},
+				// The payment failed outside of this pallet.
+				None => TrackedSpend {
+					query_id: None,
+					attempted_at: now,
+					checked_at: now,
+					retries: 0,
+					failed: true,
+					timed_out: false,
+					requeries: 0,
+				},
+			};
+			if tracked.retries >= T::MaxRetries::get() {
+				return
+			}
+
+			tracked.retries.saturating_inc();
+			tracked.checked_at = now;
+			let attempt = tracked.retries;
+			let result =
+				with_storage_layer(|| pallet_treasury::Pallet::<T>::payout(Self::origin(), index));
+			match (result, Spends::<T>::get(index).map(|spend| spend.status)) {
+				(Ok(_), Some(PaymentState::Attempted { id })) => {
+					tracked.query_id = Some(id);
+					tracked.attempted_at = now;
+					tracked.failed = false;
+					tracked.timed_out = false;
+					tracked.requeries = 0;
+					Self::deposit_event(Event::PayoutRetried { index, attempt, query_id: id });
+				},
+				(result, _) => Self::deposit_event(Event::RetryFailed {
+					index,
+					attempt,
+					error: result.err().unwrap_or(DispatchError::Other("Unexpected spend state")),
+				}),
+			}
+			Tracked::<T>::insert(index, tracked);
+		}
+
+		/// Remove the failed and expired spend `index` from the treasury.
+		fn remove_expired(index: SpendIndex) {
+			let _ = with_storage_layer(|| {
+				pallet_treasury::Pallet::<T>::check_status(Self::origin(), index)
+			});
+			if !Spends::<T>::contains_key(index) {
+				Tracked::<T>::remove(index);
+			}
+		}
+
+		/// Remove the tracked spends after `last` that are no longer known to the treasury as
+		/// long as `meter` allows.
+		///
+		/// Spends whose latest payment was not known to have failed were processed successfully.
+		fn prune_tracked(last: Option<SpendIndex>, meter: &mut WeightMeter) -> Cursor {
+			let weight = T::DbWeight::get().reads_writes(3, 2);
+			let mut tracked = match last {
+				Some(last) => Tracked::<T>::iter_from(Tracked::<T>::hashed_key_for(last)),
+				None => Tracked::<T>::iter(),
+			};
+			let mut last = last;
+
+			loop {
+				if meter.try_consume(weight).is_err() {
+					return Cursor::Tracked(last)
+				}
+				let Some((index, spend)) = tracked.next() else { return Cursor::Spends(None) };
+				if !Spends::<T>::contains_key(index) {
+					Tracked::<T>::remove(index);
+					if let (false, Some(query_id)) = (spend.failed, spend.query_id) {
+						Self::note_succeeded(index, query_id);
+					}
+				}
+				last = Some(index);
+			}
+		}
+
+		/// Record the successful payment `query_id` of spend `index`.
+		fn note_succeeded(index: SpendIndex, query_id: QueryIdOf<T>) {
+			Succeeded::<T>::mutate(|succeeded| {
+				if succeeded.is_full() {
+					succeeded.remove(0);
+				}
+				let _ = succeeded.try_push((index, query_id));
+			});
+			Self::deposit_event(Event::PayoutSucceeded { index, query_id });
+		}
+
+		fn origin() -> OriginFor<T> {
+			frame_system::RawOrigin::Signed(Self::account_id()).into()
+		}
+	}
+}
@@ -0,0 +1,34 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the treasury spend tracker pallet.
+
+use crate::SpendState;
+use alloc::vec::Vec;
+use codec::Codec;
+use pallet_treasury::SpendIndex;
+
+sp_api::decl_runtime_apis! {
+	/// API to follow the payouts of the treasury spends.
+	pub trait TreasurySpendsApi<QueryId>
+	where
+		QueryId: Codec,
+	{
+		/// Returns all pending and failed spends of the treasury and the most recently succeeded
+		/// ones together with the query ids of their payments.
+		fn spends() -> Vec<(SpendIndex, SpendState<QueryId>)>;
+	}
+}
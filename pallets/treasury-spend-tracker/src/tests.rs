@@ -0,0 +1,414 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+// Tests for Treasury Spend Tracker Pallet
+
+use super::*;
+use crate as treasury_spend_tracker;
+use core::cell::RefCell;
+use frame_support::{
+	assert_ok, construct_runtime, derive_impl, parameter_types,
+	traits::{
+		tokens::{PaymentStatus, UnityAssetBalanceConversion},
+		Hooks,
+	},
+};
+use frame_system::EnsureRootWithSuccess;
+use sp_core::{ConstU32, ConstU64};
+use sp_io::TestExternalities;
+use sp_runtime::{traits::IdentityLookup, BuildStorage, Permill};
+use std::collections::BTreeMap;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		Balances: pallet_balances,
+		Treasury: pallet_treasury,
+		TreasurySpendTracker: treasury_spend_tracker,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+thread_local! {
+	static PAYMENTS: RefCell<BTreeMap<u64, PaymentStatus>> = RefCell::new(BTreeMap::new());
+	static NEXT_PAYMENT: RefCell<u64> = RefCell::new(0);
+}
+
+/// Records the payments, whose status is set by the tests.
+pub struct TestPay;
+
+impl Pay for TestPay {
+	type Beneficiary = u64;
+	type Balance = u64;
+	type Id = u64;
+	type AssetKind = u32;
+	type Error = ();
+
+	fn pay(_: &u64, _: u32, _: u64) -> Result<u64, ()> {
+		let id = NEXT_PAYMENT.with(|next| {
+			let id = *next.borrow();
+			*next.borrow_mut() += 1;
+			id
+		});
+		PAYMENTS.with(|payments| payments.borrow_mut().insert(id, PaymentStatus::InProgress));
+		Ok(id)
+	}
+
+	fn check_payment(id: u64) -> PaymentStatus {
+		PAYMENTS
+			.with(|payments| payments.borrow().get(&id).copied())
+			.unwrap_or(PaymentStatus::Unknown)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful(_: &u64, _: u32, _: u64) {}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_concluded(id: u64) {
+		set_status(id, PaymentStatus::Failure);
+	}
+}
+
+fn set_status(id: u64, status: PaymentStatus) {
+	PAYMENTS.with(|payments| payments.borrow_mut().insert(id, status));
+}
+
+parameter_types! {
+	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+	pub const TrackerPalletId: PalletId = PalletId(*b"py/trsrt");
+	pub const Burn: Permill = Permill::zero();
+}
+
+impl pallet_treasury::Config for Test {
+	type PalletId = TreasuryPalletId;
+	type Currency = Balances;
+	type RejectOrigin = frame_system::EnsureRoot<u64>;
+	type RuntimeEvent = RuntimeEvent;
+	type SpendPeriod = ConstU64<2>;
+	type Burn = Burn;
+	type BurnDestination = ();
+	type WeightInfo = ();
+	type SpendFunds = ();
+	type MaxApprovals = ConstU32<100>;
+	type SpendOrigin = EnsureRootWithSuccess<u64, ConstU64<1000>>;
+	type AssetKind = u32;
+	type Beneficiary = u64;
+	type BeneficiaryLookup = IdentityLookup<u64>;
+	type Paymaster = TestPay;
+	type BalanceConverter = UnityAssetBalanceConversion;
+	type PayoutPeriod = ConstU64<100>;
+	type BlockNumberProvider = System;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type PalletId = TrackerPalletId;
+	type MaxRetries = ConstU32<2>;
+	type CheckInterval = ConstU64<5>;
+	type PayoutTimeout = ConstU64<20>;
+	type MaxSucceeded = ConstU32<2>;
+}
+
+pub fn new_test_ext() -> TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// Approve a spend of `amount` and pay it out, returning the spend index and the query id.
+fn paid_spend(amount: u64) -> (SpendIndex, u64) {
+	let index = pallet_treasury::SpendCount::<Test>::get();
+	assert_ok!(Treasury::spend(RuntimeOrigin::root(), Box::new(1), amount, Box::new(10), None));
+	assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), index));
+	let Some(PaymentState::Attempted { id }) = Spends::<Test>::get(index).map(|s| s.status) else {
+		panic!("spend {index} was not paid out");
+	};
+	(index, id)
+}
+
+/// Run `on_idle` with enough weight for all spends at block `n`.
+fn idle_at(n: u64) {
+	System::set_block_number(n);
+	TreasurySpendTracker::on_idle(n, Weight::MAX);
+}
+
+fn tracker_events() -> Vec<Event<Test>> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::TreasurySpendTracker(event) => Some(event),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn successful_payout_is_recorded() {
+	new_test_ext().execute_with(|| {
+		let (index, query_id) = paid_spend(100);
+
+		idle_at(2);
+		assert_eq!(
+			TreasurySpendTracker::spends(),
+			vec![(
+				index,
+				SpendState::Pending { query_id: Some(query_id), retries: 0, timed_out: false }
+			)]
+		);
+
+		set_status(query_id, PaymentStatus::Success);
+		// The next check is only due after the check interval.
+		idle_at(3);
+		assert!(Spends::<Test>::contains_key(index));
+
+		idle_at(7);
+		assert!(!Spends::<Test>::contains_key(index));
+		assert!(!Tracked::<Test>::contains_key(index));
+		assert_eq!(
+			TreasurySpendTracker::spends(),
+			vec![(index, SpendState::Succeeded { query_id })]
+		);
+		System::assert_last_event(Event::PayoutSucceeded { index, query_id }.into());
+	});
+}
+
+#[test]
+fn failed_payout_is_retried_a_bounded_number_of_times() {
+	new_test_ext().execute_with(|| {
+		let (index, first) = paid_spend(100);
+		set_status(first, PaymentStatus::Failure);
+
+		idle_at(2);
+		assert_eq!(
+			TreasurySpendTracker::spends(),
+			vec![(index, SpendState::Failed { query_id: Some(first), retries: 0 })]
+		);
+
+		// The first retry.
+		idle_at(7);
+		let Some(PaymentState::Attempted { id: second }) =
+			Spends::<Test>::get(index).map(|s| s.status)
+		else {
+			panic!("payment was not retried");
+		};
+		assert_ne!(first, second);
+		set_status(second, PaymentStatus::Failure);
+
+		// The second retry fails again.
+		idle_at(12);
+		idle_at(17);
+		let Some(PaymentState::Attempted { id: third }) =
+			Spends::<Test>::get(index).map(|s| s.status)
+		else {
+			panic!("payment was not retried");
+		};
+		set_status(third, PaymentStatus::Failure);
+		idle_at(22);
+
+		// No more retries.
+		idle_at(27);
+		idle_at(32);
+		assert_eq!(
+			TreasurySpendTracker::spends(),
+			vec![(index, SpendState::Failed { query_id: Some(third), retries: 2 })]
+		);
+		assert_eq!(
+			tracker_events(),
+			vec![
+				Event::PayoutFailed { index, query_id: first, retries: 0 },
+				Event::PayoutRetried { index, attempt: 1, query_id: second },
+				Event::PayoutFailed { index, query_id: second, retries: 1 },
+				Event::PayoutRetried { index, attempt: 2, query_id: third },
+				Event::PayoutFailed { index, query_id: third, retries: 2 },
+			]
+		);
+
+		// Once the spend expired, it is removed from the treasury.
+		idle_at(200);
+		assert!(!Spends::<Test>::contains_key(index));
+		assert!(!Tracked::<Test>::contains_key(index));
+		assert!(TreasurySpendTracker::spends().is_empty());
+	});
+}
+
+#[test]
+fn payout_without_result_times_out_and_is_queried_again() {
+	new_test_ext().execute_with(|| {
+		let (index, query_id) = paid_spend(100);
+
+		idle_at(2);
+		idle_at(17);
+		assert!(tracker_events().is_empty());
+
+		idle_at(22);
+		assert_eq!(tracker_events(), vec![Event::PayoutTimedOut { index, query_id }]);
+		assert_eq!(
+			TreasurySpendTracker::spends(),
+			vec![(
+				index,
+				SpendState::Pending { query_id: Some(query_id), retries: 0, timed_out: true }
+			)]
+		);
+
+		// The timeout is only reported once, afterwards the payment is queried again.
+		idle_at(27);
+		assert_eq!(
+			tracker_events(),
+			vec![
+				Event::PayoutTimedOut { index, query_id },
+				Event::PayoutRequeried { index, attempt: 1, query_id },
+			]
+		);
+
+		// A late response is still picked up.
+		set_status(query_id, PaymentStatus::Success);
+		idle_at(32);
+		assert!(!Spends::<Test>::contains_key(index));
+		System::assert_last_event(Event::PayoutSucceeded { index, query_id }.into());
+	});
+}
+
+#[test]
+fn timed_out_payout_is_requeried_a_bounded_number_of_times_and_retried() {
+	new_test_ext().execute_with(|| {
+		let (index, first) = paid_spend(100);
+
+		idle_at(2);
+		idle_at(22);
+		idle_at(27);
+		idle_at(32);
+		assert_eq!(
+			Spends::<Test>::get(index).map(|s| s.status),
+			Some(PaymentState::Attempted { id: first })
+		);
+
+		// Without a result after `MaxRetries` further queries, the payment is considered failed.
+		idle_at(37);
+		assert_eq!(Spends::<Test>::get(index).map(|s| s.status), Some(PaymentState::Failed));
+		assert_eq!(
+			TreasurySpendTracker::spends(),
+			vec![(index, SpendState::Failed { query_id: Some(first), retries: 0 })]
+		);
+
+		// ... and paid out again with a new query.
+		idle_at(42);
+		let Some(PaymentState::Attempted { id: second }) =
+			Spends::<Test>::get(index).map(|s| s.status)
+		else {
+			panic!("payment was not retried");
+		};
+		assert_ne!(first, second);
+		assert_eq!(
+			tracker_events(),
+			vec![
+				Event::PayoutTimedOut { index, query_id: first },
+				Event::PayoutRequeried { index, attempt: 1, query_id: first },
+				Event::PayoutRequeried { index, attempt: 2, query_id: first },
+				Event::PayoutAbandoned { index, query_id: first },
+				Event::PayoutRetried { index, attempt: 1, query_id: second },
+			]
+		);
+		assert_eq!(
+			Tracked::<Test>::get(index),
+			Some(TrackedSpend {
+				query_id: Some(second),
+				attempted_at: 42,
+				checked_at: 42,
+				retries: 1,
+				failed: false,
+				timed_out: false,
+				requeries: 0,
+			})
+		);
+
+		set_status(second, PaymentStatus::Success);
+		idle_at(47);
+		assert!(!Spends::<Test>::contains_key(index));
+		System::assert_last_event(Event::PayoutSucceeded { index, query_id: second }.into());
+	});
+}
+
+#[test]
+fn spends_processed_elsewhere_are_pruned() {
+	new_test_ext().execute_with(|| {
+		let (index, query_id) = paid_spend(100);
+		idle_at(2);
+		assert!(Tracked::<Test>::contains_key(index));
+
+		set_status(query_id, PaymentStatus::Success);
+		assert_ok!(Treasury::check_status(RuntimeOrigin::signed(1), index));
+
+		idle_at(3);
+		assert_eq!(NextCursor::<Test>::get(), Cursor::Spends(None));
+		assert!(!Tracked::<Test>::contains_key(index));
+		assert_eq!(Succeeded::<Test>::get().into_inner(), vec![(index, query_id)]);
+	});
+}
+
+#[test]
+fn on_idle_respects_the_weight_limit() {
+	new_test_ext().execute_with(|| {
+		let (first, _) = paid_spend(100);
+		let (second, _) = paid_spend(100);
+
+		let weight = <Test as frame_system::Config>::DbWeight::get()
+			.reads_writes(1, 1)
+			.saturating_add(Pallet::<Test>::follow_up_weight());
+		assert_eq!(TreasurySpendTracker::on_idle(2, weight), weight);
+
+		let visited = Tracked::<Test>::iter_keys().collect::<Vec<_>>();
+		assert_eq!(visited.len(), 1);
+		assert_eq!(NextCursor::<Test>::get(), Cursor::Spends(visited.first().copied()));
+
+		// Nothing is done without enough weight.
+		assert_eq!(TreasurySpendTracker::on_idle(2, Weight::zero()), Weight::zero());
+
+		idle_at(2);
+		assert!(Tracked::<Test>::contains_key(first));
+		assert!(Tracked::<Test>::contains_key(second));
+	});
+}
+
+#[test]
+fn succeeded_spends_are_bounded() {
+	new_test_ext().execute_with(|| {
+		let spends: Vec<_> = (0..3).map(|_| paid_spend(100)).collect();
+		for (_, query_id) in &spends {
+			set_status(*query_id, PaymentStatus::Success);
+		}
+
+		idle_at(2);
+		let mut succeeded = Succeeded::<Test>::get().into_inner();
+		assert_eq!(succeeded.len(), 2);
+		succeeded.retain(|spend| spends.contains(spend));
+		assert_eq!(succeeded.len(), 2);
+	});
+}
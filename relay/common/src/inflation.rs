@@ -0,0 +1,252 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Inflation logic shared between the relay chain runtimes.
+//!
+//! [`relay_era_payout`] follows `polkadot_runtime_common::impls::relay_era_payout` and only
+//! deviates from it if [`EraPayoutParams::cap_treasury`] is set.
+
+use polkadot_primitives::Balance;
+use sp_runtime::{traits::Zero, FixedU128, Perbill, Perquintill, Saturating};
+
+#[derive(Debug, Clone)]
+/// Parameters passed into [`relay_era_payout`] function.
+pub struct EraPayoutParams {
+	/// Total staked amount.
+	pub total_staked: Balance,
+	/// Total stakable amount.
+	///
+	/// Usually, this is equal to the total issuance, except if a large part of the issuance is
+	/// locked in another sub-system.
+	pub total_stakable: Balance,
+	/// Ideal stake ratio, which is reduced by `legacy_auction_proportion` if not `None`.
+	pub ideal_stake: Perquintill,
+	/// Maximum inflation rate.
+	pub max_annual_inflation: Perquintill,
+	/// Minimum inflation rate.
+	pub min_annual_inflation: Perquintill,
+	/// Falloff used to calculate era payouts.
+	pub falloff: Perquintill,
+	/// Fraction of the era period used to calculate era payouts.
+	pub period_fraction: Perquintill,
+	/// Legacy auction proportion, which, if not `None`, is subtracted from `ideal_stake`.
+	pub legacy_auction_proportion: Option<Perquintill>,
+	/// Whether to cap the treasury payout.
+	///
+	/// If `true` and more than half of the stakable amount is staked, the treasury payout is
+	/// limited to `other_issuance / total_staked` of the staking payout.
+	pub cap_treasury: bool,
+}
+
+/// A specialized function to compute the inflation of the staking system, tailored for Polkadot
+/// Relay Chains, such as Polkadot, Kusama, and Westend.
+///
+/// Returns `(staking_payout, treasury_payout)`.
+pub fn relay_era_payout(params: EraPayoutParams) -> (Balance, Balance) {
+	let EraPayoutParams {
+		total_staked,
+		total_stakable,
+		ideal_stake,
+		max_annual_inflation,
+		min_annual_inflation,
+		falloff,
+		period_fraction,
+		legacy_auction_proportion,
+		cap_treasury,
+	} = params;
+
+	let delta_annual_inflation = max_annual_inflation.saturating_sub(min_annual_inflation);
+
+	let ideal_stake = ideal_stake.saturating_sub(legacy_auction_proportion.unwrap_or_default());
+
+	let stake = Perquintill::from_rational(total_staked, total_stakable);
+	let adjustment = pallet_staking_reward_fn::compute_inflation(stake, ideal_stake, falloff);
+	let staking_inflation =
+		min_annual_inflation.saturating_add(delta_annual_inflation * adjustment);
+
+	let max_payout = period_fraction * max_annual_inflation * total_stakable;
+	let staking_payout = (period_fraction * staking_inflation) * total_stakable;
+	let mut rest = max_payout.saturating_sub(staking_payout);
+
+	let other_issuance = total_stakable.saturating_sub(total_staked);
+	if cap_treasury && total_staked > other_issuance {
+		let cap_rest = Perquintill::from_rational(other_issuance, total_staked) * staking_payout;
+		rest = rest.min(cap_rest);
+	}
+	(staking_payout, rest)
+}
+
+/// Estimate the annual yield of bonding `stake` as a validator and as a nominator.
+///
+/// The estimate assumes that the `annual_staking_payout` is shared equally between
+/// `validator_count` validators that are all backed by the same stake and charge the
+/// `average_commission`. Returns `(validator_apy, nominator_apy)`.
+pub fn estimate_staking_apy(
+	annual_staking_payout: Balance,
+	total_staked: Balance,
+	validator_count: u32,
+	average_commission: Perbill,
+	stake: Balance,
+) -> (FixedU128, FixedU128) {
+	if total_staked == 0 || validator_count == 0 || stake == 0 {
+		return (FixedU128::zero(), FixedU128::zero())
+	}
+
+	let validator_payout = annual_staking_payout / Balance::from(validator_count);
+	let validator_backing = (total_staked / Balance::from(validator_count)).max(1);
+	let commission = average_commission * validator_payout;
+
+	let nominator_apy =
+		FixedU128::from_rational(validator_payout.saturating_sub(commission), validator_backing);
+	let validator_apy = FixedU128::from_rational(commission, stake).saturating_add(
+		nominator_apy.saturating_mul(FixedU128::from_rational(stake.min(validator_backing), stake)),
+	);
+
+	(validator_apy, nominator_apy)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use polkadot_runtime_common::impls as upstream;
+	use proptest::prelude::*;
+
+	const UNITS: Balance = 10_000_000_000;
+
+	fn perquintill() -> impl Strategy<Value = Perquintill> {
+		(0..=Perquintill::ACCURACY).prop_map(Perquintill::from_parts)
+	}
+
+	prop_compose! {
+		fn era_payout_params()(
+			total_stakable in 1..=Balance::from(u64::MAX) * UNITS,
+			staked in perquintill(),
+			ideal_stake in perquintill(),
+			max_annual_inflation in perquintill(),
+			min_annual_inflation in perquintill(),
+			falloff in (1..=Perquintill::ACCURACY).prop_map(Perquintill::from_parts),
+			period_fraction in perquintill(),
+			legacy_auction_proportion in proptest::option::of(perquintill()),
+		) -> EraPayoutParams {
+			EraPayoutParams {
+				total_staked: staked * total_stakable,
+				total_stakable,
+				ideal_stake,
+				max_annual_inflation,
+				min_annual_inflation,
+				falloff,
+				period_fraction,
+				legacy_auction_proportion,
+				cap_treasury: false,
+			}
+		}
+	}
+
+	fn upstream_era_payout(params: EraPayoutParams) -> (Balance, Balance) {
+		upstream::relay_era_payout(upstream::EraPayoutParams {
+			total_staked: params.total_staked,
+			total_stakable: params.total_stakable,
+			ideal_stake: params.ideal_stake,
+			max_annual_inflation: params.max_annual_inflation,
+			min_annual_inflation: params.min_annual_inflation,
+			falloff: params.falloff,
+			period_fraction: params.period_fraction,
+			legacy_auction_proportion: params.legacy_auction_proportion,
+		})
+	}
+
+	proptest! {
+		#[test]
+		fn uncapped_era_payout_matches_upstream(params in era_payout_params()) {
+			prop_assert_eq!(relay_era_payout(params.clone()), upstream_era_payout(params));
+		}
+
+		#[test]
+		fn capped_era_payout_only_limits_the_treasury(params in era_payout_params()) {
+			let (staking, rest) = upstream_era_payout(params.clone());
+			let (capped_staking, capped_rest) =
+				relay_era_payout(EraPayoutParams { cap_treasury: true, ..params.clone() });
+
+			prop_assert_eq!(capped_staking, staking);
+			prop_assert!(capped_rest <= rest);
+
+			let other_issuance = params.total_stakable - params.total_staked;
+			if params.total_staked > other_issuance {
+				let cap = Perquintill::from_rational(other_issuance, params.total_staked) * staking;
+				prop_assert_eq!(capped_rest, rest.min(cap));
+			} else {
+				prop_assert_eq!(capped_rest, rest);
+			}
+		}
+	}
+
+	#[test]
+	fn treasury_cap_applies_when_most_is_staked() {
+		let params = EraPayoutParams {
+			total_staked: 80 * UNITS,
+			total_stakable: 100 * UNITS,
+			ideal_stake: Perquintill::from_percent(75),
+			max_annual_inflation: Perquintill::from_percent(10),
+			min_annual_inflation: Perquintill::from_percent(10),
+			falloff: Perquintill::from_percent(5),
+			period_fraction: Perquintill::one(),
+			legacy_auction_proportion: None,
+			cap_treasury: false,
+		};
+
+		// A flat inflation pays everything to the stakers.
+		assert_eq!(relay_era_payout(params.clone()), (10 * UNITS, 0));
+
+		// Paying half of the maximum to the stakers leaves the other half for the treasury,
+		// which the cap limits to `20 / 80` of the staking payout.
+		let params =
+			EraPayoutParams { min_annual_inflation: Perquintill::from_percent(5), ..params };
+		let (staking, rest) = relay_era_payout(params.clone());
+		let cap = Perquintill::from_percent(25) * staking;
+		assert_eq!(staking + rest, 10 * UNITS);
+		assert!(rest > cap);
+		assert_eq!(
+			relay_era_payout(EraPayoutParams { cap_treasury: true, ..params }),
+			(staking, cap)
+		);
+	}
+
+	#[test]
+	fn estimate_staking_apy_works() {
+		// 300 validators backed by 100 units each, 10% commission and 3000 units paid per year.
+		let (validator_apy, nominator_apy) = estimate_staking_apy(
+			3_000 * UNITS,
+			30_000 * UNITS,
+			300,
+			Perbill::from_percent(10),
+			UNITS,
+		);
+
+		// A nominator gets 90% of the 10 units paid per validator for 100 units of backing.
+		assert_eq!(nominator_apy, FixedU128::from_rational(9, 100));
+		// A validator gets the 1 unit of commission on top of its 1 unit of stake.
+		assert_eq!(validator_apy, FixedU128::from_rational(109, 100));
+	}
+
+	#[test]
+	fn estimate_staking_apy_handles_empty_inputs() {
+		let zero = (FixedU128::zero(), FixedU128::zero());
+
+		assert_eq!(estimate_staking_apy(UNITS, 0, 300, Perbill::zero(), UNITS), zero);
+		assert_eq!(estimate_staking_apy(UNITS, UNITS, 0, Perbill::zero(), UNITS), zero);
+		assert_eq!(estimate_staking_apy(UNITS, UNITS, 300, Perbill::zero(), 0), zero);
+	}
+}
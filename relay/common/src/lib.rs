@@ -17,11 +17,16 @@
 //! Shared code between the Kusama nd Polkadot RC Runtimes.
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use polkadot_primitives::Balance;
-use sp_runtime::{Perquintill, Saturating};
+extern crate alloc;
+
+pub mod inflation;
+
+pub use inflation::{estimate_staking_apy, relay_era_payout, EraPayoutParams};
 
 /// Extra runtime APIs for kusama runtime.
 pub mod apis {
+	use alloc::vec::Vec;
+
 	/// Information about the current inflation rate of the system.
 	///
 	/// Both fields should be treated as best-effort, given that the inflation rate might not be
@@ -38,79 +43,59 @@ pub mod apis {
 		pub next_mint: (polkadot_primitives::Balance, polkadot_primitives::Balance),
 	}
 
+	/// The amounts minted at the end of an era.
+	#[derive(scale_info::TypeInfo, codec::Encode, codec::Decode, Clone, PartialEq, Eq)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct EraMint {
+		/// The era the amounts were minted for.
+		pub era: u32,
+		/// The amount that was minted for the stakers of the era.
+		pub to_stakers: polkadot_primitives::Balance,
+		/// The amount that was minted for the treasury.
+		pub to_treasury: polkadot_primitives::Balance,
+	}
+
+	/// Information about the past and the expected staking rewards.
+	///
+	/// The estimates should be treated as best-effort, see [`super::estimate_staking_apy`].
+	#[derive(scale_info::TypeInfo, codec::Encode, codec::Decode)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct StakingRewardsInfo {
+		/// The amounts minted in the last eras, oldest first.
+		///
+		/// Only eras that are still part of the staking history are included.
+		pub era_mints: Vec<EraMint>,
+		/// The ratio of stake to stakable issuance at which the stakers are rewarded the most.
+		///
+		/// `None` if the staking rewards do not depend on the stake.
+		pub ideal_stake: Option<sp_runtime::Perquintill>,
+		/// The current ratio of stake to stakable issuance.
+		pub current_stake: sp_runtime::Perquintill,
+		/// The estimated annual yield of a validator that bonds the given stake itself.
+		pub validator_apy: sp_runtime::FixedU128,
+		/// The estimated annual yield of a nominator that bonds the given stake.
+		pub nominator_apy: sp_runtime::FixedU128,
+	}
+
 	sp_api::decl_runtime_apis! {
+		#[api_version(2)]
 		pub trait Inflation {
 			/// Return the current estimates of the inflation amount.
 			///
 			/// This is marked as experimental in light of RFC#89. Nonetheless, its usage is highly
 			/// recommended over trying to read-storage, or re-create the onchain logic.
 			fn experimental_inflation_prediction_info() -> InflationInfo;
-		}
-	}
-}
-
-// ---- TODO: Below is copy pasted from sdk, remove once we pull the version containing
-// https://github.com/paritytech/polkadot-sdk/pull/4938
-
-#[derive(Debug, Clone)]
-/// Parameters passed into [`relay_era_payout`] function.
-pub struct EraPayoutParams {
-	/// Total staked amount.
-	pub total_staked: Balance,
-	/// Total stakable amount.
-	///
-	/// Usually, this is equal to the total issuance, except if a large part of the issuance is
-	/// locked in another sub-system.
-	pub total_stakable: Balance,
-	/// Ideal stake ratio, which is reduced by `legacy_auction_proportion` if not `None`.
-	pub ideal_stake: Perquintill,
-	/// Maximum inflation rate.
-	pub max_annual_inflation: Perquintill,
-	/// Minimum inflation rate.
-	pub min_annual_inflation: Perquintill,
-	/// Falloff used to calculate era payouts.
-	pub falloff: Perquintill,
-	/// Fraction of the era period used to calculate era payouts.
-	pub period_fraction: Perquintill,
-	/// Legacy auction proportion, which, if not `None`, is subtracted from `ideal_stake`.
-	pub legacy_auction_proportion: Option<Perquintill>,
-}
-
-/// A specialized function to compute the inflation of the staking system, tailored for Polkadot
-/// Relay Chains, such as Polkadot, Kusama, and Westend.
-pub fn relay_era_payout(params: EraPayoutParams) -> (Balance, Balance) {
-	let EraPayoutParams {
-		total_staked,
-		total_stakable,
-		ideal_stake,
-		max_annual_inflation,
-		min_annual_inflation,
-		falloff,
-		period_fraction,
-		legacy_auction_proportion,
-	} = params;
 
-	let delta_annual_inflation = max_annual_inflation.saturating_sub(min_annual_inflation);
-
-	let ideal_stake = ideal_stake.saturating_sub(legacy_auction_proportion.unwrap_or_default());
-
-	let stake = Perquintill::from_rational(total_staked, total_stakable);
-	let adjustment = pallet_staking_reward_fn::compute_inflation(stake, ideal_stake, falloff);
-	let staking_inflation =
-		min_annual_inflation.saturating_add(delta_annual_inflation * adjustment);
-
-	let max_payout = period_fraction * max_annual_inflation * total_stakable;
-	let staking_payout = (period_fraction * staking_inflation) * total_stakable;
-	let rest = max_payout.saturating_sub(staking_payout);
-
-	let other_issuance = total_stakable.saturating_sub(total_staked);
-	if total_staked > other_issuance {
-		let _cap_rest = Perquintill::from_rational(other_issuance, total_staked) * staking_payout;
-		// We don't do anything with this, but if we wanted to, we could introduce a cap on the
-		// treasury amount with: `rest = rest.min(cap_rest);`
+			/// Return the mints of the last `eras` eras and the estimated yield of bonding
+			/// `stake`.
+			///
+			/// This is marked as experimental for the same reasons as
+			/// [`Self::experimental_inflation_prediction_info`].
+			#[api_version(2)]
+			fn experimental_staking_rewards_info(
+				eras: u32,
+				stake: polkadot_primitives::Balance,
+			) -> StakingRewardsInfo;
+		}
 	}
-	(staking_payout, rest)
 }
-
-// ---- TODO: Above is copy pasted from sdk, remove once we pull the version containing
-// https://github.com/paritytech/polkadot-sdk/pull/4938
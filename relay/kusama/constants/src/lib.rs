@@ -143,7 +143,7 @@ pub const TREASURY_PALLET_ID: u8 = 18;
 
 pub mod proxy {
 	use pallet_remote_proxy::ProxyDefinition;
-	use polkadot_primitives::{AccountId, BlakeTwo256, BlockNumber, Hash};
+	use polkadot_primitives::{AccountId, BlockNumber, Hash};
 	use sp_runtime::traits::Convert;
 
 	/// The type used to represent the kinds of proxying allowed.
@@ -185,11 +185,16 @@ pub mod proxy {
 		Spokesperson,
 		#[codec(index = 10)]
 		ParaRegistration,
+		/// Can only operate a validator, i.e. call `validate`, `chill` and manage session keys.
+		#[codec(index = 11)]
+		ValidatorOperator,
 	}
 
 	/// Remote proxy interface that uses the relay chain as remote location.
-	pub struct RemoteProxyInterface<LocalProxyType, ProxyDefinitionConverter>(
-		core::marker::PhantomData<(LocalProxyType, ProxyDefinitionConverter)>,
+	///
+	/// The `DelayConverter` converts the delay of a remote proxy into local blocks.
+	pub struct RemoteProxyInterface<LocalProxyType, ProxyDefinitionConverter, DelayConverter>(
+		core::marker::PhantomData<(LocalProxyType, ProxyDefinitionConverter, DelayConverter)>,
 	);
 
 	impl<
@@ -198,8 +203,9 @@ pub mod proxy {
 				ProxyDefinition<AccountId, ProxyType, BlockNumber>,
 				Option<ProxyDefinition<AccountId, LocalProxyType, BlockNumber>>,
 			>,
+			DelayConverter: Convert<BlockNumber, Option<BlockNumber>>,
 		> pallet_remote_proxy::RemoteProxyInterface<AccountId, LocalProxyType, BlockNumber>
-		for RemoteProxyInterface<LocalProxyType, ProxyDefinitionConverter>
+		for RemoteProxyInterface<LocalProxyType, ProxyDefinitionConverter, DelayConverter>
 	{
 		type RemoteAccountId = AccountId;
 
@@ -209,18 +215,14 @@ pub mod proxy {
 
 		type RemoteHash = Hash;
 
-		type RemoteHasher = BlakeTwo256;
-
-		fn block_to_storage_root(
-			validation_data: &polkadot_primitives::PersistedValidationData,
-		) -> Option<(Self::RemoteBlockNumber, <Self::RemoteHasher as sp_core::Hasher>::Out)> {
-			Some((validation_data.relay_parent_number, validation_data.relay_parent_storage_root))
-		}
-
 		fn local_to_remote_account_id(local: &AccountId) -> Option<Self::RemoteAccountId> {
 			Some(local.clone())
 		}
 
+		fn remote_to_local_account_id(remote: &Self::RemoteAccountId) -> Option<AccountId> {
+			Some(remote.clone())
+		}
+
 		fn remote_to_local_proxy_defintion(
 			remote: ProxyDefinition<
 				Self::RemoteAccountId,
@@ -231,12 +233,17 @@ pub mod proxy {
 			ProxyDefinitionConverter::convert(remote)
 		}
 
+		fn remote_to_local_delay(delay: Self::RemoteBlockNumber) -> Option<BlockNumber> {
+			DelayConverter::convert(delay)
+		}
+
 		#[cfg(feature = "runtime-benchmarks")]
 		fn create_remote_proxy_proof(
 			caller: &AccountId,
 			proxy: &AccountId,
 		) -> (pallet_remote_proxy::RemoteProxyProof<Self::RemoteBlockNumber>, BlockNumber, Hash) {
 			use codec::Encode;
+			use polkadot_primitives::BlakeTwo256;
 			use sp_trie::TrieMut;
 
 			let (mut db, mut root) = sp_trie::MemoryDB::<BlakeTwo256>::default_with_root();
@@ -229,6 +229,35 @@ pub fn kusama_development_config_genesis() -> serde_json::Value {
 	)
 }
 
+/// Genesis config patch with `count` validators, derived from the well-known dev seeds and
+/// continuing with `Validator<N>` once those are exhausted.
+///
+/// Only the `balances`, `session` and `staking` sections are returned, with the stash and
+/// controller accounts of the validators as the only endowed accounts. This is used by the
+/// `chain-spec-generator` to replace the validator set of a preset.
+pub fn validators_genesis_patch(count: u32) -> serde_json::Value {
+	const SEEDS: [&str; 6] = ["Alice", "Bob", "Charlie", "Dave", "Eve", "Ferdie"];
+
+	let initial_authorities: Vec<_> = (0..count as usize)
+		.map(|index| match SEEDS.get(index) {
+			Some(seed) => get_authority_keys_from_seed(seed),
+			None => get_authority_keys_from_seed(&format!("Validator{index}")),
+		})
+		.collect();
+	let endowed_accounts =
+		initial_authorities.iter().flat_map(|x| [x.0.clone(), x.1.clone()]).collect();
+
+	let mut patch = kusama_testnet_genesis(
+		initial_authorities,
+		get_account_id_from_seed::<sr25519::Public>("Alice"),
+		Some(endowed_accounts),
+	);
+	if let Some(sections) = patch.as_object_mut() {
+		sections.retain(|key, _| matches!(key.as_str(), "balances" | "session" | "staking"));
+	}
+	patch
+}
+
 /// Provides the names of the predefined genesis configs for this runtime.
 pub fn preset_names() -> Vec<PresetId> {
 	vec![
@@ -259,4 +288,21 @@ mod tests {
 	fn default_parachains_host_configuration_is_consistent() {
 		default_parachains_host_configuration().panic_if_not_consistent();
 	}
+
+	#[test]
+	fn validators_genesis_patch_works() {
+		let patch = validators_genesis_patch(8);
+
+		assert_eq!(patch["session"]["keys"].as_array().unwrap().len(), 8);
+		assert_eq!(patch["staking"]["stakers"].as_array().unwrap().len(), 8);
+		assert_eq!(patch["staking"]["validatorCount"], 8);
+		assert_eq!(patch["balances"]["balances"].as_array().unwrap().len(), 16);
+		assert!(patch.get("configuration").is_none());
+
+		// The first validators use the well-known dev seeds.
+		let alice_stash = get_account_id_from_seed::<sr25519::Public>("Alice//stash");
+		assert_eq!(patch["staking"]["stakers"][0][0], serde_json::json!(alice_stash));
+		let validator_7 = get_account_id_from_seed::<sr25519::Public>("Validator7//stash");
+		assert_eq!(patch["staking"]["stakers"][7][0], serde_json::json!(validator_7));
+	}
 }
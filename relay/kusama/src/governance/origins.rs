@@ -98,6 +98,8 @@ pub mod pallet_custom_origins {
 		Fellowship9Dan,
 		/// Origin for signaling that the network wishes for some change.
 		WishForChange,
+		/// Origin for changing the economic runtime parameters, such as the inflation.
+		ParametersAdmin,
 	}
 
 	macro_rules! decl_unit_ensures {
@@ -141,6 +143,7 @@ pub mod pallet_custom_origins {
 		ReferendumKiller,
 		WhitelistedCaller,
 		WishForChange,
+		ParametersAdmin,
 		FellowshipInitiates: u16 = 0,
 		Fellows: u16 = 3,
 		FellowshipExperts: u16 = 5,
@@ -43,6 +43,9 @@ const APP_AUCTION_ADMIN: Curve =
 	Curve::make_reciprocal(4, 28, percent(80), percent(50), percent(100));
 const SUP_AUCTION_ADMIN: Curve =
 	Curve::make_reciprocal(7, 28, percent(10), percent(0), percent(50));
+const APP_PARAMETERS_ADMIN: Curve = Curve::make_linear(17, 28, percent(50), percent(100));
+const SUP_PARAMETERS_ADMIN: Curve =
+	Curve::make_reciprocal(12, 28, percent(1), percent(0), percent(50));
 const APP_LEASE_ADMIN: Curve = Curve::make_linear(17, 28, percent(50), percent(100));
 const SUP_LEASE_ADMIN: Curve = Curve::make_reciprocal(12, 28, percent(1), percent(0), percent(50));
 const APP_REFERENDUM_CANCELLER: Curve = Curve::make_linear(17, 28, percent(50), percent(100));
@@ -68,7 +71,7 @@ const APP_WHITELISTED_CALLER: Curve =
 const SUP_WHITELISTED_CALLER: Curve =
 	Curve::make_reciprocal(1, 28, percent(20), percent(5), percent(50));
 
-const TRACKS_DATA: [pallet_referenda::Track<u16, Balance, BlockNumber>; 16] = [
+const TRACKS_DATA: [pallet_referenda::Track<u16, Balance, BlockNumber>; 17] = [
 	pallet_referenda::Track {
 		id: 0,
 		info: pallet_referenda::TrackInfo {
@@ -195,6 +198,20 @@ const TRACKS_DATA: [pallet_referenda::Track<u16, Balance, BlockNumber>; 16] = [
 			min_support: SUP_AUCTION_ADMIN,
 		},
 	},
+	pallet_referenda::Track {
+		id: 16,
+		info: pallet_referenda::TrackInfo {
+			name: s("parameters_admin"),
+			max_deciding: 10,
+			decision_deposit: 5 * GRAND,
+			prepare_period: 2 * HOURS,
+			decision_period: 14 * DAYS,
+			confirm_period: 24 * HOURS,
+			min_enactment_period: 24 * HOURS,
+			min_approval: APP_PARAMETERS_ADMIN,
+			min_support: SUP_PARAMETERS_ADMIN,
+		},
+	},
 	pallet_referenda::Track {
 		id: 20,
 		info: pallet_referenda::TrackInfo {
@@ -321,6 +338,7 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 				origins::Origin::FellowshipAdmin => Ok(13),
 				origins::Origin::GeneralAdmin => Ok(14),
 				origins::Origin::AuctionAdmin => Ok(15),
+				origins::Origin::ParametersAdmin => Ok(16),
 				// Referendum admins
 				origins::Origin::ReferendumCanceller => Ok(20),
 				origins::Origin::ReferendumKiller => Ok(21),
@@ -58,6 +58,7 @@ use polkadot_runtime_common::{
 	paras_registrar, prod_or_fast, slots, BalanceToU256, BlockHashCount, BlockLength,
 	CurrencyToVote, SlowAdjustingFeeUpdate, U256ToBalance,
 };
+use referenda_tracks_api::ReferendaTracks;
 use relay_common::apis::*;
 use scale_info::TypeInfo;
 use sp_runtime::traits::Saturating;
@@ -165,10 +166,15 @@ pub mod xcm_config;
 
 // Governance configurations.
 pub mod governance;
+
+// Handling of the treasury burn.
+pub mod treasury_burn;
 use governance::{
-	pallet_custom_origins, AuctionAdmin, Fellows, GeneralAdmin, LeaseAdmin, StakingAdmin,
-	Treasurer, TreasurySpender,
+	pallet_custom_origins, AuctionAdmin, Fellows, GeneralAdmin, LeaseAdmin, ParametersAdmin,
+	StakingAdmin, Treasurer, TreasurySpender,
 };
+use treasury_burn::pallet_treasury_burn;
+pub use treasury_burn::{TreasuryBurnDestination, TreasuryBurnHandler};
 
 #[cfg(test)]
 mod tests;
@@ -661,33 +667,12 @@ impl pallet_bags_list::Config<VoterBagsListInstance> for Runtime {
 	type Score = sp_npos_elections::VoteWeight;
 }
 
-#[derive(
-	Default,
-	MaxEncodedLen,
-	Encode,
-	Decode,
-	DecodeWithMemTracking,
-	TypeInfo,
-	Clone,
-	Eq,
-	PartialEq,
-	Debug,
-)]
-pub struct BurnDestinationAccount(pub Option<AccountId>);
-
-impl BurnDestinationAccount {
-	pub fn is_set(&self) -> bool {
-		self.0.is_some()
-	}
-}
-
 /// Dynamic params that can be adjusted at runtime.
 #[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
 pub mod dynamic_params {
 	use super::*;
 
-	/// Parameters used to calculate era payouts, see
-	/// [`polkadot_runtime_common::impls::EraPayoutParams`].
+	/// Parameters used to calculate era payouts, see [`relay_common::EraPayoutParams`].
 	#[dynamic_pallet_params]
 	#[codec(index = 0)]
 	pub mod inflation {
@@ -717,6 +702,12 @@ pub mod dynamic_params {
 		/// generally be set to false.
 		#[codec(index = 4)]
 		pub static UseAuctionSlots: bool = true;
+
+		/// Whether to cap the treasury part of era payouts. If true, then the treasury receives at
+		/// most `(total_stakable - total_staked) / total_staked` of the staking payout once more
+		/// than half of the stakable issuance is staked.
+		#[codec(index = 5)]
+		pub static CapTreasury: bool = false;
 	}
 
 	/// Parameters used by `pallet-treasury` to handle the burn process.
@@ -727,7 +718,7 @@ pub mod dynamic_params {
 		pub static BurnPortion: Permill = Permill::from_percent(0);
 
 		#[codec(index = 1)]
-		pub static BurnDestination: BurnDestinationAccount = Default::default();
+		pub static BurnDestination: TreasuryBurnDestination = Default::default();
 	}
 }
 
@@ -753,7 +744,8 @@ impl EnsureOriginWithArg<RuntimeOrigin, RuntimeParametersKey> for DynamicParamet
 		use crate::RuntimeParametersKey::*;
 
 		match key {
-			Inflation(_) => frame_system::ensure_root(origin.clone()),
+			Inflation(_) =>
+				EitherOf::<EnsureRoot<AccountId>, ParametersAdmin>::ensure_origin(origin.clone()),
 			Treasury(_) =>
 				EitherOf::<EnsureRoot<AccountId>, GeneralAdmin>::ensure_origin(origin.clone()),
 		}
@@ -802,6 +794,7 @@ impl pallet_staking::EraPayout<Balance> for EraPayout {
 			} else {
 				None
 			},
+			cap_treasury: dynamic_params::inflation::CapTreasury::get(),
 		};
 		log::debug!(target: "runtime::kusama", "params: {params:?}");
 		relay_common::relay_era_payout(params)
@@ -833,6 +826,14 @@ parameter_types! {
 	// 24
 	pub const MaxNominations: u32 = <NposCompactSolution24 as NposSolution>::LIMIT as u32;
 	pub TreasuryAccount: AccountId = Treasury::account_id();
+	pub ActiveEraIndex: Option<sp_staking::EraIndex> =
+		pallet_staking::ActiveEra::<Runtime>::get().map(|era| era.index);
+}
+
+impl pallet_era_treasury_mints::Config for Runtime {
+	type Balance = Balance;
+	type ActiveEra = ActiveEraIndex;
+	type HistoryDepth = <Runtime as pallet_staking::Config>::HistoryDepth;
 }
 
 impl pallet_staking::Config for Runtime {
@@ -844,7 +845,8 @@ impl pallet_staking::Config for Runtime {
 	type CurrencyToVote = CurrencyToVote;
 	type ElectionProvider = ElectionProviderMultiPhase;
 	type GenesisElectionProvider = onchain::OnChainExecution<OnChainSeqPhragmen>;
-	type RewardRemainder = ResolveTo<TreasuryAccount, Balances>;
+	type RewardRemainder =
+		pallet_era_treasury_mints::RecordEraMint<Runtime, ResolveTo<TreasuryAccount, Balances>>;
 	type RuntimeEvent = RuntimeEvent;
 	type Slash = ResolveTo<TreasuryAccount, Balances>;
 	type Reward = ();
@@ -886,6 +888,8 @@ parameter_types! {
 	pub const SpendPeriod: BlockNumber = 6 * DAYS;
 	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
 	pub const PayoutSpendPeriod: BlockNumber = 90 * DAYS;
+	// Matches the timeout of the XCM query of `PayOverXcm`.
+	pub const TreasuryPayoutTimeout: BlockNumber = 6 * HOURS;
 	// The asset's interior location for the paying account. This is the Treasury
 	// pallet instance (which sits at index 18).
 	pub TreasuryInteriorLocation: InteriorLocation = PalletInstance(TREASURY_PALLET_ID).into();
@@ -903,33 +907,8 @@ parameter_types! {
 use frame_support::traits::{Currency, OnUnbalanced};
 
 pub type BalancesNegativeImbalance = <Balances as Currency<AccountId>>::NegativeImbalance;
-pub struct TreasuryBurnHandler;
-
-impl OnUnbalanced<BalancesNegativeImbalance> for TreasuryBurnHandler {
-	fn on_nonzero_unbalanced(amount: BalancesNegativeImbalance) {
-		let destination = dynamic_params::treasury::BurnDestination::get();
-
-		if let BurnDestinationAccount(Some(account)) = destination {
-			// Must resolve into existing but better to be safe.
-			Balances::resolve_creating(&account, amount);
-		} else {
-			// If no account to destinate the funds to, just drop the
-			// imbalance.
-			<() as OnUnbalanced<_>>::on_nonzero_unbalanced(amount)
-		}
-	}
-}
-
-impl Get<Permill> for TreasuryBurnHandler {
-	fn get() -> Permill {
-		let destination = dynamic_params::treasury::BurnDestination::get();
-
-		if destination.is_set() {
-			dynamic_params::treasury::BurnPortion::get()
-		} else {
-			Permill::zero()
-		}
-	}
+impl pallet_treasury_burn::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 }
 
 impl pallet_treasury::Config for Runtime {
@@ -951,7 +930,7 @@ impl pallet_treasury::Config for Runtime {
 		TreasuryInteriorLocation,
 		crate::xcm_config::XcmRouter,
 		crate::XcmPallet,
-		ConstU32<{ 6 * HOURS }>,
+		TreasuryPayoutTimeout,
 		Self::Beneficiary,
 		Self::AssetKind,
 		LocatableAssetConverter,
@@ -964,6 +943,22 @@ impl pallet_treasury::Config for Runtime {
 	type BlockNumberProvider = System;
 }
 
+parameter_types! {
+	pub const TreasurySpendTrackerPalletId: PalletId = PalletId(*b"py/trsrt");
+	pub const MaxPayoutRetries: u32 = 3;
+	pub const PayoutCheckInterval: BlockNumber = 10 * MINUTES;
+	pub const MaxSucceededSpends: u32 = 100;
+}
+
+impl pallet_treasury_spend_tracker::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type PalletId = TreasurySpendTrackerPalletId;
+	type MaxRetries = MaxPayoutRetries;
+	type CheckInterval = PayoutCheckInterval;
+	type PayoutTimeout = TreasuryPayoutTimeout;
+	type MaxSucceeded = MaxSucceededSpends;
+}
+
 parameter_types! {
 	pub const BountyDepositBase: Balance = 100 * CENTS;
 	pub const BountyDepositPayoutDelay: BlockNumber = 0;
@@ -1361,6 +1356,16 @@ impl InstanceFilter<RuntimeCall> for TransparentProxyType {
 					RuntimeCall::Utility(pallet_utility::Call::force_batch { .. }) |
 					RuntimeCall::Proxy(pallet_proxy::Call::remove_proxy { .. })
 			),
+			ProxyType::ValidatorOperator => matches!(
+				c,
+				RuntimeCall::Staking(pallet_staking::Call::validate { .. }) |
+					RuntimeCall::Staking(pallet_staking::Call::chill { .. }) |
+					RuntimeCall::Session(pallet_session::Call::set_keys { .. }) |
+					RuntimeCall::Session(pallet_session::Call::purge_keys { .. }) |
+					RuntimeCall::Utility(pallet_utility::Call::batch { .. }) |
+					RuntimeCall::Utility(pallet_utility::Call::batch_all { .. }) |
+					RuntimeCall::Utility(pallet_utility::Call::force_batch { .. })
+			),
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -1369,6 +1374,7 @@ impl InstanceFilter<RuntimeCall> for TransparentProxyType {
 			(ProxyType::Any, _) => true,
 			(_, ProxyType::Any) => false,
 			(ProxyType::NonTransfer, _) => true,
+			(ProxyType::Staking, ProxyType::ValidatorOperator) => true,
 			_ => false,
 		}
 	}
@@ -1558,6 +1564,60 @@ impl parachains_on_demand::Config for Runtime {
 
 impl parachains_assigner_coretime::Config for Runtime {}
 
+/// Ensures that the origin is the Coretime chain.
+pub struct EnsureBroker;
+impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for EnsureBroker {
+	type Success = ();
+
+	fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+		match o.clone().into() {
+			Ok(parachains_origin::Origin::Parachain(id)) if id == BrokerId::get().into() => Ok(()),
+			_ => Err(o),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(parachains_origin::Origin::Parachain(BrokerId::get().into()).into())
+	}
+}
+
+/// Forwards the complete assignments of [`CoretimeAssignmentBuffer`] to the coretime assigner.
+pub struct AssignCoreToAssigner;
+impl pallet_coretime_assignment_buffer::AssignCore<BlockNumber> for AssignCoreToAssigner {
+	fn assign_core(
+		core: pallet_coretime_assignment_buffer::CoreIndex,
+		begin: BlockNumber,
+		assignment: Vec<(
+			pallet_coretime_assignment_buffer::CoreAssignment,
+			pallet_coretime_assignment_buffer::PartsOf57600,
+		)>,
+		end_hint: Option<BlockNumber>,
+	) -> sp_runtime::DispatchResult {
+		let assignment = assignment
+			.into_iter()
+			.map(|(assignment, parts)| {
+				(assignment, parachains_assigner_coretime::PartsOf57600::new_saturating(parts))
+			})
+			.collect();
+		parachains_assigner_coretime::Pallet::<Runtime>::assign_core(
+			CoreIndex(core.into()),
+			begin,
+			assignment,
+			end_hint,
+		)
+	}
+}
+
+impl pallet_coretime_assignment_buffer::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssignOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureBroker>;
+	type AssignCore = AssignCoreToAssigner;
+	type MaxAssignments = ConstU32<100>;
+	type MaxPendingAssignments = ConstU32<1_000>;
+	type WeightInfo = weights::pallet_coretime_assignment_buffer::WeightInfo<Runtime>;
+}
+
 impl parachains_initializer::Config for Runtime {
 	type Randomness = pallet_babe::RandomnessFromOneEpochAgo<Runtime>;
 	type ForceOrigin = EnsureRoot<AccountId>;
@@ -1823,6 +1883,8 @@ construct_runtime! {
 		Origins: pallet_custom_origins = 43,
 		Whitelist: pallet_whitelist = 44,
 		Parameters: pallet_parameters = 46,
+		TreasuryBurn: pallet_treasury_burn = 48,
+		TreasurySpendTracker: pallet_treasury_spend_tracker = 49,
 
 		// Claims. Usable initially.
 		Claims: claims = 19,
@@ -1876,6 +1938,9 @@ construct_runtime! {
 		// Staking extension for delegation
 		DelegatedStaking: pallet_delegated_staking = 47,
 
+		// Treasury part of the era payouts.
+		EraTreasuryMints: pallet_era_treasury_mints = 76,
+
 		// Parachains pallets. Start indices at 50 to leave room.
 		ParachainsOrigin: parachains_origin = 50,
 		Configuration: parachains_configuration = 51,
@@ -1899,6 +1964,7 @@ construct_runtime! {
 		Auctions: auctions = 72,
 		Crowdloan: crowdloan = 73,
 		Coretime: coretime = 74,
+		CoretimeAssignmentBuffer: pallet_coretime_assignment_buffer = 75,
 
 		// Pallet for sending XCM.
 		XcmPallet: pallet_xcm = 99,
@@ -2008,6 +2074,7 @@ mod benches {
 		[runtime_parachains::paras, Paras]
 		[runtime_parachains::on_demand, OnDemandAssignmentProvider]
 		[runtime_parachains::coretime, Coretime]
+		[pallet_coretime_assignment_buffer, CoretimeAssignmentBuffer]
 		// Substrate
 		[pallet_balances, Native]
 		[pallet_balances, NisCounterpart]
@@ -2251,15 +2318,10 @@ mod benches {
 use benches::*;
 
 impl Runtime {
-	fn impl_experimental_inflation_info() -> InflationInfo {
-		use pallet_staking::{ActiveEra, EraPayout, ErasTotalStake};
-		let (staked, _start) = ActiveEra::<Runtime>::get()
-			.map(|ae| (ErasTotalStake::<Runtime>::get(ae.index), ae.start.unwrap_or(0)))
-			.unwrap_or((0, 0));
-		let stake_able_issuance = Nis::issuance().other;
-
+	/// The ideal staking rate used by [`EraPayout`], reduced by the auctioned slots if enabled.
+	fn ideal_staking_rate() -> Perquintill {
 		let ideal_staking_rate = dynamic_params::inflation::IdealStake::get();
-		let inflation = if dynamic_params::inflation::UseAuctionSlots::get() {
+		if dynamic_params::inflation::UseAuctionSlots::get() {
 			let auctioned_slots = parachains_paras::Parachains::<Runtime>::get()
 				.into_iter()
 				// All active para-ids that do not belong to a system chain is the number of
@@ -2270,7 +2332,16 @@ impl Runtime {
 				.saturating_sub(Perquintill::from_rational(auctioned_slots.min(60), 200u64))
 		} else {
 			ideal_staking_rate
-		};
+		}
+	}
+
+	fn impl_experimental_inflation_info() -> InflationInfo {
+		use pallet_staking::{ActiveEra, EraPayout, ErasTotalStake};
+		let (staked, _start) = ActiveEra::<Runtime>::get()
+			.map(|ae| (ErasTotalStake::<Runtime>::get(ae.index), ae.start.unwrap_or(0)))
+			.unwrap_or((0, 0));
+		let stake_able_issuance = Nis::issuance().other;
+		let inflation = Self::ideal_staking_rate();
 
 		// We assume un-delayed 6h eras.
 		let era_duration = 6 * (HOURS as Moment) * MILLISECS_PER_BLOCK;
@@ -2282,13 +2353,103 @@ impl Runtime {
 
 		InflationInfo { inflation, next_mint }
 	}
+
+	fn impl_experimental_staking_rewards_info(eras: u32, stake: Balance) -> StakingRewardsInfo {
+		use pallet_staking::{ActiveEra, ErasTotalStake, ErasValidatorPrefs, ErasValidatorReward};
+		const MILLISECONDS_PER_YEAR: u64 = (1000 * 3600 * 24 * 36525) / 100;
+
+		let active_era = ActiveEra::<Runtime>::get().map(|ae| ae.index).unwrap_or(0);
+		let era_mints = (active_era.saturating_sub(eras)..active_era)
+			.filter_map(|era| {
+				let to_stakers = ErasValidatorReward::<Runtime>::get(era)?;
+				let to_treasury = EraTreasuryMints::mint_of(era).unwrap_or_default();
+				Some(EraMint { era, to_stakers, to_treasury })
+			})
+			.collect();
+
+		let total_staked = ErasTotalStake::<Runtime>::get(active_era);
+		let current_stake = Perquintill::from_rational(total_staked, Nis::issuance().other);
+
+		let (validator_count, total_commission) =
+			ErasValidatorPrefs::<Runtime>::iter_prefix_values(active_era).fold(
+				(0u32, 0u64),
+				|(count, total), prefs| {
+					(
+						count.saturating_add(1),
+						total.saturating_add(prefs.commission.deconstruct().into()),
+					)
+				},
+			);
+		let average_commission = Perbill::from_parts(
+			total_commission.checked_div(validator_count.into()).unwrap_or_default() as u32,
+		);
+
+		let eras_per_year = MILLISECONDS_PER_YEAR / (6 * (HOURS as Moment) * MILLISECS_PER_BLOCK);
+		let (next_staking_mint, _) = Self::impl_experimental_inflation_info().next_mint;
+		let (validator_apy, nominator_apy) = relay_common::estimate_staking_apy(
+			next_staking_mint.saturating_mul(eras_per_year.into()),
+			total_staked,
+			validator_count,
+			average_commission,
+			stake,
+		);
+
+		StakingRewardsInfo {
+			era_mints,
+			ideal_stake: Some(Self::ideal_staking_rate()),
+			current_stake,
+			validator_apy,
+			nominator_apy,
+		}
+	}
 }
 
+/// The referenda pallets exposed through the `ReferendaTracksApi`.
+type ReferendaPallets = (Referenda, FellowshipReferenda);
+
 sp_api::impl_runtime_apis! {
+	#[api_version(2)]
 	impl relay_common::apis::Inflation<Block> for Runtime {
 		fn experimental_inflation_prediction_info() -> InflationInfo {
 			Runtime::impl_experimental_inflation_info()
 		}
+
+		fn experimental_staking_rewards_info(eras: u32, stake: Balance) -> StakingRewardsInfo {
+			Runtime::impl_experimental_staking_rewards_info(eras, stake)
+		}
+	}
+
+	impl referenda_tracks_api::ReferendaTracksApi<Block, u16, Balance, BlockNumber> for Runtime {
+		fn tracks(
+			pallet_index: u8,
+		) -> Option<Vec<pallet_referenda::Track<u16, Balance, BlockNumber>>> {
+			ReferendaPallets::tracks(pallet_index)
+		}
+
+		fn track_thresholds(
+			pallet_index: u8,
+			track: u16,
+			elapsed: Perbill,
+		) -> Option<referenda_tracks_api::Thresholds> {
+			ReferendaPallets::track_thresholds(pallet_index, track, elapsed)
+		}
+
+		fn referendum_thresholds(
+			pallet_index: u8,
+			index: pallet_referenda::ReferendumIndex,
+			step: BlockNumber,
+		) -> Option<Vec<referenda_tracks_api::ThresholdsAt<BlockNumber>>> {
+			ReferendaPallets::referendum_thresholds(pallet_index, index, step)
+		}
+	}
+
+	impl pallet_treasury_spend_tracker::runtime_api::TreasurySpendsApi<Block, QueryId> for Runtime {
+		fn spends() -> Vec<(
+			pallet_treasury::SpendIndex,
+			pallet_treasury_spend_tracker::SpendState<QueryId>,
+		)> {
+			TreasurySpendTracker::spends()
+		}
 	}
 
 	impl sp_api::Core<Block> for Runtime {
@@ -3034,6 +3195,108 @@ mod multiplier_tests {
 		})
 	}
 
+	#[test]
+	fn staking_rewards_info_reports_era_mints() {
+		use frame_support::traits::fungible::Balanced;
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			let history_depth = <Runtime as pallet_staking::Config>::HistoryDepth::get();
+			for era in 0..history_depth + 5 {
+				pallet_staking::ActiveEra::<Runtime>::put(pallet_staking::ActiveEraInfo {
+					index: era,
+					start: None,
+				});
+				pallet_staking::ErasValidatorReward::<Runtime>::insert(era, 100 * era as Balance);
+				pallet_era_treasury_mints::RecordEraMint::<Runtime, ()>::on_unbalanced(
+					Balances::issue(era as Balance),
+				);
+			}
+
+			// Only the last `HistoryDepth` eras are kept.
+			assert_eq!(EraTreasuryMints::mint_of(4), None);
+			assert_eq!(EraTreasuryMints::mint_of(5), Some(5));
+
+			let active_era = history_depth + 4;
+			let info = Runtime::impl_experimental_staking_rewards_info(3, UNITS);
+			assert_eq!(
+				info.era_mints,
+				(active_era - 3..active_era)
+					.map(|era| EraMint {
+						era,
+						to_stakers: 100 * era as Balance,
+						to_treasury: era as Balance,
+					})
+					.collect::<Vec<_>>()
+			);
+			assert_eq!(info.ideal_stake, Some(Perquintill::from_percent(75)));
+			// Without any validators there is nothing to estimate.
+			assert_eq!(info.validator_apy, FixedU128::from_u32(0));
+			assert_eq!(info.nominator_apy, FixedU128::from_u32(0));
+		});
+	}
+
+	#[test]
+	fn treasury_cap_follows_dynamic_params() {
+		use dynamic_params::inflation;
+		use pallet_staking::EraPayout as _;
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			pallet_balances::TotalIssuance::<Runtime>::put(1_000 * UNITS);
+			let era_duration = 6 * (HOURS as Moment) * MILLISECS_PER_BLOCK;
+			let (to_stakers, to_treasury) =
+				super::EraPayout::era_payout(900 * UNITS, 0, era_duration);
+
+			pallet_parameters::Pallet::<Runtime>::set_parameter(
+				RuntimeOrigin::root(),
+				RuntimeParameters::Inflation(inflation::Parameters::CapTreasury(
+					inflation::CapTreasury,
+					Some(true),
+				)),
+			)
+			.unwrap();
+
+			// With 90% staked, the treasury gets at most a ninth of the staking payout.
+			let (capped_to_stakers, capped_to_treasury) =
+				super::EraPayout::era_payout(900 * UNITS, 0, era_duration);
+			assert_eq!(capped_to_stakers, to_stakers);
+			assert!(to_treasury > to_stakers / 9);
+			assert_eq!(capped_to_treasury, Perquintill::from_rational(1u64, 9) * to_stakers);
+		});
+	}
+
+	#[test]
+	fn parameters_admin_can_only_change_inflation() {
+		use dynamic_params::{inflation, treasury};
+		use pallet_custom_origins::Origin as CustomOrigin;
+		use pallet_referenda::TracksInfo as _;
+
+		assert_eq!(
+			governance::TracksInfo::track_for(&OriginCaller::Origins(
+				CustomOrigin::ParametersAdmin
+			)),
+			Ok(16)
+		);
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			let set_parameter = |origin: CustomOrigin, parameter| {
+				pallet_parameters::Pallet::<Runtime>::set_parameter(origin.into(), parameter)
+			};
+			let cap_treasury = RuntimeParameters::Inflation(inflation::Parameters::CapTreasury(
+				inflation::CapTreasury,
+				Some(true),
+			));
+			let burn_portion = RuntimeParameters::Treasury(treasury::Parameters::BurnPortion(
+				treasury::BurnPortion,
+				Some(Permill::from_percent(1)),
+			));
+
+			assert!(set_parameter(CustomOrigin::GeneralAdmin, cap_treasury.clone()).is_err());
+			assert!(set_parameter(CustomOrigin::ParametersAdmin, burn_portion).is_err());
+			assert!(set_parameter(CustomOrigin::ParametersAdmin, cap_treasury).is_ok());
+			assert!(inflation::CapTreasury::get());
+		});
+	}
+
 	#[test]
 	fn fast_unstake_estimate() {
 		use pallet_fast_unstake::WeightInfo;
@@ -3235,6 +3498,7 @@ mod remote_tests {
 			log::info!(target: LOG_TARGET, "falloff = {:?}", dynamic_params::inflation::Falloff::get());
 			log::info!(target: LOG_TARGET, "useAuctionSlots = {:?}", dynamic_params::inflation::UseAuctionSlots::get());
 			log::info!(target: LOG_TARGET, "idealStake = {:?}", dynamic_params::inflation::IdealStake::get());
+			log::info!(target: LOG_TARGET, "capTreasury = {:?}", dynamic_params::inflation::CapTreasury::get());
 			log::info!(target: LOG_TARGET, "maxStakingRewards = {:?}", pallet_staking::MaxStakedRewards::<Runtime>::get());
 			log::info!(target: LOG_TARGET, "💰 Inflation ==> staking = {:?} / leftover = {:?}", token.amount(staking), token.amount(leftover));
 		});
@@ -181,3 +181,105 @@ fn check_treasury_pallet_id() {
 		kusama_runtime_constants::TREASURY_PALLET_ID
 	);
 }
+
+#[test]
+fn validator_operator_proxy_only_allows_validator_calls() {
+	let operator = TransparentProxyType(ProxyType::ValidatorOperator);
+	let keys = SessionKeys::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes())
+		.expect("session keys decode from zeroes");
+
+	let allowed = [
+		RuntimeCall::Staking(pallet_staking::Call::validate { prefs: Default::default() }),
+		RuntimeCall::Staking(pallet_staking::Call::chill {}),
+		RuntimeCall::Session(pallet_session::Call::set_keys { keys, proof: vec![] }),
+		RuntimeCall::Session(pallet_session::Call::purge_keys {}),
+		RuntimeCall::Utility(pallet_utility::Call::batch_all { calls: vec![] }),
+	];
+	for call in &allowed {
+		assert!(operator.filter(call), "{call:?} should be allowed");
+		assert!(TransparentProxyType(ProxyType::Staking).filter(call));
+	}
+
+	let denied = [
+		RuntimeCall::Staking(pallet_staking::Call::bond_extra { max_additional: 1 }),
+		RuntimeCall::Staking(pallet_staking::Call::unbond { value: 1 }),
+		RuntimeCall::Staking(pallet_staking::Call::nominate { targets: vec![] }),
+		RuntimeCall::Staking(pallet_staking::Call::set_payee {
+			payee: pallet_staking::RewardDestination::Stash,
+		}),
+		RuntimeCall::Staking(pallet_staking::Call::withdraw_unbonded { num_slashing_spans: 0 }),
+		RuntimeCall::Balances(pallet_balances::Call::transfer_all {
+			dest: AccountId::from([0; 32]).into(),
+			keep_alive: false,
+		}),
+		RuntimeCall::Proxy(pallet_proxy::Call::remove_proxies {}),
+	];
+	for call in &denied {
+		assert!(!operator.filter(call), "{call:?} should be denied");
+	}
+
+	for proxy_type in [ProxyType::Any, ProxyType::NonTransfer, ProxyType::Staking] {
+		assert!(TransparentProxyType(proxy_type).is_superset(&operator));
+	}
+	assert!(!operator.is_superset(&TransparentProxyType(ProxyType::Staking)));
+	assert!(!TransparentProxyType(ProxyType::NominationPools).is_superset(&operator));
+}
+
+#[test]
+fn referenda_tracks_api_works() {
+	use frame_support::traits::PalletInfoAccess;
+	use pallet_referenda::TracksInfo as _;
+	type FellowshipTracks =
+		<Runtime as pallet_referenda::Config<governance::FellowshipReferendaInstance>>::Tracks;
+
+	let referenda = <Referenda as PalletInfoAccess>::index() as u8;
+	let fellowship = <FellowshipReferenda as PalletInfoAccess>::index() as u8;
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		let tracks = ReferendaPallets::tracks(referenda).unwrap();
+		assert_eq!(tracks.len(), governance::TracksInfo::tracks().count());
+		assert!(tracks.iter().any(|track| track.id == 16));
+		assert_eq!(
+			ReferendaPallets::tracks(fellowship).unwrap().len(),
+			FellowshipTracks::tracks().count(),
+		);
+		assert!(ReferendaPallets::tracks(0).is_none());
+
+		let root = governance::TracksInfo::info(0).unwrap();
+		for elapsed in [Perbill::zero(), Perbill::from_percent(50), Perbill::one()] {
+			assert_eq!(
+				ReferendaPallets::track_thresholds(referenda, 0, elapsed),
+				Some(referenda_tracks_api::Thresholds {
+					approval: root.min_approval.threshold(elapsed),
+					support: root.min_support.threshold(elapsed),
+				})
+			);
+		}
+		assert!(ReferendaPallets::track_thresholds(referenda, 99, Perbill::zero()).is_none());
+		assert!(ReferendaPallets::referendum_thresholds(referenda, 0, 1).is_none());
+	});
+}
+
+#[test]
+fn referenda_tracks_api_works_for_ongoing_referenda() {
+	use pallet_referenda::TracksInfo as _;
+	use referenda_tracks_api::testing::check_referendum_thresholds;
+	type FellowshipTracks =
+		<Runtime as pallet_referenda::Config<governance::FellowshipReferendaInstance>>::Tracks;
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		System::set_block_number(1_000_000);
+		let root: OriginCaller = frame_system::RawOrigin::Root.into();
+
+		for track in governance::TracksInfo::tracks().map(|track| track.id) {
+			check_referendum_thresholds::<Runtime, (), ReferendaPallets>(track, root.clone());
+		}
+		for track in FellowshipTracks::tracks().map(|track| track.id) {
+			check_referendum_thresholds::<
+				Runtime,
+				governance::FellowshipReferendaInstance,
+				ReferendaPallets,
+			>(track, root.clone());
+		}
+	});
+}
@@ -0,0 +1,205 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Handling of the treasury burn.
+//!
+//! Every spend period, [`dynamic_params::treasury::BurnPortion`] of the treasury surplus is handed
+//! to the [`TreasuryBurnHandler`], which sends it to the configured
+//! [`dynamic_params::treasury::BurnDestination`] or burns it if none is configured. The handled
+//! amounts are accounted for in [`pallet_treasury_burn`].
+
+use crate::{
+	dynamic_params, xcm_config, AccountId, Balances, BalancesNegativeImbalance, Runtime,
+	RuntimeOrigin, Treasury, XcmPallet,
+};
+use alloc::boxed::Box;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{
+	storage::with_storage_layer,
+	traits::{Currency, Get, Imbalance, OnUnbalanced},
+};
+use scale_info::TypeInfo;
+use sp_runtime::Permill;
+use xcm::prelude::*;
+use xcm_executor::traits::ConvertLocation;
+
+pub use pallet_treasury_burn::*;
+
+/// Where the treasury burn is sent to.
+///
+/// The encoding of [`Self::Burn`] and [`Self::Account`] matches the one of the former
+/// `Option<AccountId>` destination.
+#[derive(
+	Default,
+	MaxEncodedLen,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	TypeInfo,
+	Clone,
+	Eq,
+	PartialEq,
+	Debug,
+)]
+pub enum TreasuryBurnDestination {
+	/// Burn the amount.
+	#[default]
+	#[codec(index = 0)]
+	Burn,
+	/// Send the amount to a local account.
+	#[codec(index = 1)]
+	Account(AccountId),
+	/// Send the amount to a location, e.g. an account on Asset Hub.
+	///
+	/// Locations that map to a local account are credited directly. Locations within a child
+	/// parachain receive the amount through a teleport from the treasury account.
+	#[codec(index = 2)]
+	Location(Location),
+}
+
+#[frame_support::pallet]
+pub mod pallet_treasury_burn {
+	use super::TreasuryBurnDestination;
+	use crate::Balance;
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The cumulative amount of the treasury burn that was burned.
+	#[pallet::storage]
+	pub type TotalBurned<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The cumulative amount of the treasury burn that was sent to a burn destination.
+	#[pallet::storage]
+	pub type TotalRedirected<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// `amount` of the treasury burn was burned.
+		Burned { amount: Balance },
+		/// `amount` of the treasury burn was sent to `destination`.
+		Redirected { amount: Balance, destination: TreasuryBurnDestination },
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account for `amount` being burned.
+		pub(crate) fn note_burned(amount: Balance) {
+			TotalBurned::<T>::mutate(|total| *total = total.saturating_add(amount));
+			Self::deposit_event(Event::Burned { amount });
+		}
+
+		/// Account for `amount` being sent to `destination`.
+		pub(crate) fn note_redirected(amount: Balance, destination: TreasuryBurnDestination) {
+			TotalRedirected::<T>::mutate(|total| *total = total.saturating_add(amount));
+			Self::deposit_event(Event::Redirected { amount, destination });
+		}
+	}
+}
+
+type TreasuryBurn = pallet_treasury_burn::Pallet<Runtime>;
+
+pub struct TreasuryBurnHandler;
+
+impl TreasuryBurnHandler {
+	/// Send `amount` to `location`.
+	///
+	/// Accounts within a child parachain are paid through a teleport from the treasury account,
+	/// all other locations are credited to their local sovereign account. If neither works, the
+	/// amount stays in the treasury.
+	fn send_to_location(amount: BalancesNegativeImbalance, location: Location) {
+		let value = amount.peek();
+
+		let (beneficiary, chain) = location.clone().split_first_interior();
+		let para_id = match (location.parent_count(), chain) {
+			(0, Some(Parachain(id))) if !beneficiary.is_here() => id,
+			_ => {
+				match xcm_config::SovereignAccountOf::convert_location(&location) {
+					Some(account) => {
+						Balances::resolve_creating(&account, amount);
+						TreasuryBurn::note_redirected(
+							value,
+							TreasuryBurnDestination::Location(location),
+						);
+					},
+					None => {
+						log::error!(
+							target: "runtime::treasury_burn",
+							"Unsupported burn destination {location:?}, keeping {value} in the treasury",
+						);
+						Balances::resolve_creating(&Treasury::account_id(), amount);
+					},
+				}
+				return
+			},
+		};
+
+		let treasury = Treasury::account_id();
+		Balances::resolve_creating(&treasury, amount);
+		let assets: Assets = (Here, value).into();
+		let result = with_storage_layer(|| {
+			XcmPallet::transfer_assets(
+				RuntimeOrigin::signed(treasury),
+				Box::new(Location::new(0, Parachain(para_id)).into()),
+				Box::new(beneficiary.into()),
+				Box::new(assets.into()),
+				0,
+				Unlimited,
+			)
+		});
+		match result {
+			Ok(_) =>
+				TreasuryBurn::note_redirected(value, TreasuryBurnDestination::Location(location)),
+			Err(error) => log::error!(
+				target: "runtime::treasury_burn",
+				"Failed to send {value} to {location:?}, keeping it in the treasury: {error:?}",
+			),
+		}
+	}
+}
+
+impl OnUnbalanced<BalancesNegativeImbalance> for TreasuryBurnHandler {
+	fn on_nonzero_unbalanced(amount: BalancesNegativeImbalance) {
+		let value = amount.peek();
+
+		match dynamic_params::treasury::BurnDestination::get() {
+			TreasuryBurnDestination::Burn => {
+				// Dropping the imbalance burns it.
+				<() as OnUnbalanced<_>>::on_nonzero_unbalanced(amount);
+				TreasuryBurn::note_burned(value);
+			},
+			TreasuryBurnDestination::Account(account) => {
+				// Must resolve into existing but better to be safe.
+				Balances::resolve_creating(&account, amount);
+				TreasuryBurn::note_redirected(value, TreasuryBurnDestination::Account(account));
+			},
+			TreasuryBurnDestination::Location(location) => Self::send_to_location(amount, location),
+		}
+	}
+}
+
+impl Get<Permill> for TreasuryBurnHandler {
+	fn get() -> Permill {
+		dynamic_params::treasury::BurnPortion::get()
+	}
+}
@@ -26,6 +26,7 @@ pub mod pallet_beefy_mmr;
 pub mod pallet_bounties;
 pub mod pallet_child_bounties;
 pub mod pallet_conviction_voting;
+pub mod pallet_coretime_assignment_buffer;
 pub mod pallet_election_provider_multi_phase;
 pub mod pallet_fast_unstake;
 pub mod pallet_indices;
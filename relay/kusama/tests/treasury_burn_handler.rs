@@ -31,9 +31,11 @@ use polkadot_primitives::{AccountId, Balance};
 use sp_arithmetic::Permill;
 use staging_kusama_runtime::{
 	dynamic_params::treasury::{self, BurnDestination, BurnPortion},
-	Balances, BurnDestinationAccount, Parameters, RuntimeOrigin, RuntimeParameters, Treasury,
-	TreasuryBurnHandler,
+	treasury_burn::{Event, TotalBurned, TotalRedirected},
+	Balances, Parameters, Runtime, RuntimeEvent, RuntimeOrigin, RuntimeParameters, System,
+	Treasury, TreasuryBurnDestination, TreasuryBurnHandler,
 };
+use xcm::prelude::*;
 
 parameter_types! {
 	TreasuryAccount: AccountId = Treasury::account_id();
@@ -44,8 +46,33 @@ const BURN_DESTINATION_ACCOUNT: AccountId = AccountId::new([1u8; 32]);
 const TREASURY_AMOUNT: Balance = 10 * UNITS;
 const SURPLUS: Balance = UNITS;
 
+fn set_burn_portion(portion: Permill) {
+	Parameters::set_parameter(
+		RuntimeOrigin::root(),
+		RuntimeParameters::Treasury(treasury::Parameters::BurnPortion(BurnPortion, Some(portion))),
+	)
+	.expect("parameters are set accordingly; qed");
+}
+
+fn set_burn_destination(destination: TreasuryBurnDestination) {
+	Parameters::set_parameter(
+		RuntimeOrigin::root(),
+		RuntimeParameters::Treasury(treasury::Parameters::BurnDestination(
+			BurnDestination,
+			Some(destination),
+		)),
+	)
+	.expect("parameters are set accordingly; qed");
+}
+
+fn last_event() -> RuntimeEvent {
+	System::events().pop().expect("an event was deposited; qed").event
+}
+
 fn test(pre: impl FnOnce(), test: impl FnOnce(Balance)) {
 	sp_io::TestExternalities::default().execute_with(|| {
+		// Events are not deposited in the genesis block.
+		System::set_block_number(1);
 		pre();
 
 		Balances::set_balance(&TreasuryAccount::get(), TREASURY_AMOUNT);
@@ -73,14 +100,6 @@ fn test(pre: impl FnOnce(), test: impl FnOnce(Balance)) {
 		TreasuryBurnHandler::on_unbalanced(credit);
 
 		test(amount_to_handle);
-
-		// Only the amount to handle was transferred to the burn destination account
-		// let burn_destination_account = BurnDestination::get();
-		let burn_destination_account = BURN_DESTINATION_ACCOUNT;
-		let burn_destination_account_balance =
-			<Balances as Inspect<_>>::total_balance(&burn_destination_account);
-
-		assert_eq!(burn_destination_account_balance, amount_to_handle);
 	})
 }
 
@@ -91,6 +110,8 @@ fn on_burn_parameters_not_set_does_not_handle_burn() {
 		|amount_to_handle| {
 			// Amount to burn should be zero by default
 			assert_eq!(amount_to_handle, 0);
+			assert_eq!(TotalBurned::<Runtime>::get(), 0);
+			assert_eq!(TotalRedirected::<Runtime>::get(), 0);
 		},
 	)
 }
@@ -98,40 +119,28 @@ fn on_burn_parameters_not_set_does_not_handle_burn() {
 #[test]
 fn on_burn_portion_not_set_does_not_handle_burn() {
 	test(
-		|| {
-			Parameters::set_parameter(
-				RuntimeOrigin::root(),
-				RuntimeParameters::Treasury(treasury::Parameters::BurnDestination(
-					BurnDestination,
-					Some(BurnDestinationAccount(Some(BURN_DESTINATION_ACCOUNT))),
-				)),
-			)
-			.expect("parameters are set accordingly; qed");
-		},
+		|| set_burn_destination(TreasuryBurnDestination::Account(BURN_DESTINATION_ACCOUNT)),
 		|amount_to_handle| {
 			// Amount to burn should be zero by default
 			assert_eq!(amount_to_handle, 0);
+			assert_eq!(Balances::total_balance(&BURN_DESTINATION_ACCOUNT), 0);
+			assert_eq!(TotalRedirected::<Runtime>::get(), 0);
 		},
 	)
 }
 
 #[test]
-fn on_burn_destination_not_set_does_not_handle_burn() {
+fn on_burn_destination_not_set_burns() {
 	let one_percent = Permill::from_percent(1);
 	test(
-		|| {
-			Parameters::set_parameter(
-				RuntimeOrigin::root(),
-				RuntimeParameters::Treasury(treasury::Parameters::BurnPortion(
-					BurnPortion,
-					Some(one_percent),
-				)),
-			)
-			.expect("parameters are set accordingly; qed");
-		},
+		|| set_burn_portion(one_percent),
 		|amount_to_handle| {
-			// Amount to burn should be zero by default
-			assert_eq!(amount_to_handle, 0);
+			assert_eq!(amount_to_handle, one_percent * SURPLUS);
+			// The handled amount is burned as well.
+			assert_eq!(Balances::total_issuance(), TREASURY_AMOUNT - SURPLUS);
+			assert_eq!(TotalBurned::<Runtime>::get(), amount_to_handle);
+			assert_eq!(TotalRedirected::<Runtime>::get(), 0);
+			assert_eq!(last_event(), Event::<Runtime>::Burned { amount: amount_to_handle }.into());
 		},
 	)
 }
@@ -141,26 +150,72 @@ fn on_burn_parameters_set_works() {
 	let one_percent = Permill::from_percent(1);
 	test(
 		|| {
-			Parameters::set_parameter(
-				RuntimeOrigin::root(),
-				RuntimeParameters::Treasury(treasury::Parameters::BurnDestination(
-					BurnDestination,
-					Some(BurnDestinationAccount(Some(BURN_DESTINATION_ACCOUNT))),
-				)),
-			)
-			.expect("parameters are set accordingly; qed");
-			Parameters::set_parameter(
-				RuntimeOrigin::root(),
-				RuntimeParameters::Treasury(treasury::Parameters::BurnPortion(
-					BurnPortion,
-					Some(one_percent),
-				)),
-			)
-			.expect("parameters are set accordingly; qed");
+			set_burn_destination(TreasuryBurnDestination::Account(BURN_DESTINATION_ACCOUNT));
+			set_burn_portion(one_percent);
 		},
 		|amount_to_handle| {
-			// Amount to burn should be zero by default
 			assert_eq!(amount_to_handle, one_percent * SURPLUS);
+			// Only the amount to handle was transferred to the burn destination account
+			assert_eq!(Balances::total_balance(&BURN_DESTINATION_ACCOUNT), amount_to_handle);
+			assert_eq!(TotalRedirected::<Runtime>::get(), amount_to_handle);
+			assert_eq!(TotalBurned::<Runtime>::get(), 0);
+			assert_eq!(
+				last_event(),
+				Event::<Runtime>::Redirected {
+					amount: amount_to_handle,
+					destination: TreasuryBurnDestination::Account(BURN_DESTINATION_ACCOUNT),
+				}
+				.into()
+			);
+		},
+	)
+}
+
+#[test]
+fn on_burn_local_location_set_works() {
+	let one_percent = Permill::from_percent(1);
+	let location =
+		Location::new(0, AccountId32 { network: None, id: BURN_DESTINATION_ACCOUNT.into() });
+	test(
+		|| {
+			set_burn_destination(TreasuryBurnDestination::Location(location.clone()));
+			set_burn_portion(one_percent);
+		},
+		|amount_to_handle| {
+			assert_eq!(Balances::total_balance(&BURN_DESTINATION_ACCOUNT), amount_to_handle);
+			assert_eq!(TotalRedirected::<Runtime>::get(), amount_to_handle);
+			assert_eq!(
+				last_event(),
+				Event::<Runtime>::Redirected {
+					amount: amount_to_handle,
+					destination: TreasuryBurnDestination::Location(location.clone()),
+				}
+				.into()
+			);
+		},
+	)
+}
+
+#[test]
+fn on_burn_unreachable_location_keeps_amount_in_treasury() {
+	let one_percent = Permill::from_percent(1);
+	// Asset Hub is not registered, so the amount cannot be sent.
+	let location = Location::new(
+		0,
+		[Parachain(1000), AccountId32 { network: None, id: BURN_DESTINATION_ACCOUNT.into() }],
+	);
+	test(
+		|| {
+			set_burn_destination(TreasuryBurnDestination::Location(location.clone()));
+			set_burn_portion(one_percent);
+		},
+		|amount_to_handle| {
+			assert_eq!(
+				Balances::balance(&TreasuryAccount::get()),
+				TREASURY_AMOUNT - SURPLUS + amount_to_handle
+			);
+			assert_eq!(TotalRedirected::<Runtime>::get(), 0);
+			assert_eq!(TotalBurned::<Runtime>::get(), 0);
 		},
 	)
 }
@@ -26,7 +26,8 @@ use xcm::latest::BodyId;
 mod origins;
 pub use origins::{
 	pallet_custom_origins, AuctionAdmin, FellowshipAdmin, GeneralAdmin, LeaseAdmin,
-	ReferendumCanceller, ReferendumKiller, Spender, StakingAdmin, Treasurer, WhitelistedCaller,
+	ParametersAdmin, ReferendumCanceller, ReferendumKiller, Spender, StakingAdmin, Treasurer,
+	WhitelistedCaller,
 };
 mod tracks;
 pub use tracks::TracksInfo;
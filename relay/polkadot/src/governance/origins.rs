@@ -73,6 +73,8 @@ pub mod pallet_custom_origins {
 		WhitelistedCaller,
 		/// Origin for signaling that the network wishes for some change.
 		WishForChange,
+		/// Origin for changing the economic runtime parameters, such as the inflation.
+		ParametersAdmin,
 	}
 
 	macro_rules! decl_unit_ensures {
@@ -116,6 +118,7 @@ pub mod pallet_custom_origins {
 		ReferendumKiller,
 		WhitelistedCaller,
 		WishForChange,
+		ParametersAdmin,
 	);
 
 	macro_rules! decl_ensure {
@@ -38,7 +38,8 @@ use polkadot_runtime_common::{
 	traits::OnSwap,
 	BlockHashCount, BlockLength, CurrencyToVote, SlowAdjustingFeeUpdate,
 };
-use relay_common::apis::InflationInfo;
+use referenda_tracks_api::ReferendaTracks;
+use relay_common::apis::{EraMint, InflationInfo, StakingRewardsInfo};
 
 use runtime_parachains::{
 	assigner_coretime as parachains_assigner_coretime, configuration as parachains_configuration,
@@ -70,14 +71,15 @@ use frame_election_provider_support::{
 };
 use frame_support::{
 	construct_runtime,
+	dynamic_params::{dynamic_pallet_params, dynamic_params},
 	genesis_builder_helper::{build_state, get_preset},
 	parameter_types,
 	traits::{
 		fungible::HoldConsideration,
 		tokens::{imbalance::ResolveTo, UnityOrOuterConversion},
-		ConstU32, ConstU8, EitherOf, EitherOfDiverse, Everything, FromContains, Get,
-		InstanceFilter, KeyOwnerProofSystem, LinearStoragePrice, PrivilegeCmp, ProcessMessage,
-		ProcessMessageError, WithdrawReasons,
+		ConstU32, ConstU8, EitherOf, EitherOfDiverse, EnsureOrigin, EnsureOriginWithArg,
+		Everything, FromContains, Get, InstanceFilter, KeyOwnerProofSystem, LinearStoragePrice,
+		PrivilegeCmp, ProcessMessage, ProcessMessageError, WithdrawReasons,
 	},
 	weights::{
 		constants::{WEIGHT_PROOF_SIZE_PER_KB, WEIGHT_REF_TIME_PER_MICROS},
@@ -146,8 +148,8 @@ pub mod genesis_config_presets;
 // Governance configurations.
 pub mod governance;
 use governance::{
-	pallet_custom_origins, AuctionAdmin, FellowshipAdmin, GeneralAdmin, LeaseAdmin, StakingAdmin,
-	Treasurer, TreasurySpender,
+	pallet_custom_origins, AuctionAdmin, FellowshipAdmin, GeneralAdmin, LeaseAdmin,
+	ParametersAdmin, StakingAdmin, Treasurer, TreasurySpender,
 };
 pub mod impls;
 pub mod xcm_config;
@@ -636,7 +638,78 @@ impl pallet_bags_list::Config<VoterBagsListInstance> for Runtime {
 	type Score = sp_npos_elections::VoteWeight;
 }
 
+/// Dynamic params that can be adjusted at runtime.
+#[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
+pub mod dynamic_params {
+	use super::*;
+
+	/// Parameters used to calculate era payouts, see [`EraPayout`].
+	#[dynamic_pallet_params]
+	#[codec(index = 0)]
+	pub mod inflation {
+		/// The total issuance the yearly emission is calculated from.
+		///
+		/// TI at the time of execution of [Referendum 1139](https://polkadot.subsquare.io/referenda/1139),
+		/// block hash: `0x39422610299a75ef69860417f4d0e1d94e77699f45005645ffc5e8e619950f9f`.
+		#[codec(index = 0)]
+		pub static FixedTotalIssuance: Balance = 15_011_657_390_566_252_333;
+
+		/// The yearly emission as a fraction of [`FixedTotalIssuance`].
+		#[codec(index = 1)]
+		pub static AnnualInflationRate: Perquintill = Perquintill::from_percent(8);
+
+		/// The portion of each era emission that goes to the treasury.
+		#[codec(index = 2)]
+		pub static TreasuryPortion: Perquintill = Perquintill::from_percent(15);
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl Default for RuntimeParameters {
+	fn default() -> Self {
+		RuntimeParameters::Inflation(dynamic_params::inflation::Parameters::AnnualInflationRate(
+			dynamic_params::inflation::AnnualInflationRate,
+			Some(Perquintill::from_percent(8)),
+		))
+	}
+}
+
+/// Defines what origin can modify which dynamic parameters.
+pub struct DynamicParameterOrigin;
+impl EnsureOriginWithArg<RuntimeOrigin, RuntimeParametersKey> for DynamicParameterOrigin {
+	type Success = ();
+
+	fn try_origin(
+		origin: RuntimeOrigin,
+		key: &RuntimeParametersKey,
+	) -> Result<Self::Success, RuntimeOrigin> {
+		use crate::RuntimeParametersKey::*;
+
+		match key {
+			Inflation(_) =>
+				EitherOf::<EnsureRoot<AccountId>, ParametersAdmin>::ensure_origin(origin.clone()),
+		}
+		.map_err(|_| origin)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(_key: &RuntimeParametersKey) -> Result<RuntimeOrigin, ()> {
+		// Provide the origin for the parameter returned by `Default`:
+		Ok(RuntimeOrigin::root())
+	}
+}
+
+impl pallet_parameters::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeParameters = RuntimeParameters;
+	type AdminOrigin = DynamicParameterOrigin;
+	type WeightInfo = weights::pallet_parameters::WeightInfo<Runtime>;
+}
+
 /// Defines how much should the inflation be for an era given its duration.
+///
+/// The yearly emission is a fixed portion of a fixed total issuance, both controlled by
+/// [`dynamic_params::inflation`].
 pub struct EraPayout;
 impl pallet_staking::EraPayout<Balance> for EraPayout {
 	fn era_payout(
@@ -648,18 +721,20 @@ impl pallet_staking::EraPayout<Balance> for EraPayout {
 		// A normal-sized era will have 1 / 365.25 here:
 		let relative_era_len =
 			FixedU128::from_rational(era_duration_millis.into(), MILLISECONDS_PER_YEAR.into());
+		let to_fixed = |portion: Perquintill| {
+			FixedU128::from_rational(portion.deconstruct().into(), Perquintill::ACCURACY.into())
+		};
 
-		// TI at the time of execution of [Referendum 1139](https://polkadot.subsquare.io/referenda/1139), block hash: `0x39422610299a75ef69860417f4d0e1d94e77699f45005645ffc5e8e619950f9f`.
-		let fixed_total_issuance: i128 = 15_011_657_390_566_252_333;
-		let fixed_inflation_rate = FixedU128::from_rational(8, 100);
+		let fixed_total_issuance = dynamic_params::inflation::FixedTotalIssuance::get();
+		let fixed_inflation_rate = to_fixed(dynamic_params::inflation::AnnualInflationRate::get());
 		let yearly_emission = fixed_inflation_rate.saturating_mul_int(fixed_total_issuance);
 
 		let era_emission = relative_era_len.saturating_mul_int(yearly_emission);
-		// 15% to treasury, as per ref 1139.
-		let to_treasury = FixedU128::from_rational(15, 100).saturating_mul_int(era_emission);
+		let to_treasury = to_fixed(dynamic_params::inflation::TreasuryPortion::get())
+			.saturating_mul_int(era_emission);
 		let to_stakers = era_emission.saturating_sub(to_treasury);
 
-		(to_stakers.saturated_into(), to_treasury.saturated_into())
+		(to_stakers, to_treasury)
 	}
 }
 
@@ -687,6 +762,14 @@ parameter_types! {
 	// 16
 	pub const MaxNominations: u32 = <NposCompactSolution16 as frame_election_provider_support::NposSolution>::LIMIT as u32;
 	pub TreasuryAccount: AccountId = Treasury::account_id();
+	pub ActiveEraIndex: Option<sp_staking::EraIndex> =
+		pallet_staking::ActiveEra::<Runtime>::get().map(|era| era.index);
+}
+
+impl pallet_era_treasury_mints::Config for Runtime {
+	type Balance = Balance;
+	type ActiveEra = ActiveEraIndex;
+	type HistoryDepth = <Runtime as pallet_staking::Config>::HistoryDepth;
 }
 
 impl pallet_staking::Config for Runtime {
@@ -696,7 +779,8 @@ impl pallet_staking::Config for Runtime {
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type UnixTime = Timestamp;
 	type CurrencyToVote = CurrencyToVote;
-	type RewardRemainder = ResolveTo<TreasuryAccount, Balances>;
+	type RewardRemainder =
+		pallet_era_treasury_mints::RecordEraMint<Runtime, ResolveTo<TreasuryAccount, Balances>>;
 	type RuntimeEvent = RuntimeEvent;
 	type Slash = ResolveTo<TreasuryAccount, Balances>;
 	type Reward = ();
@@ -741,6 +825,8 @@ parameter_types! {
 	pub const Burn: Permill = Permill::from_percent(1);
 	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
 	pub const PayoutSpendPeriod: BlockNumber = 90 * DAYS;
+	// Matches the timeout of the XCM query of `PayOverXcm`.
+	pub const TreasuryPayoutTimeout: BlockNumber = 6 * HOURS;
 	// The asset's interior location for the paying account. This is the Treasury
 	// pallet instance (which sits at index 19).
 	pub TreasuryInteriorLocation: InteriorLocation = PalletInstance(TREASURY_PALLET_ID).into();
@@ -776,7 +862,7 @@ impl pallet_treasury::Config for Runtime {
 		TreasuryInteriorLocation,
 		crate::xcm_config::XcmRouter,
 		crate::XcmPallet,
-		ConstU32<{ 6 * HOURS }>,
+		TreasuryPayoutTimeout,
 		Self::Beneficiary,
 		Self::AssetKind,
 		LocatableAssetConverter,
@@ -789,6 +875,22 @@ impl pallet_treasury::Config for Runtime {
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::TreasuryArguments;
 }
 
+parameter_types! {
+	pub const TreasurySpendTrackerPalletId: PalletId = PalletId(*b"py/trsrt");
+	pub const MaxPayoutRetries: u32 = 3;
+	pub const PayoutCheckInterval: BlockNumber = 10 * MINUTES;
+	pub const MaxSucceededSpends: u32 = 100;
+}
+
+impl pallet_treasury_spend_tracker::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type PalletId = TreasurySpendTrackerPalletId;
+	type MaxRetries = MaxPayoutRetries;
+	type CheckInterval = PayoutCheckInterval;
+	type PayoutTimeout = TreasuryPayoutTimeout;
+	type MaxSucceeded = MaxSucceededSpends;
+}
+
 parameter_types! {
 	pub const BountyDepositBase: Balance = DOLLARS;
 	pub const BountyDepositPayoutDelay: BlockNumber = 0;
@@ -1139,6 +1241,16 @@ impl InstanceFilter<RuntimeCall> for TransparentProxyType<ProxyType> {
 					RuntimeCall::Utility(pallet_utility::Call::force_batch { .. }) |
 					RuntimeCall::Proxy(pallet_proxy::Call::remove_proxy { .. })
 			),
+			ProxyType::ValidatorOperator => matches!(
+				c,
+				RuntimeCall::Staking(pallet_staking::Call::validate { .. }) |
+					RuntimeCall::Staking(pallet_staking::Call::chill { .. }) |
+					RuntimeCall::Session(pallet_session::Call::set_keys { .. }) |
+					RuntimeCall::Session(pallet_session::Call::purge_keys { .. }) |
+					RuntimeCall::Utility(pallet_utility::Call::batch { .. }) |
+					RuntimeCall::Utility(pallet_utility::Call::batch_all { .. }) |
+					RuntimeCall::Utility(pallet_utility::Call::force_batch { .. })
+			),
 		}
 	}
 
@@ -1148,6 +1260,7 @@ impl InstanceFilter<RuntimeCall> for TransparentProxyType<ProxyType> {
 			(ProxyType::Any, _) => true,
 			(_, ProxyType::Any) => false,
 			(ProxyType::NonTransfer, _) => true,
+			(ProxyType::Staking, ProxyType::ValidatorOperator) => true,
 			_ => false,
 		}
 	}
@@ -1337,6 +1450,60 @@ impl parachains_on_demand::Config for Runtime {
 
 impl parachains_assigner_coretime::Config for Runtime {}
 
+/// Ensures that the origin is the Coretime chain.
+pub struct EnsureBroker;
+impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for EnsureBroker {
+	type Success = ();
+
+	fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+		match o.clone().into() {
+			Ok(parachains_origin::Origin::Parachain(id)) if id == BrokerId::get().into() => Ok(()),
+			_ => Err(o),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(parachains_origin::Origin::Parachain(BrokerId::get().into()).into())
+	}
+}
+
+/// Forwards the complete assignments of [`CoretimeAssignmentBuffer`] to the coretime assigner.
+pub struct AssignCoreToAssigner;
+impl pallet_coretime_assignment_buffer::AssignCore<BlockNumber> for AssignCoreToAssigner {
+	fn assign_core(
+		core: pallet_coretime_assignment_buffer::CoreIndex,
+		begin: BlockNumber,
+		assignment: Vec<(
+			pallet_coretime_assignment_buffer::CoreAssignment,
+			pallet_coretime_assignment_buffer::PartsOf57600,
+		)>,
+		end_hint: Option<BlockNumber>,
+	) -> sp_runtime::DispatchResult {
+		let assignment = assignment
+			.into_iter()
+			.map(|(assignment, parts)| {
+				(assignment, parachains_assigner_coretime::PartsOf57600::new_saturating(parts))
+			})
+			.collect();
+		parachains_assigner_coretime::Pallet::<Runtime>::assign_core(
+			CoreIndex(core.into()),
+			begin,
+			assignment,
+			end_hint,
+		)
+	}
+}
+
+impl pallet_coretime_assignment_buffer::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssignOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureBroker>;
+	type AssignCore = AssignCoreToAssigner;
+	type MaxAssignments = ConstU32<100>;
+	type MaxPendingAssignments = ConstU32<1_000>;
+	type WeightInfo = weights::pallet_coretime_assignment_buffer::WeightInfo<Runtime>;
+}
+
 impl parachains_initializer::Config for Runtime {
 	type Randomness = pallet_babe::RandomnessFromOneEpochAgo<Runtime>;
 	type ForceOrigin = EnsureRoot<AccountId>;
@@ -1581,6 +1748,8 @@ construct_runtime! {
 		Referenda: pallet_referenda = 21,
 		Origins: pallet_custom_origins = 22,
 		Whitelist: pallet_whitelist = 23,
+		Parameters: pallet_parameters = 46,
+		TreasurySpendTracker: pallet_treasury_spend_tracker = 49,
 
 		// Claims. Usable initially.
 		Claims: claims = 24,
@@ -1616,6 +1785,9 @@ construct_runtime! {
 		// Staking extension for delegation
 		DelegatedStaking: pallet_delegated_staking = 41,
 
+		// Treasury part of the era payouts.
+		EraTreasuryMints: pallet_era_treasury_mints = 76,
+
 		// Parachains pallets. Start indices at 50 to leave room.
 		ParachainsOrigin: parachains_origin = 50,
 		Configuration: parachains_configuration = 51,
@@ -1640,6 +1812,7 @@ construct_runtime! {
 		Auctions: auctions = 72,
 		Crowdloan: crowdloan = 73,
 		Coretime: coretime = 74,
+		CoretimeAssignmentBuffer: pallet_coretime_assignment_buffer = 75,
 
 		// State trie migration pallet, only temporary.
 		StateTrieMigration: pallet_state_trie_migration = 98,
@@ -1756,6 +1929,7 @@ mod benches {
 		[runtime_parachains::paras_inherent, ParaInherent]
 		[runtime_parachains::on_demand, OnDemand]
 		[runtime_parachains::coretime, Coretime]
+		[pallet_coretime_assignment_buffer, CoretimeAssignmentBuffer]
 		// Substrate
 		[pallet_bags_list, VoterList]
 		[pallet_balances, Balances]
@@ -1786,6 +1960,7 @@ mod benches {
 		[pallet_conviction_voting, ConvictionVoting]
 		[pallet_referenda, Referenda]
 		[pallet_whitelist, Whitelist]
+		[pallet_parameters, Parameters]
 		[pallet_asset_rate, AssetRate]
 		// XCM
 		[pallet_xcm, PalletXcmExtrinsicsBenchmark::<Runtime>]
@@ -2019,13 +2194,104 @@ impl Runtime {
 
 		InflationInfo { inflation, next_mint }
 	}
+
+	fn impl_experimental_staking_rewards_info(eras: u32, stake: Balance) -> StakingRewardsInfo {
+		use pallet_staking::{ActiveEra, ErasTotalStake, ErasValidatorPrefs, ErasValidatorReward};
+		const MILLISECONDS_PER_YEAR: u64 = (1000 * 3600 * 24 * 36525) / 100;
+
+		let active_era = ActiveEra::<Runtime>::get().map(|ae| ae.index).unwrap_or(0);
+		let era_mints = (active_era.saturating_sub(eras)..active_era)
+			.filter_map(|era| {
+				let to_stakers = ErasValidatorReward::<Runtime>::get(era)?;
+				let to_treasury = EraTreasuryMints::mint_of(era).unwrap_or_default();
+				Some(EraMint { era, to_stakers, to_treasury })
+			})
+			.collect();
+
+		let total_staked = ErasTotalStake::<Runtime>::get(active_era);
+		let current_stake = Perquintill::from_rational(total_staked, Balances::total_issuance());
+
+		let (validator_count, total_commission) =
+			ErasValidatorPrefs::<Runtime>::iter_prefix_values(active_era).fold(
+				(0u32, 0u64),
+				|(count, total), prefs| {
+					(
+						count.saturating_add(1),
+						total.saturating_add(prefs.commission.deconstruct().into()),
+					)
+				},
+			);
+		let average_commission = Perbill::from_parts(
+			total_commission.checked_div(validator_count.into()).unwrap_or_default() as u32,
+		);
+
+		let eras_per_year = MILLISECONDS_PER_YEAR / (24 * (HOURS as Moment) * MILLISECS_PER_BLOCK);
+		let (next_staking_mint, _) = Self::impl_experimental_inflation_info().next_mint;
+		let (validator_apy, nominator_apy) = relay_common::estimate_staking_apy(
+			next_staking_mint.saturating_mul(eras_per_year.into()),
+			total_staked,
+			validator_count,
+			average_commission,
+			stake,
+		);
+
+		StakingRewardsInfo {
+			era_mints,
+			// The era payout does not depend on the stake.
+			ideal_stake: None,
+			current_stake,
+			validator_apy,
+			nominator_apy,
+		}
+	}
 }
 
+/// The referenda pallets exposed through the `ReferendaTracksApi`.
+type ReferendaPallets = Referenda;
+
 sp_api::impl_runtime_apis! {
+	#[api_version(2)]
 	impl relay_common::apis::Inflation<Block> for Runtime {
 		fn experimental_inflation_prediction_info() -> InflationInfo {
 			Runtime::impl_experimental_inflation_info()
 		}
+
+		fn experimental_staking_rewards_info(eras: u32, stake: Balance) -> StakingRewardsInfo {
+			Runtime::impl_experimental_staking_rewards_info(eras, stake)
+		}
+	}
+
+	impl referenda_tracks_api::ReferendaTracksApi<Block, u16, Balance, BlockNumber> for Runtime {
+		fn tracks(
+			pallet_index: u8,
+		) -> Option<Vec<pallet_referenda::Track<u16, Balance, BlockNumber>>> {
+			ReferendaPallets::tracks(pallet_index)
+		}
+
+		fn track_thresholds(
+			pallet_index: u8,
+			track: u16,
+			elapsed: Perbill,
+		) -> Option<referenda_tracks_api::Thresholds> {
+			ReferendaPallets::track_thresholds(pallet_index, track, elapsed)
+		}
+
+		fn referendum_thresholds(
+			pallet_index: u8,
+			index: pallet_referenda::ReferendumIndex,
+			step: BlockNumber,
+		) -> Option<Vec<referenda_tracks_api::ThresholdsAt<BlockNumber>>> {
+			ReferendaPallets::referendum_thresholds(pallet_index, index, step)
+		}
+	}
+
+	impl pallet_treasury_spend_tracker::runtime_api::TreasurySpendsApi<Block, QueryId> for Runtime {
+		fn spends() -> Vec<(
+			pallet_treasury::SpendIndex,
+			pallet_treasury_spend_tracker::SpendState<QueryId>,
+		)> {
+			TreasurySpendTracker::spends()
+		}
 	}
 
 	impl sp_api::Core<Block> for Runtime {
@@ -2925,6 +3191,71 @@ mod test {
 		// Ensure that the name doesn't include `staging` (from the pallet name)
 		assert_eq!(vec!["xcm", "VersionedXcm"], path.segments);
 	}
+
+	#[test]
+	fn validator_operator_proxy_only_allows_validator_calls() {
+		let operator = TransparentProxyType(ProxyType::ValidatorOperator);
+		let keys = SessionKeys::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes())
+			.expect("session keys decode from zeroes");
+
+		let allowed = [
+			RuntimeCall::Staking(pallet_staking::Call::validate { prefs: Default::default() }),
+			RuntimeCall::Staking(pallet_staking::Call::chill {}),
+			RuntimeCall::Session(pallet_session::Call::set_keys { keys, proof: vec![] }),
+			RuntimeCall::Session(pallet_session::Call::purge_keys {}),
+			RuntimeCall::Utility(pallet_utility::Call::batch_all { calls: vec![] }),
+		];
+		for call in &allowed {
+			assert!(operator.filter(call), "{call:?} should be allowed");
+			assert!(TransparentProxyType(ProxyType::Staking).filter(call));
+		}
+
+		let denied = [
+			RuntimeCall::Staking(pallet_staking::Call::bond_extra { max_additional: 1 }),
+			RuntimeCall::Staking(pallet_staking::Call::unbond { value: 1 }),
+			RuntimeCall::Staking(pallet_staking::Call::nominate { targets: vec![] }),
+			RuntimeCall::Staking(pallet_staking::Call::set_payee {
+				payee: pallet_staking::RewardDestination::Stash,
+			}),
+			RuntimeCall::Staking(pallet_staking::Call::withdraw_unbonded { num_slashing_spans: 0 }),
+			RuntimeCall::Balances(pallet_balances::Call::transfer_all {
+				dest: AccountId::from([0; 32]).into(),
+				keep_alive: false,
+			}),
+			RuntimeCall::Proxy(pallet_proxy::Call::remove_proxies {}),
+		];
+		for call in &denied {
+			assert!(!operator.filter(call), "{call:?} should be denied");
+		}
+
+		for proxy_type in [ProxyType::Any, ProxyType::NonTransfer, ProxyType::Staking] {
+			assert!(TransparentProxyType(proxy_type).is_superset(&operator));
+		}
+		assert!(!operator.is_superset(&TransparentProxyType(ProxyType::Staking)));
+		assert!(!TransparentProxyType(ProxyType::NominationPools).is_superset(&operator));
+	}
+
+	#[test]
+	fn referenda_tracks_api_works() {
+		use pallet_referenda::TracksInfo as _;
+		use referenda_tracks_api::testing::check_referendum_thresholds;
+
+		let referenda = <Referenda as frame_support::traits::PalletInfoAccess>::index() as u8;
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			let tracks = ReferendaPallets::tracks(referenda).unwrap();
+			assert_eq!(tracks.len(), governance::TracksInfo::tracks().count());
+			assert!(ReferendaPallets::tracks(0).is_none());
+			assert!(ReferendaPallets::track_thresholds(referenda, 99, Perbill::zero()).is_none());
+			assert!(ReferendaPallets::referendum_thresholds(referenda, 0, 1).is_none());
+
+			System::set_block_number(1_000_000);
+			let root: OriginCaller = frame_system::RawOrigin::Root.into();
+			for track in tracks.iter().map(|track| track.id) {
+				check_referendum_thresholds::<Runtime, (), ReferendaPallets>(track, root.clone());
+			}
+		});
+	}
 }
 
 #[cfg(test)]
@@ -2969,9 +3300,20 @@ mod multiplier_tests {
 	use approx::assert_relative_eq;
 	const MILLISECONDS_PER_DAY: u64 = 24 * 60 * 60 * 1000;
 
+	/// Calls [`super::EraPayout`] with the default dynamic parameters.
+	fn era_payout(
+		total_staked: Balance,
+		total_issuance: Balance,
+		era_duration_millis: u64,
+	) -> (Balance, Balance) {
+		sp_io::TestExternalities::default().execute_with(|| {
+			super::EraPayout::era_payout(total_staked, total_issuance, era_duration_millis)
+		})
+	}
+
 	#[test]
 	fn staking_inflation_correct_single_era() {
-		let (to_stakers, to_treasury) = super::EraPayout::era_payout(
+		let (to_stakers, to_treasury) = era_payout(
 			123, // ignored
 			456, // ignored
 			MILLISECONDS_PER_DAY,
@@ -2991,7 +3333,7 @@ mod multiplier_tests {
 	#[test]
 	fn staking_inflation_correct_longer_era() {
 		// Twice the era duration means twice the emission:
-		let (to_stakers, to_treasury) = super::EraPayout::era_payout(
+		let (to_stakers, to_treasury) = era_payout(
 			123, // ignored
 			456, // ignored
 			2 * MILLISECONDS_PER_DAY,
@@ -3011,7 +3353,7 @@ mod multiplier_tests {
 
 	#[test]
 	fn staking_inflation_correct_whole_year() {
-		let (to_stakers, to_treasury) = super::EraPayout::era_payout(
+		let (to_stakers, to_treasury) = era_payout(
 			123,                                  // ignored
 			456,                                  // ignored
 			(36525 * MILLISECONDS_PER_DAY) / 100, // 1 year
@@ -3036,7 +3378,7 @@ mod multiplier_tests {
 	// 10 years into the future, our values do not overflow.
 	#[test]
 	fn staking_inflation_correct_not_overflow() {
-		let (to_stakers, to_treasury) = super::EraPayout::era_payout(
+		let (to_stakers, to_treasury) = era_payout(
 			123,                                 // ignored
 			456,                                 // ignored
 			(36525 * MILLISECONDS_PER_DAY) / 10, // 10 years
@@ -3055,7 +3397,7 @@ mod multiplier_tests {
 	// Print percent per year, just as convenience.
 	#[test]
 	fn staking_inflation_correct_print_percent() {
-		let (to_stakers, to_treasury) = super::EraPayout::era_payout(
+		let (to_stakers, to_treasury) = era_payout(
 			123,                                  // ignored
 			456,                                  // ignored
 			(36525 * MILLISECONDS_PER_DAY) / 100, // 1 year
@@ -3073,6 +3415,170 @@ mod multiplier_tests {
 		}
 	}
 
+	#[test]
+	fn parameters_admin_can_change_inflation() {
+		use dynamic_params::inflation;
+		use pallet_custom_origins::Origin as CustomOrigin;
+		use pallet_referenda::TracksInfo as _;
+
+		assert_eq!(
+			governance::TracksInfo::track_for(&OriginCaller::Origins(
+				CustomOrigin::ParametersAdmin
+			)),
+			Ok(16)
+		);
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			let set_rate = |origin: CustomOrigin| {
+				pallet_parameters::Pallet::<Runtime>::set_parameter(
+					origin.into(),
+					RuntimeParameters::Inflation(inflation::Parameters::AnnualInflationRate(
+						inflation::AnnualInflationRate,
+						Some(Perquintill::from_percent(4)),
+					)),
+				)
+			};
+
+			assert!(set_rate(CustomOrigin::GeneralAdmin).is_err());
+			assert!(set_rate(CustomOrigin::ParametersAdmin).is_ok());
+			assert_eq!(inflation::AnnualInflationRate::get(), Perquintill::from_percent(4));
+		});
+	}
+
+	#[test]
+	fn signed_origin_cannot_change_inflation() {
+		use dynamic_params::inflation;
+		use pallet_custom_origins::Origin as CustomOrigin;
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			let set_rate = |origin: RuntimeOrigin, rate| {
+				pallet_parameters::Pallet::<Runtime>::set_parameter(
+					origin,
+					RuntimeParameters::Inflation(inflation::Parameters::AnnualInflationRate(
+						inflation::AnnualInflationRate,
+						Some(Perquintill::from_percent(rate)),
+					)),
+				)
+			};
+			let initial_rate = inflation::AnnualInflationRate::get();
+
+			// Only root and `ParametersAdmin` can change the inflation parameters.
+			assert!(set_rate(RuntimeOrigin::signed(AccountId::from([1; 32])), 4).is_err());
+			assert_eq!(inflation::AnnualInflationRate::get(), initial_rate);
+
+			assert!(set_rate(CustomOrigin::ParametersAdmin.into(), 4).is_ok());
+			assert_eq!(inflation::AnnualInflationRate::get(), Perquintill::from_percent(4));
+			assert!(set_rate(RuntimeOrigin::root(), 5).is_ok());
+			assert_eq!(inflation::AnnualInflationRate::get(), Perquintill::from_percent(5));
+		});
+	}
+
+	#[test]
+	fn staking_inflation_follows_dynamic_params() {
+		use dynamic_params::inflation;
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			let (to_stakers, to_treasury) =
+				super::EraPayout::era_payout(123, 456, MILLISECONDS_PER_DAY);
+
+			let set_parameter = |parameter| {
+				pallet_parameters::Pallet::<Runtime>::set_parameter(
+					RuntimeOrigin::root(),
+					RuntimeParameters::Inflation(parameter),
+				)
+			};
+
+			// Halving the rate halves the emission.
+			set_parameter(inflation::Parameters::AnnualInflationRate(
+				inflation::AnnualInflationRate,
+				Some(Perquintill::from_percent(4)),
+			))
+			.unwrap();
+			let (halved_to_stakers, halved_to_treasury) =
+				super::EraPayout::era_payout(123, 456, MILLISECONDS_PER_DAY);
+			assert_relative_eq!(
+				halved_to_stakers as f64 * 2.0,
+				to_stakers as f64,
+				max_relative = 0.001
+			);
+			assert_relative_eq!(
+				halved_to_treasury as f64 * 2.0,
+				to_treasury as f64,
+				max_relative = 0.001
+			);
+
+			// Doubling the baseline issuance restores it.
+			set_parameter(inflation::Parameters::FixedTotalIssuance(
+				inflation::FixedTotalIssuance,
+				Some(2 * 15_011_657_390_566_252_333),
+			))
+			.unwrap();
+			let (restored_to_stakers, restored_to_treasury) =
+				super::EraPayout::era_payout(123, 456, MILLISECONDS_PER_DAY);
+			assert_relative_eq!(
+				restored_to_stakers as f64,
+				to_stakers as f64,
+				max_relative = 0.001
+			);
+			assert_relative_eq!(
+				restored_to_treasury as f64,
+				to_treasury as f64,
+				max_relative = 0.001
+			);
+
+			// Everything goes to the treasury.
+			set_parameter(inflation::Parameters::TreasuryPortion(
+				inflation::TreasuryPortion,
+				Some(Perquintill::one()),
+			))
+			.unwrap();
+			let (all_to_stakers, all_to_treasury) =
+				super::EraPayout::era_payout(123, 456, MILLISECONDS_PER_DAY);
+			assert_eq!(all_to_stakers, 0);
+			assert_eq!(all_to_treasury, restored_to_stakers + restored_to_treasury);
+		});
+	}
+
+	#[test]
+	fn staking_rewards_info_reports_era_mints() {
+		use frame_support::traits::{fungible::Balanced, OnUnbalanced};
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			let history_depth = <Runtime as pallet_staking::Config>::HistoryDepth::get();
+			for era in 0..history_depth + 5 {
+				pallet_staking::ActiveEra::<Runtime>::put(pallet_staking::ActiveEraInfo {
+					index: era,
+					start: None,
+				});
+				pallet_staking::ErasValidatorReward::<Runtime>::insert(era, 100 * era as Balance);
+				pallet_era_treasury_mints::RecordEraMint::<Runtime, ()>::on_unbalanced(
+					Balances::issue(era as Balance),
+				);
+			}
+
+			// Only the last `HistoryDepth` eras are kept.
+			assert_eq!(EraTreasuryMints::mint_of(4), None);
+			assert_eq!(EraTreasuryMints::mint_of(5), Some(5));
+
+			let active_era = history_depth + 4;
+			let info = Runtime::impl_experimental_staking_rewards_info(3, UNITS);
+			assert_eq!(
+				info.era_mints,
+				(active_era - 3..active_era)
+					.map(|era| EraMint {
+						era,
+						to_stakers: 100 * era as Balance,
+						to_treasury: era as Balance,
+					})
+					.collect::<Vec<_>>()
+			);
+			assert_eq!(info.ideal_stake, None);
+			// Without any validators there is nothing to estimate.
+			assert_eq!(info.validator_apy, FixedU128::from_u32(0));
+			assert_eq!(info.nominator_apy, FixedU128::from_u32(0));
+		});
+	}
+
 	#[test]
 	fn fast_unstake_estimate() {
 		use pallet_fast_unstake::WeightInfo;
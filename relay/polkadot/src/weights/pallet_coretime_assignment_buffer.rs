@@ -0,0 +1,52 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_coretime_assignment_buffer`
+//!
+//! NOTE: These weights were NOT produced by the benchmark CLI. Until the pallet is benchmarked
+//! with the Polkadot runtime, they are the benchmarked weights of
+//! `runtime_parachains::coretime::assign_core`, which `assign_core_part` forwards to, plus the
+//! storage accesses of the buffer at their maximum encoded size.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_coretime_assignment_buffer`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_coretime_assignment_buffer::WeightInfo for WeightInfo<T> {
+	/// Storage: `CoretimeAssignmentBuffer::PendingAssignments` (r:1 w:1)
+	/// Proof: `CoretimeAssignmentBuffer::PendingAssignments` (`max_values`: None, `max_size`: Some(715), added: 3190, mode: `MaxEncodedLen`)
+	/// Storage: `CoretimeAssignmentBuffer::ExpiryQueue` (r:1 w:1)
+	/// Proof: `CoretimeAssignmentBuffer::ExpiryQueue` (`max_values`: Some(1), `max_size`: Some(6002), added: 6497, mode: `MaxEncodedLen`)
+	/// Storage: `CoretimeAssignmentProvider::CoreDescriptors` (r:1 w:1)
+	/// Proof: `CoretimeAssignmentProvider::CoreDescriptors` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CoretimeAssignmentProvider::CoreSchedules` (r:0 w:1)
+	/// Proof: `CoretimeAssignmentProvider::CoreSchedules` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 100]`.
+	fn assign_core_part(s: u32, ) -> Weight {
+		// `assign_core` (3579) plus `PendingAssignments` (3190) and `ExpiryQueue` (6497).
+		Weight::from_parts(16_875_340, 0)
+			.saturating_add(Weight::from_parts(0, 13_266))
+			.saturating_add(Weight::from_parts(11_711, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+}
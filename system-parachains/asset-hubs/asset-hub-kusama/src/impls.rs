@@ -42,14 +42,18 @@ pub mod tx_payment {
 	/// [`pallet_asset_conversion_tx_payment`] with the [`SwapCreditAdapter`] type. For both types,
 	/// the credit types they handle must be the same, therefore they must be credits of
 	/// `fungibles`.
-	pub struct FungiblesAdapter<F, A, OU>(PhantomData<(F, A, OU)>);
+	///
+	/// The tip is separated from the fee and handed to `OT`, the rest of the fee to `OU`. By
+	/// default both go to `OU`.
+	pub struct FungiblesAdapter<F, A, OU, OT = OU>(PhantomData<(F, A, OU, OT)>);
 
-	impl<T, F, A, OU> OnChargeTransaction<T> for FungiblesAdapter<F, A, OU>
+	impl<T, F, A, OU, OT> OnChargeTransaction<T> for FungiblesAdapter<F, A, OU, OT>
 	where
 		T: pallet_transaction_payment::Config,
 		F: fungibles::Balanced<T::AccountId>,
 		A: Get<F::AssetId>,
 		OU: OnUnbalanced<fungibles::Credit<T::AccountId, F>>,
+		OT: OnUnbalanced<fungibles::Credit<T::AccountId, F>>,
 	{
 		type LiquidityInfo = Option<fungibles::Credit<T::AccountId, F>>;
 		type Balance = F::Balance;
@@ -83,7 +87,7 @@ pub mod tx_payment {
 			_dispatch_info: &DispatchInfoOf<<T>::RuntimeCall>,
 			_post_info: &PostDispatchInfoOf<<T>::RuntimeCall>,
 			corrected_fee: Self::Balance,
-			_tip: Self::Balance,
+			tip: Self::Balance,
 			already_withdrawn: Self::LiquidityInfo,
 		) -> Result<(), TransactionValidityError> {
 			let Some(paid) = already_withdrawn else {
@@ -113,11 +117,11 @@ pub mod tx_payment {
 					// refund amount is not greater than paid amount.
 					_ => return Err(InvalidTransaction::Payment.into()),
 				};
-			// No separation for simplicity.
-			// In our case the fees and the tips are deposited to the same pot.
 			// We cannot call [`OnUnbalanced::on_unbalanceds`] since fungibles credit does not
-			// implement `Imbalanced` trait.
-			OU::on_unbalanced(adjusted_paid);
+			// implement `Imbalanced` trait, so the tip is split off and handled on its own.
+			let (tip, fee) = adjusted_paid.split(tip);
+			OU::on_unbalanced(fee);
+			OT::on_unbalanced(tip);
 			Ok(())
 		}
 
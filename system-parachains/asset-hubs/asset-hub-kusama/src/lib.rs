@@ -41,7 +41,9 @@ use assets_common::{
 };
 use cumulus_pallet_parachain_system::{RelayNumberMonotonicallyIncreases, RelaychainDataProvider};
 use cumulus_primitives_core::{AggregateMessageOrigin, ParaId};
-use kusama_runtime_constants::time::MINUTES as RC_MINUTES;
+use kusama_runtime_constants::time::{
+	MILLISECS_PER_BLOCK as RC_MILLISECS_PER_BLOCK, MINUTES as RC_MINUTES,
+};
 use pallet_proxy::ProxyDefinition;
 use pallet_revive::{evm::runtime::EthExtra, AddressMapper};
 use sp_api::impl_runtime_apis;
@@ -53,7 +55,7 @@ use sp_runtime::{
 		Verify,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, Perbill, Permill,
+	ApplyExtrinsicResult, Perbill, Percent, Permill,
 };
 
 #[cfg(feature = "std")]
@@ -64,6 +66,7 @@ use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use frame_support::{
 	construct_runtime,
 	dispatch::{DispatchClass, DispatchInfo},
+	dynamic_params::{dynamic_pallet_params, dynamic_params},
 	genesis_builder_helper::{build_state, get_preset},
 	ord_parameter_types, parameter_types,
 	traits::{
@@ -277,12 +280,68 @@ parameter_types! {
 	pub const TransactionByteFee: Balance = system_parachains_constants::kusama::fee::TRANSACTION_BYTE_FEE;
 }
 
+/// Dynamic params that can be adjusted at runtime.
+#[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
+pub mod dynamic_params {
+	use super::*;
+
+	/// Parameters used to handle the transaction fees, see [`DealWithFees`].
+	#[dynamic_pallet_params]
+	#[codec(index = 0)]
+	pub mod fees {
+		/// The share of the transaction fees, without the tips, that goes to the Relay Chain
+		/// treasury instead of the collators.
+		#[codec(index = 0)]
+		pub static TreasuryShare: Percent = Percent::zero();
+
+		/// Whether the treasury share of the transaction fees is burned instead.
+		#[codec(index = 1)]
+		pub static BurnTreasuryShare: bool = false;
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl Default for RuntimeParameters {
+	fn default() -> Self {
+		RuntimeParameters::Fees(dynamic_params::fees::Parameters::TreasuryShare(
+			dynamic_params::fees::TreasuryShare,
+			Some(Percent::zero()),
+		))
+	}
+}
+
+impl pallet_parameters::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeParameters = RuntimeParameters;
+	type AdminOrigin = AsEnsureOriginWithArg<EnsureRoot<AccountId>>;
+	type WeightInfo = weights::pallet_parameters::WeightInfo<Runtime>;
+}
+
+/// Splits the transaction fees between the collators and the Relay Chain treasury (or burns the
+/// treasury share), according to the [`dynamic_params::fees`].
+pub type DealWithFees = asset_hub_common::tx_payment::SplitCredit<
+	dynamic_params::fees::TreasuryShare,
+	asset_hub_common::tx_payment::BurnIf<
+		dynamic_params::fees::BurnTreasuryShare,
+		ResolveAssetTo<xcm_config::RelayTreasuryPalletAccount, NativeAndAssets>,
+	>,
+	ResolveAssetTo<StakingPot, NativeAndAssets>,
+>;
+
+/// Gives the tips to the block author, or to the collators if the author is unknown.
+pub type DealWithTips = asset_hub_common::tx_payment::ResolveAssetToAuthor<
+	Runtime,
+	NativeAndAssets,
+	ResolveAssetTo<StakingPot, NativeAndAssets>,
+>;
+
 impl pallet_transaction_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type OnChargeTransaction = impls::tx_payment::FungiblesAdapter<
 		NativeAndAssets,
 		KsmLocation,
-		ResolveAssetTo<StakingPot, NativeAndAssets>,
+		DealWithFees,
+		DealWithTips,
 	>;
 	type WeightToFee = WeightToFee;
 	type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
@@ -322,8 +381,8 @@ impl pallet_assets::Config<TrustBackedAssetsInstance> for Runtime {
 	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type ApprovalDeposit = ExistentialDeposit;
 	type StringLimit = AssetsStringLimit;
-	type Freezer = ();
-	type Holder = ();
+	type Freezer = AssetsFreezer;
+	type Holder = AssetsHolder;
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_local::WeightInfo<Runtime>;
 	type CallbackHandle = pallet_assets::AutoIncAssetId<Runtime, TrustBackedAssetsInstance>;
@@ -333,6 +392,16 @@ impl pallet_assets::Config<TrustBackedAssetsInstance> for Runtime {
 	type BenchmarkHelper = ();
 }
 
+impl pallet_assets_freezer::Config<TrustBackedAssetsInstance> for Runtime {
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeEvent = RuntimeEvent;
+}
+
+impl pallet_assets_holder::Config<TrustBackedAssetsInstance> for Runtime {
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeEvent = RuntimeEvent;
+}
+
 parameter_types! {
 	pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
 	pub const LiquidityWithdrawalFee: Permill = Permill::from_percent(0);
@@ -468,8 +537,8 @@ impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
 	type MetadataDepositPerByte = ForeignAssetsMetadataDepositPerByte;
 	type ApprovalDeposit = ExistentialDeposit;
 	type StringLimit = ForeignAssetsAssetsStringLimit;
-	type Freezer = ();
-	type Holder = ();
+	type Freezer = ForeignAssetsFreezer;
+	type Holder = ForeignAssetsHolder;
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_foreign::WeightInfo<Runtime>;
 	type CallbackHandle = ();
@@ -479,6 +548,16 @@ impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
 	type BenchmarkHelper = xcm_config::XcmBenchmarkHelper;
 }
 
+impl pallet_assets_freezer::Config<ForeignAssetsInstance> for Runtime {
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeEvent = RuntimeEvent;
+}
+
+impl pallet_assets_holder::Config<ForeignAssetsInstance> for Runtime {
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeEvent = RuntimeEvent;
+}
+
 parameter_types! {
 	// One storage item; key size is 32; value is size 4+4+16+32 bytes = 56 bytes.
 	pub const DepositBase: Balance = system_para_deposit(1, 88);
@@ -693,7 +772,7 @@ parameter_types! {
 
 impl cumulus_pallet_parachain_system::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnSystemEvent = RemoteProxyRelayChain;
+	type OnSystemEvent = RelayStateOracle;
 	type SelfParaId = parachain_info::Pallet<Runtime>;
 	type DmpQueue = frame_support::traits::EnqueueWithOrigin<MessageQueue, RelayOrigin>;
 	type ReservedDmpWeight = ReservedDmpWeight;
@@ -852,11 +931,12 @@ impl pallet_collator_selection::Config for Runtime {
 impl pallet_asset_conversion_tx_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type AssetId = Location;
-	type OnChargeAssetTransaction = pallet_asset_conversion_tx_payment::SwapAssetAdapter<
+	type OnChargeAssetTransaction = asset_hub_common::tx_payment::SwapAssetAdapter<
 		KsmLocation,
 		NativeAndAssets,
 		AssetConversion,
-		ResolveAssetTo<StakingPot, NativeAndAssets>,
+		DealWithFees,
+		DealWithTips,
 	>;
 	type WeightInfo = weights::pallet_asset_conversion_tx_payment::WeightInfo<Self>;
 
@@ -1006,25 +1086,40 @@ impl
 			kusama_runtime_constants::proxy::ProxyType::Spokesperson |
 			kusama_runtime_constants::proxy::ProxyType::NominationPools |
 			kusama_runtime_constants::proxy::ProxyType::Society |
-			kusama_runtime_constants::proxy::ProxyType::ParaRegistration => return None,
+			kusama_runtime_constants::proxy::ProxyType::ParaRegistration |
+			kusama_runtime_constants::proxy::ProxyType::ValidatorOperator => return None,
 		};
 
 		Some(ProxyDefinition {
 			delegate: a.delegate,
 			proxy_type,
-			// Delays are currently not supported by the remote proxy pallet, but should be
-			// converted in the future to the block time used by the local proxy pallet.
+			// The delay is converted separately by `RelayChainToLocalDelayConverter`.
 			delay: a.delay,
 		})
 	}
 }
 
-impl pallet_remote_proxy::Config for Runtime {
+/// Converts the delay of a relay chain proxy from relay chain blocks into local blocks.
+pub type RelayChainToLocalDelayConverter = pallet_remote_proxy::BlockTimeDelayConverter<
+	ConstU64<RC_MILLISECS_PER_BLOCK>,
+	ConstU64<{ system_parachains_constants::async_backing::MILLISECS_PER_BLOCK }>,
+>;
+
+impl pallet_relay_state_oracle::Config for Runtime {
+	type RelayBlockNumber = BlockNumber;
+	type RelayHash = Hash;
+	type RelayHasher = BlakeTwo256;
 	// The time between creating a proof and using the proof in a transaction.
 	type MaxStorageRootsToKeep = ConstU32<{ RC_MINUTES }>;
+}
+
+impl pallet_remote_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RelayStateOracle = RelayStateOracle;
 	type RemoteProxy = kusama_runtime_constants::proxy::RemoteProxyInterface<
 		ProxyType,
 		RelayChainToLocalProxyTypeConverter,
+		RelayChainToLocalDelayConverter,
 	>;
 	type WeightInfo = weights::pallet_remote_proxy::WeightInfo<Runtime>;
 }
@@ -1077,6 +1172,7 @@ construct_runtime!(
 		TransactionPayment: pallet_transaction_payment = 11,
 		AssetTxPayment: pallet_asset_conversion_tx_payment = 13,
 		Vesting: pallet_vesting = 14,
+		Parameters: pallet_parameters = 15,
 
 		// Collator support. the order of these 5 are important and shall not change.
 		Authorship: pallet_authorship = 20,
@@ -1098,6 +1194,7 @@ construct_runtime!(
 		Multisig: pallet_multisig = 41,
 		Proxy: pallet_proxy = 42,
 		RemoteProxyRelayChain: pallet_remote_proxy = 43,
+		RelayStateOracle: pallet_relay_state_oracle = 44,
 
 		// The main stage.
 		Assets: pallet_assets::<Instance1> = 50,
@@ -1111,6 +1208,12 @@ construct_runtime!(
 
 		Revive: pallet_revive = 60,
 
+		// Freezes and holds of the trust-backed and foreign assets.
+		AssetsFreezer: pallet_assets_freezer::<Instance1> = 64,
+		ForeignAssetsFreezer: pallet_assets_freezer::<Instance2> = 65,
+		AssetsHolder: pallet_assets_holder::<Instance1> = 66,
+		ForeignAssetsHolder: pallet_assets_holder::<Instance2> = 67,
+
 		// State trie migration pallet, only temporary.
 		StateTrieMigration: pallet_state_trie_migration = 70,
 	}
@@ -1176,8 +1279,19 @@ impl TryFrom<RuntimeCall> for pallet_revive::Call<Runtime> {
 pub type UncheckedExtrinsic =
 	pallet_revive::evm::runtime::UncheckedExtrinsic<Address, Signature, EthExtraImpl>;
 
+parameter_types! {
+	pub const RemoteProxyRelayChainPalletName: &'static str = "RemoteProxyRelayChain";
+	pub const BlockToRootStorageName: &'static str = "BlockToRoot";
+}
+
 /// Migrations to apply on runtime upgrade.
 pub type Migrations = (
+	// The storage roots moved from the remote proxy pallet to the relay state oracle.
+	frame_support::migrations::RemoveStorage<
+		RemoteProxyRelayChainPalletName,
+		BlockToRootStorageName,
+		RocksDbWeight,
+	>,
 	pallet_session::migrations::v1::MigrateV0ToV1<
 		Runtime,
 		pallet_session::migrations::v1::InitOffenceSeverity<Runtime>,
@@ -1285,6 +1399,7 @@ mod benches {
 		[pallet_multisig, Multisig]
 		[pallet_nft_fractionalization, NftFractionalization]
 		[pallet_nfts, Nfts]
+		[pallet_parameters, Parameters]
 		[pallet_proxy, Proxy]
 		[pallet_remote_proxy, RemoteProxyRelayChain]
 		[pallet_revive, Revive]
@@ -1776,6 +1891,28 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_remote_proxy::runtime_api::RemoteProxyApi<Block, AccountId, ProxyType, BlockNumber, BlockNumber, Hash> for Runtime {
+		fn known_storage_roots() -> Vec<(BlockNumber, Hash)> {
+			RemoteProxyRelayChain::known_storage_roots()
+		}
+
+		fn recommended_anchor_block() -> Option<BlockNumber> {
+			RemoteProxyRelayChain::recommended_anchor_block()
+		}
+
+		fn verify_remote_proxy_proof(
+			real: AccountId,
+			delegate: AccountId,
+			proxy_type: Option<ProxyType>,
+			proof: pallet_remote_proxy::RemoteProxyProof<BlockNumber>,
+		) -> Result<
+			pallet_remote_proxy::ProxyDefinition<AccountId, ProxyType, BlockNumber>,
+			sp_runtime::DispatchError,
+		> {
+			RemoteProxyRelayChain::verify_remote_proxy_proof(real, delegate, proxy_type, proof)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,
@@ -1934,6 +2071,49 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl asset_hub_common::apis::FungiblesBalancesApi<Block, AccountId, Balance> for Runtime {
+		fn query_account_balance_breakdowns(
+			account: AccountId,
+		) -> Vec<(xcm::VersionedLocation, asset_hub_common::apis::BalanceBreakdown<Balance>)> {
+			use asset_hub_common::apis::{assets_balance_breakdown, BalanceBreakdown};
+			use sp_runtime::traits::MaybeEquivalence;
+
+			// collect pallet_balances, holds are reserved in it
+			let native = {
+				let data = frame_system::Account::<Runtime>::get(&account).data;
+				BalanceBreakdown { free: data.free, frozen: data.frozen, held: data.reserved }
+			};
+			// collect pallet_assets (TrustBackedAssets)
+			let trust_backed = Assets::account_balances(account.clone())
+				.into_iter()
+				.filter_map(|(id, _)| {
+					let location = AssetIdForTrustBackedAssetsConvert::<
+						TrustBackedAssetsPalletLocation,
+						Location,
+					>::convert_back(&id)?;
+					let breakdown =
+						assets_balance_breakdown::<Runtime, TrustBackedAssetsInstance>(id, &account);
+					Some((location, breakdown))
+				});
+			// collect pallet_assets (ForeignAssets)
+			let foreign = ForeignAssets::account_balances(account.clone())
+				.into_iter()
+				.map(|(id, _)| {
+					let breakdown =
+						assets_balance_breakdown::<Runtime, ForeignAssetsInstance>(id.clone(), &account);
+					(id, breakdown)
+				});
+
+			[(KsmLocation::get(), native)]
+				.into_iter()
+				.chain(trust_backed)
+				.chain(foreign)
+				.filter(|(_, breakdown)| !breakdown.is_zero())
+				.map(|(location, breakdown)| (location.into(), breakdown))
+				.collect()
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
@@ -31,6 +31,7 @@ pub mod pallet_message_queue;
 pub mod pallet_multisig;
 pub mod pallet_nft_fractionalization;
 pub mod pallet_nfts;
+pub mod pallet_parameters;
 pub mod pallet_proxy;
 pub mod pallet_remote_proxy;
 pub mod pallet_revive;
@@ -33,6 +33,9 @@
 // --all
 // --quiet
 
+// NOTE: These weights were generated without the assets freezer and holder and are used until
+// the pallet is benchmarked again with the Asset Hub Kusama runtime.
+
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
 #![allow(unused_imports)]
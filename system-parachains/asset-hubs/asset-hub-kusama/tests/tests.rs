@@ -256,6 +256,114 @@ fn test_assets_balances_api_works() {
 		});
 }
 
+#[test]
+fn fungibles_balances_api_reports_held_balances() {
+	use asset_hub_common::apis::{
+		runtime_decl_for_fungibles_balances_api::FungiblesBalancesApi, BalanceBreakdown,
+	};
+	use asset_hub_kusama_runtime::{AssetsHolder, RuntimeHoldReason};
+	use frame_support::traits::fungibles::MutateHold;
+
+	ExtBuilder::<Runtime>::default().build().execute_with(|| {
+		let alice = AccountId::from(ALICE);
+		let local_asset_id = 1;
+		let minimum_asset_balance = 3333333_u128;
+		let reason = RuntimeHoldReason::NftFractionalization(
+			pallet_nft_fractionalization::HoldReason::Fractionalized,
+		);
+
+		assert_ok!(Assets::force_create(
+			RuntimeHelper::root_origin(),
+			local_asset_id.into(),
+			alice.clone().into(),
+			true,
+			minimum_asset_balance
+		));
+		assert_ok!(Assets::mint(
+			RuntimeHelper::origin_of(alice.clone()),
+			local_asset_id.into(),
+			alice.clone().into(),
+			3 * minimum_asset_balance
+		));
+		assert_ok!(AssetsHolder::hold(local_asset_id, &reason, &alice, minimum_asset_balance));
+
+		// The account has no native balance, so only the asset is reported.
+		let location = Location::new(0, [PalletInstance(50), GeneralIndex(local_asset_id.into())]);
+		assert_eq!(
+			Runtime::query_account_balance_breakdowns(alice),
+			vec![(
+				location.into(),
+				BalanceBreakdown {
+					free: 2 * minimum_asset_balance,
+					frozen: 0,
+					held: minimum_asset_balance
+				}
+			)]
+		);
+	});
+}
+
+#[test]
+fn fungibles_balances_api_reports_frozen_balances() {
+	use asset_hub_common::apis::{
+		runtime_decl_for_fungibles_balances_api::FungiblesBalancesApi, BalanceBreakdown,
+	};
+	use asset_hub_kusama_runtime::TrustBackedAssetsInstance;
+	use frame_support::assert_noop;
+
+	ExtBuilder::<Runtime>::default().build().execute_with(|| {
+		let alice = AccountId::from(ALICE);
+		let bob = AccountId::from([2u8; 32]);
+		let local_asset_id = 1;
+		let minimum_asset_balance = 3333333_u128;
+
+		assert_ok!(Assets::force_create(
+			RuntimeHelper::root_origin(),
+			local_asset_id.into(),
+			alice.clone().into(),
+			true,
+			minimum_asset_balance
+		));
+		assert_ok!(Assets::mint(
+			RuntimeHelper::origin_of(alice.clone()),
+			local_asset_id.into(),
+			alice.clone().into(),
+			3 * minimum_asset_balance
+		));
+		// No pallet of the runtime declares a freeze reason yet, so the freeze is written to the
+		// freezer's storage directly.
+		pallet_assets_freezer::FrozenBalances::<Runtime, TrustBackedAssetsInstance>::insert(
+			local_asset_id,
+			&alice,
+			2 * minimum_asset_balance,
+		);
+
+		// The frozen balance cannot be transferred.
+		assert_noop!(
+			Assets::transfer(
+				RuntimeHelper::origin_of(alice.clone()),
+				local_asset_id.into(),
+				bob.clone().into(),
+				2 * minimum_asset_balance
+			),
+			pallet_assets::Error::<Runtime, TrustBackedAssetsInstance>::BalanceLow
+		);
+
+		let location = Location::new(0, [PalletInstance(50), GeneralIndex(local_asset_id.into())]);
+		assert_eq!(
+			Runtime::query_account_balance_breakdowns(alice),
+			vec![(
+				location.into(),
+				BalanceBreakdown {
+					free: 3 * minimum_asset_balance,
+					frozen: 2 * minimum_asset_balance,
+					held: 0
+				}
+			)]
+		);
+	});
+}
+
 asset_test_utils::include_teleports_for_native_asset_works!(
 	Runtime,
 	AllPalletsWithoutSystem,
@@ -558,6 +666,60 @@ fn test_report_bridge_status_call_compatibility() {
 	)
 }
 
+#[test]
+fn test_proxy_type_compatibility() {
+	use asset_hub_kusama_runtime::ProxyType;
+	use bp_asset_hub_kusama::proxy::ProxyType as BridgeProxyType;
+
+	// if this test fails, make sure `bp_asset_hub_kusama::proxy::ProxyType` has valid encoding
+	for (local, bridge) in [
+		(ProxyType::Any, BridgeProxyType::Any),
+		(ProxyType::NonTransfer, BridgeProxyType::NonTransfer),
+		(ProxyType::CancelProxy, BridgeProxyType::CancelProxy),
+		(ProxyType::Assets, BridgeProxyType::Assets),
+		(ProxyType::AssetOwner, BridgeProxyType::AssetOwner),
+		(ProxyType::AssetManager, BridgeProxyType::AssetManager),
+		(ProxyType::Collator, BridgeProxyType::Collator),
+	] {
+		assert_eq!(local.encode(), bridge.encode());
+	}
+}
+
+#[test]
+fn relay_chain_proxy_types_are_converted() {
+	use asset_hub_kusama_runtime::{ProxyType, RelayChainToLocalProxyTypeConverter};
+	use kusama_runtime_constants::proxy::ProxyType as RelayProxyType;
+	use pallet_proxy::ProxyDefinition;
+	use sp_runtime::traits::Convert;
+
+	let convert = |proxy_type| {
+		RelayChainToLocalProxyTypeConverter::convert(ProxyDefinition {
+			delegate: AccountId::from(ALICE),
+			proxy_type,
+			delay: 10,
+		})
+		.map(|definition| definition.proxy_type)
+	};
+
+	assert_eq!(convert(RelayProxyType::Any), Some(ProxyType::Any));
+	assert_eq!(convert(RelayProxyType::NonTransfer), Some(ProxyType::NonTransfer));
+	assert_eq!(convert(RelayProxyType::CancelProxy), Some(ProxyType::CancelProxy));
+
+	// Relay chain specific proxy types, including `ValidatorOperator`, have no counterpart here.
+	for proxy_type in [
+		RelayProxyType::Governance,
+		RelayProxyType::Staking,
+		RelayProxyType::Auction,
+		RelayProxyType::Society,
+		RelayProxyType::NominationPools,
+		RelayProxyType::Spokesperson,
+		RelayProxyType::ParaRegistration,
+		RelayProxyType::ValidatorOperator,
+	] {
+		assert_eq!(convert(proxy_type), None, "{proxy_type:?} should not be converted");
+	}
+}
+
 #[test]
 fn check_sane_weight_report_bridge_status() {
 	use pallet_xcm_bridge_hub_router::WeightInfo;
@@ -637,6 +799,99 @@ fn treasury_pallet_account_not_none() {
 	)
 }
 
+#[test]
+fn transaction_fees_are_split_between_collators_and_treasury() {
+	use asset_hub_kusama_runtime::{
+		dynamic_params::fees, DealWithFees, NativeAndAssets, Parameters, RuntimeParameters,
+	};
+	use frame_support::traits::{fungible::Inspect, fungibles::Balanced, OnUnbalanced};
+	use sp_runtime::Percent;
+
+	ExtBuilder::<Runtime>::default().build().execute_with(|| {
+		let fee = 100 * ExistentialDeposit::get();
+		let pay_fee =
+			|| DealWithFees::on_unbalanced(NativeAndAssets::issue(KsmLocation::get(), fee));
+		let pot = || Balances::balance(&StakingPot::get());
+		let treasury = || Balances::balance(&RelayTreasuryPalletAccount::get());
+		let (initial_pot, initial_treasury) = (pot(), treasury());
+
+		// By default all the fees go to the collators.
+		pay_fee();
+		assert_eq!(pot(), initial_pot + fee);
+		assert_eq!(treasury(), initial_treasury);
+
+		// Only governance can change the split.
+		let treasury_share = RuntimeParameters::Fees(fees::Parameters::TreasuryShare(
+			fees::TreasuryShare,
+			Some(Percent::from_percent(20)),
+		));
+		assert!(Parameters::set_parameter(
+			RuntimeOrigin::signed(AccountId::from(ALICE)),
+			treasury_share.clone()
+		)
+		.is_err());
+		assert_ok!(Parameters::set_parameter(RuntimeOrigin::root(), treasury_share));
+		pay_fee();
+		assert_eq!(pot(), initial_pot + fee + fee * 80 / 100);
+		assert_eq!(treasury(), initial_treasury + fee * 20 / 100);
+
+		// The treasury share is burned instead.
+		assert_ok!(Parameters::set_parameter(
+			RuntimeOrigin::root(),
+			RuntimeParameters::Fees(fees::Parameters::BurnTreasuryShare(
+				fees::BurnTreasuryShare,
+				Some(true),
+			)),
+		));
+		let total_issuance = Balances::total_issuance();
+		pay_fee();
+		assert_eq!(pot(), initial_pot + 2 * fee + fee * 80 / 100);
+		assert_eq!(treasury(), initial_treasury + fee * 20 / 100);
+		assert_eq!(Balances::total_issuance(), total_issuance + fee * 80 / 100);
+	});
+}
+
+#[test]
+fn transaction_tips_go_to_the_block_author() {
+	use asset_hub_kusama_runtime::{DealWithTips, NativeAndAssets};
+	use frame_support::traits::{fungible::Inspect, fungibles::Balanced, OnUnbalanced};
+	use sp_consensus_aura::{Slot, AURA_ENGINE_ID};
+	use sp_runtime::DigestItem;
+
+	let author = AccountId::from(ALICE);
+	ExtBuilder::<Runtime>::default()
+		.with_collators(vec![author.clone()])
+		.with_session_keys(vec![(
+			author.clone(),
+			author.clone(),
+			SessionKeys { aura: AuraId::from(sp_core::sr25519::Public::from_raw(ALICE)) },
+		)])
+		.build()
+		.execute_with(|| {
+			let tip = 10 * ExistentialDeposit::get();
+			let pay_tip =
+				|| DealWithTips::on_unbalanced(NativeAndAssets::issue(KsmLocation::get(), tip));
+			let pot = || Balances::balance(&StakingPot::get());
+			let (initial_author, initial_pot) = (Balances::balance(&author), pot());
+
+			// Without a block author the tips go to the collators.
+			assert_eq!(pallet_authorship::Pallet::<Runtime>::author(), None);
+			pay_tip();
+			assert_eq!(Balances::balance(&author), initial_author);
+			assert_eq!(pot(), initial_pot + tip);
+
+			// The block author is found through the Aura pre-runtime digest.
+			frame_system::Pallet::<Runtime>::deposit_log(DigestItem::PreRuntime(
+				AURA_ENGINE_ID,
+				Slot::from(0).encode(),
+			));
+			assert_eq!(pallet_authorship::Pallet::<Runtime>::author(), Some(author.clone()));
+			pay_tip();
+			assert_eq!(Balances::balance(&author), initial_author + tip);
+			assert_eq!(pot(), initial_pot + tip);
+		});
+}
+
 #[test]
 fn location_conversion_works() {
 	let alice_32 =
@@ -77,3 +77,67 @@ pub fn build_congestion_message<RuntimeCall>(
 
 /// Identifier of AssetHubPolkadot in the Polkadot relay chain.
 pub const ASSET_HUB_POLKADOT_PARACHAIN_ID: u32 = 1000;
+
+/// Proxy related types and interfaces of `AssetHubPolkadot`.
+pub mod proxy {
+	use super::ASSET_HUB_POLKADOT_PARACHAIN_ID;
+	use parachains_common::Header;
+
+	/// The type used to represent the kinds of proxying allowed on `AssetHubPolkadot`.
+	///
+	/// Must be kept in sync with the `ProxyType` of the `AssetHubPolkadot` runtime, so that we
+	/// maintain SCALE-compatibility.
+	#[derive(
+		Copy,
+		Clone,
+		Eq,
+		PartialEq,
+		Ord,
+		PartialOrd,
+		codec::Encode,
+		codec::Decode,
+		codec::DecodeWithMemTracking,
+		codec::MaxEncodedLen,
+		core::fmt::Debug,
+		scale_info::TypeInfo,
+		Default,
+	)]
+	pub enum ProxyType {
+		#[codec(index = 0)]
+		#[default]
+		Any,
+		#[codec(index = 1)]
+		NonTransfer,
+		#[codec(index = 2)]
+		CancelProxy,
+		#[codec(index = 3)]
+		Assets,
+		#[codec(index = 4)]
+		AssetOwner,
+		#[codec(index = 5)]
+		AssetManager,
+		#[codec(index = 6)]
+		Collator,
+	}
+
+	frame_support::parameter_types! {
+		/// The para id of `AssetHubPolkadot`.
+		pub const SiblingParaId: u32 = ASSET_HUB_POLKADOT_PARACHAIN_ID;
+		/// The name of the proxy pallet in the `AssetHubPolkadot` runtime.
+		pub const ProxyPalletName: &'static str = "Proxy";
+	}
+
+	/// Remote proxy interface that uses `AssetHubPolkadot` as remote location.
+	///
+	/// The `DelayConverter` converts the delay of a remote proxy into local blocks.
+	pub type RemoteProxyInterface<LocalProxyType, ProxyDefinitionConverter, DelayConverter> =
+		pallet_remote_proxy::SiblingParachainRemoteProxy<
+			SiblingParaId,
+			ProxyPalletName,
+			ProxyType,
+			Header,
+			LocalProxyType,
+			ProxyDefinitionConverter,
+			DelayConverter,
+		>;
+}
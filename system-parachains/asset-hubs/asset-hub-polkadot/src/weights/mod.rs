@@ -29,8 +29,12 @@ pub mod pallet_balances;
 pub mod pallet_collator_selection;
 pub mod pallet_message_queue;
 pub mod pallet_multisig;
+pub mod pallet_nft_fractionalization;
 pub mod pallet_nfts;
+pub mod pallet_parameters;
 pub mod pallet_proxy;
+pub mod pallet_remote_proxy;
+pub mod pallet_revive;
 pub mod pallet_session;
 pub mod pallet_timestamp;
 pub mod pallet_transaction_payment;
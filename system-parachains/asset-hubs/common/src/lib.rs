@@ -0,0 +1,83 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Shared code between the Kusama and Polkadot Asset Hub runtimes.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod tx_payment;
+
+/// Extra runtime APIs for the Asset Hub runtimes.
+pub mod apis {
+	use alloc::vec::Vec;
+	use codec::{Codec, Decode, Encode};
+	use pallet_assets::{BalanceOnHold, FrozenBalance};
+	use scale_info::TypeInfo;
+	use sp_runtime::{traits::Zero, RuntimeDebug};
+	use xcm::VersionedLocation;
+
+	/// The balance of an account in a fungible asset, broken down into its parts.
+	#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Default, RuntimeDebug)]
+	pub struct BalanceBreakdown<Balance> {
+		/// The balance that is not on hold, including the frozen balance.
+		pub free: Balance,
+		/// The balance that is frozen, i.e. the total balance cannot be reduced below it by the
+		/// account. It can overlap with the held balance.
+		pub frozen: Balance,
+		/// The balance that is on hold.
+		pub held: Balance,
+	}
+
+	impl<Balance: Zero> BalanceBreakdown<Balance> {
+		/// Whether all parts of the balance are zero.
+		pub fn is_zero(&self) -> bool {
+			self.free.is_zero() && self.frozen.is_zero() && self.held.is_zero()
+		}
+	}
+
+	/// The balance breakdown of `who` in the asset `id` of the `pallet_assets` instance `I`.
+	pub fn assets_balance_breakdown<T, I>(
+		id: T::AssetId,
+		who: &T::AccountId,
+	) -> BalanceBreakdown<T::Balance>
+	where
+		T: pallet_assets::Config<I>,
+		I: 'static,
+	{
+		BalanceBreakdown {
+			free: pallet_assets::Pallet::<T, I>::balance(id.clone(), who),
+			frozen: T::Freezer::frozen_balance(id.clone(), who).unwrap_or_default(),
+			held: T::Holder::balance_on_hold(id, who).unwrap_or_default(),
+		}
+	}
+
+	sp_api::decl_runtime_apis! {
+		/// Reports the balances of an account separately as free, frozen and held, which
+		/// `assets_common::runtime_api::FungiblesApi` only reports as a single amount.
+		pub trait FungiblesBalancesApi<AccountId, Balance>
+		where
+			AccountId: Codec,
+			Balance: Codec,
+		{
+			/// The balance breakdowns of `account` in the native asset and in all trust-backed
+			/// and foreign assets it holds, identified by their location.
+			fn query_account_balance_breakdowns(
+				account: AccountId,
+			) -> Vec<(VersionedLocation, BalanceBreakdown<Balance>)>;
+		}
+	}
+}
@@ -0,0 +1,172 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Handling of the transaction fees and tips of the Asset Hub runtimes.
+
+use core::marker::PhantomData;
+use frame_support::traits::{fungibles, Get, Imbalance, OnUnbalanced};
+use pallet_asset_conversion_tx_payment::OnChargeAssetTransaction;
+use sp_runtime::{
+	traits::{DispatchInfoOf, PostDispatchInfoOf, Saturating, Zero},
+	transaction_validity::TransactionValidityError,
+	Percent,
+};
+
+/// Implements [`OnChargeAssetTransaction`] for [`pallet_asset_conversion_tx_payment`] like
+/// [`pallet_asset_conversion_tx_payment::SwapAssetAdapter`], but hands the tip to `OT` and the
+/// rest of the fee to `OU`.
+///
+/// The fees are paid in the native asset `A`, which is why the tip can be split off the
+/// credit withdrawn by the inner adapter.
+pub struct SwapAssetAdapter<A, F, S, OU, OT>(PhantomData<(A, F, S, OU, OT)>);
+
+/// The [`pallet_asset_conversion_tx_payment::SwapAssetAdapter`] wrapped by
+/// [`SwapAssetAdapter`].
+type InnerSwapAssetAdapter<A, F, S, OU> =
+	pallet_asset_conversion_tx_payment::SwapAssetAdapter<A, F, S, OU>;
+
+impl<T, A, F, S, OU, OT> OnChargeAssetTransaction<T> for SwapAssetAdapter<A, F, S, OU, OT>
+where
+	T: pallet_asset_conversion_tx_payment::Config,
+	F: fungibles::Balanced<T::AccountId>,
+	InnerSwapAssetAdapter<A, F, S, OU>: OnChargeAssetTransaction<
+		T,
+		Balance = F::Balance,
+		LiquidityInfo = (fungibles::Credit<T::AccountId, F>, F::Balance),
+	>,
+	OT: OnUnbalanced<fungibles::Credit<T::AccountId, F>>,
+{
+	type AssetId = <InnerSwapAssetAdapter<A, F, S, OU> as OnChargeAssetTransaction<T>>::AssetId;
+	type Balance = F::Balance;
+	type LiquidityInfo = (fungibles::Credit<T::AccountId, F>, F::Balance);
+
+	fn withdraw_fee(
+		who: &T::AccountId,
+		call: &T::RuntimeCall,
+		dispatch_info: &DispatchInfoOf<T::RuntimeCall>,
+		asset_id: Self::AssetId,
+		fee: Self::Balance,
+		tip: Self::Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		InnerSwapAssetAdapter::<A, F, S, OU>::withdraw_fee(
+			who,
+			call,
+			dispatch_info,
+			asset_id,
+			fee,
+			tip,
+		)
+	}
+
+	fn can_withdraw_fee(
+		who: &T::AccountId,
+		asset_id: Self::AssetId,
+		fee: Self::Balance,
+	) -> Result<(), TransactionValidityError> {
+		InnerSwapAssetAdapter::<A, F, S, OU>::can_withdraw_fee(who, asset_id, fee)
+	}
+
+	fn correct_and_deposit_fee(
+		who: &T::AccountId,
+		dispatch_info: &DispatchInfoOf<T::RuntimeCall>,
+		post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+		corrected_fee: Self::Balance,
+		tip: Self::Balance,
+		asset_id: Self::AssetId,
+		already_withdrawn: Self::LiquidityInfo,
+	) -> Result<Self::Balance, TransactionValidityError> {
+		let (paid, asset_consumed) = already_withdrawn;
+		// The tip is never refunded, so it can be handed on before the inner adapter refunds
+		// the unused part of the fee.
+		let (tip, paid) = paid.split(tip);
+		let fee_without_tip = corrected_fee.saturating_sub(tip.peek());
+		OT::on_unbalanced(tip);
+		InnerSwapAssetAdapter::<A, F, S, OU>::correct_and_deposit_fee(
+			who,
+			dispatch_info,
+			post_info,
+			fee_without_tip,
+			Zero::zero(),
+			asset_id,
+			(paid, asset_consumed),
+		)
+	}
+}
+
+/// Splits a fungibles credit, `Share` of it is handed to `OS` and the rest to `OR`.
+///
+/// Used to split the transaction fees between the collators and the treasury, like
+/// `DealWithFees` on the relay chain.
+pub struct SplitCredit<Share, OS, OR>(PhantomData<(Share, OS, OR)>);
+
+impl<AccountId, F, Share, OS, OR> OnUnbalanced<fungibles::Credit<AccountId, F>>
+	for SplitCredit<Share, OS, OR>
+where
+	F: fungibles::Balanced<AccountId>,
+	Share: Get<Percent>,
+	OS: OnUnbalanced<fungibles::Credit<AccountId, F>>,
+	OR: OnUnbalanced<fungibles::Credit<AccountId, F>>,
+{
+	fn on_nonzero_unbalanced(credit: fungibles::Credit<AccountId, F>) {
+		let share = Share::get().mul_floor(credit.peek());
+		let (share, rest) = credit.split(share);
+		OS::on_unbalanced(share);
+		OR::on_unbalanced(rest);
+	}
+}
+
+/// Resolves a fungibles credit to the author of the current block.
+///
+/// The credit is handed to `OU` if the author is unknown or the credit cannot be resolved to
+/// it.
+pub struct ResolveAssetToAuthor<R, F, OU>(PhantomData<(R, F, OU)>);
+
+impl<R, F, OU> OnUnbalanced<fungibles::Credit<R::AccountId, F>> for ResolveAssetToAuthor<R, F, OU>
+where
+	R: pallet_authorship::Config,
+	F: fungibles::Balanced<R::AccountId>,
+	OU: OnUnbalanced<fungibles::Credit<R::AccountId, F>>,
+{
+	fn on_nonzero_unbalanced(credit: fungibles::Credit<R::AccountId, F>) {
+		let credit = match pallet_authorship::Pallet::<R>::author() {
+			Some(author) => match F::resolve(&author, credit) {
+				Ok(()) => return,
+				Err(credit) => credit,
+			},
+			None => credit,
+		};
+		OU::on_unbalanced(credit);
+	}
+}
+
+/// Burns a fungibles credit if `Burn` is `true`, otherwise hands it to `OU`.
+pub struct BurnIf<Burn, OU>(PhantomData<(Burn, OU)>);
+
+impl<AccountId, F, Burn, OU> OnUnbalanced<fungibles::Credit<AccountId, F>> for BurnIf<Burn, OU>
+where
+	F: fungibles::Balanced<AccountId>,
+	Burn: Get<bool>,
+	OU: OnUnbalanced<fungibles::Credit<AccountId, F>>,
+{
+	fn on_nonzero_unbalanced(credit: fungibles::Credit<AccountId, F>) {
+		if Burn::get() {
+			// Dropping the credit reduces the total issuance.
+			drop(credit);
+		} else {
+			OU::on_unbalanced(credit);
+		}
+	}
+}
@@ -111,6 +111,7 @@ pub use sp_runtime::BuildStorage;
 // Polkadot imports
 use pallet_xcm::{EnsureXcm, IsVoiceOfBody};
 use polkadot_runtime_common::{BlockHashCount, SlowAdjustingFeeUpdate};
+use referenda_tracks_api::ReferendaTracks;
 use xcm::prelude::*;
 use xcm_runtime_apis::{
 	dry_run::{CallDryRunEffects, Error as XcmDryRunApiError, XcmDryRunEffects},
@@ -1062,6 +1063,9 @@ mod benches {
 #[cfg(feature = "runtime-benchmarks")]
 use benches::*;
 
+/// The referenda pallets exposed through the `ReferendaTracksApi`.
+type ReferendaPallets = (FellowshipReferenda, AmbassadorReferenda);
+
 impl_runtime_apis! {
 	impl sp_consensus_aura::AuraApi<Block, AuraId> for Runtime {
 		fn slot_duration() -> sp_consensus_aura::SlotDuration {
@@ -1165,6 +1169,30 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl referenda_tracks_api::ReferendaTracksApi<Block, u16, Balance, BlockNumber> for Runtime {
+		fn tracks(
+			pallet_index: u8,
+		) -> Option<Vec<pallet_referenda::Track<u16, Balance, BlockNumber>>> {
+			ReferendaPallets::tracks(pallet_index)
+		}
+
+		fn track_thresholds(
+			pallet_index: u8,
+			track: u16,
+			elapsed: Perbill,
+		) -> Option<referenda_tracks_api::Thresholds> {
+			ReferendaPallets::track_thresholds(pallet_index, track, elapsed)
+		}
+
+		fn referendum_thresholds(
+			pallet_index: u8,
+			index: pallet_referenda::ReferendumIndex,
+			step: BlockNumber,
+		) -> Option<Vec<referenda_tracks_api::ThresholdsAt<BlockNumber>>> {
+			ReferendaPallets::referendum_thresholds(pallet_index, index, step)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,
@@ -54,3 +54,51 @@ fn governance_authorize_upgrade_works() {
 		RuntimeOrigin,
 	>(GovernanceOrigin::Location(GovernanceLocation::get())));
 }
+
+#[test]
+fn referenda_tracks_api_works() {
+	use collectives_polkadot_runtime::{
+		ambassador::AmbassadorReferendaInstance, fellowship::FellowshipReferendaInstance,
+		AmbassadorReferenda, FellowshipReferenda, OriginCaller, System,
+	};
+	use frame_support::traits::PalletInfoAccess;
+	use pallet_referenda::TracksInfo;
+	use referenda_tracks_api::{testing::check_referendum_thresholds, ReferendaTracks};
+	use sp_runtime::Perbill;
+	type ReferendaPallets = (FellowshipReferenda, AmbassadorReferenda);
+	type FellowshipTracks =
+		<Runtime as pallet_referenda::Config<FellowshipReferendaInstance>>::Tracks;
+	type AmbassadorTracks =
+		<Runtime as pallet_referenda::Config<AmbassadorReferendaInstance>>::Tracks;
+
+	let fellowship = <FellowshipReferenda as PalletInfoAccess>::index() as u8;
+	let ambassador = <AmbassadorReferenda as PalletInfoAccess>::index() as u8;
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		assert_eq!(
+			ReferendaPallets::tracks(fellowship).unwrap().len(),
+			FellowshipTracks::tracks().count(),
+		);
+		assert_eq!(
+			ReferendaPallets::tracks(ambassador).unwrap().len(),
+			AmbassadorTracks::tracks().count(),
+		);
+		assert!(ReferendaPallets::tracks(0).is_none());
+		assert!(ReferendaPallets::track_thresholds(ambassador, 99, Perbill::zero()).is_none());
+
+		System::set_block_number(1_000_000);
+		let root: OriginCaller = frame_system::RawOrigin::Root.into();
+		for track in FellowshipTracks::tracks().map(|track| track.id) {
+			check_referendum_thresholds::<Runtime, FellowshipReferendaInstance, ReferendaPallets>(
+				track,
+				root.clone(),
+			);
+		}
+		for track in AmbassadorTracks::tracks().map(|track| track.id) {
+			check_referendum_thresholds::<Runtime, AmbassadorReferendaInstance, ReferendaPallets>(
+				track,
+				root.clone(),
+			);
+		}
+	});
+}
@@ -0,0 +1,69 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Shared code between the Kusama and Polkadot Coretime runtimes.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+/// Extra runtime APIs for the Coretime runtimes.
+pub mod apis {
+	use alloc::vec::Vec;
+	use codec::{Codec, Decode, Encode};
+	use pallet_broker::{CoreIndex, RegionId, RegionRecord, SaleInfoRecord, Timeslice};
+	use scale_info::TypeInfo;
+	use sp_runtime::RuntimeDebug;
+
+	/// The phase of the current bulk coretime sale.
+	#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub enum SalePhase<Balance, RelayBlockNumber> {
+		/// No sale has been started yet.
+		NotStarted,
+		/// Only renewals are possible until the sale starts at `sale_start`.
+		Interlude(SaleInfoRecord<Balance, RelayBlockNumber>),
+		/// The price falls from the leadin price towards the end price.
+		Leadin(SaleInfoRecord<Balance, RelayBlockNumber>),
+		/// The price stays at the end price until the next sale is rotated in.
+		FixedPrice(SaleInfoRecord<Balance, RelayBlockNumber>),
+	}
+
+	sp_api::decl_runtime_apis! {
+		/// Views into `pallet_broker`, so that clients neither need to decode its raw storage nor
+		/// re-implement the configured `PriceAdapter`.
+		pub trait BrokerInfoApi<AccountId, Balance, RelayBlockNumber>
+		where
+			AccountId: Codec,
+			Balance: Codec,
+			RelayBlockNumber: Codec,
+		{
+			/// The phase of the current sale, along with its details.
+			fn sale_phase() -> SalePhase<Balance, RelayBlockNumber>;
+
+			/// The price of a core in the current sale when bought at relay chain block `when`.
+			///
+			/// Returns `None` if there is no sale or the sale has not started at `when`.
+			fn sale_price_at(when: RelayBlockNumber) -> Option<Balance>;
+
+			/// The price of renewing `core` for the region beginning at timeslice `when`.
+			///
+			/// Returns `None` if no renewal of `core` is possible at `when`.
+			fn renewal_price(core: CoreIndex, when: Timeslice) -> Option<Balance>;
+
+			/// All regions owned by `who`.
+			fn regions_of(who: AccountId) -> Vec<(RegionId, RegionRecord<AccountId, Balance>)>;
+		}
+	}
+}
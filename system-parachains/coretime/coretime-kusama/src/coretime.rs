@@ -16,6 +16,7 @@
 
 use crate::*;
 use codec::{Decode, Encode};
+use coretime_common::apis::SalePhase;
 use cumulus_pallet_parachain_system::RelaychainDataProvider;
 use cumulus_primitives_core::relay_chain;
 use frame_support::{
@@ -30,21 +31,27 @@ use frame_support::{
 use frame_system::Pallet as System;
 use kusama_runtime_constants::{system_parachain::coretime, time::DAYS as RELAY_DAYS};
 use pallet_broker::{
-	CoreAssignment, CoreIndex, CoretimeInterface, PartsOf57600, RCBlockNumberOf, TaskId,
+	AdaptPrice, CoreAssignment, CoreIndex, CoretimeInterface, PartsOf57600, PotentialRenewalId,
+	RCBlockNumberOf, RegionId, RegionRecordOf, TaskId, Timeslice,
 };
 use parachains_common::{AccountId, Balance};
-use sp_runtime::traits::{AccountIdConversion, MaybeConvert};
+use sp_runtime::{
+	traits::{AccountIdConversion, BlockNumberProvider, MaybeConvert},
+	FixedPointNumber, FixedU64,
+};
 use xcm::latest::prelude::*;
 use xcm_config::LocationToAccountId;
 use xcm_executor::traits::{ConvertLocation, TransactAsset};
 
-/// A type containing the encoding of the coretime pallet in the Relay chain runtime. Used to
-/// construct any remote calls. The codec index must correspond to the index of `Coretime` in the
-/// `construct_runtime` of the Relay chain.
+/// A type containing the encoding of the coretime pallets in the Relay chain runtime. Used to
+/// construct any remote calls. The codec indices must correspond to the indices of `Coretime` and
+/// `CoretimeAssignmentBuffer` in the `construct_runtime` of the Relay chain.
 #[derive(Encode, Decode)]
 enum RelayRuntimePallets {
 	#[codec(index = 74)]
 	Coretime(CoretimeProviderCalls),
+	#[codec(index = 75)]
+	CoretimeAssignmentBuffer(AssignmentBufferCalls),
 }
 
 /// Call encoding for the calls needed from the relay coretime pallet.
@@ -65,6 +72,24 @@ enum CoretimeProviderCalls {
 	),
 }
 
+/// Call encoding for the calls needed from the relay coretime assignment buffer pallet.
+#[derive(Encode, Decode)]
+enum AssignmentBufferCalls {
+	#[codec(index = 0)]
+	AssignCorePart(
+		CoreIndex,
+		relay_chain::BlockNumber,
+		Vec<(CoreAssignment, PartsOf57600)>,
+		Option<relay_chain::BlockNumber>,
+	),
+}
+
+/// The maximum number of assignments sent to the Relay chain in a single call.
+///
+/// Larger assignments are split into several `assign_core_part` calls, which are buffered by the
+/// Relay chain until the complete core is assigned.
+const MAX_ASSIGNMENTS_PER_CALL: usize = 28;
+
 parameter_types! {
 	/// The holding account into which burnt funds will be moved at the point of sale. This will be
 	/// burnt periodically.
@@ -209,60 +234,14 @@ impl CoretimeInterface for CoretimeAllocator {
 
 	fn credit_account(who: Self::AccountId, amount: Self::Balance) {
 		use crate::coretime::CoretimeProviderCalls::CreditAccount;
-		let _credit_account_call = RelayRuntimePallets::Coretime(CreditAccount(who, amount));
-
-		log::debug!(
-			target: "runtime::coretime",
-			"`credit_account` is unimplemented on the relay."
-		);
-	}
+		let credit_account_call = RelayRuntimePallets::Coretime(CreditAccount(who, amount));
 
-	fn assign_core(
-		core: CoreIndex,
-		begin: RCBlockNumberOf<Self>,
-		assignment: Vec<(CoreAssignment, PartsOf57600)>,
-		end_hint: Option<RCBlockNumberOf<Self>>,
-	) {
-		use crate::coretime::CoretimeProviderCalls::AssignCore;
-
-		// Weight for `assign_core` from Kusama runtime benchmarks:
+		// Weight for `credit_account` from Kusama runtime benchmarks:
 		// `ref_time`, `proof_size`, reads, writes
-		// 12_042_907 + 80 * 13_919, 3545, 1, 2
+		// 12_580_000, 3574, 1, 1
 		// Add 30% to each component with a healthy round up.
 		let call_weight =
-			Weight::from_parts(350 * WEIGHT_REF_TIME_PER_MICROS, 5 * WEIGHT_PROOF_SIZE_PER_KB);
-
-		// The relay chain currently only allows `assign_core` to be called with a complete mask
-		// and only ever with increasing `begin`. The assignments must be truncated to avoid
-		// dropping that core's assignment completely.
-
-		// This shadowing of `assignment` is temporary and can be removed when the relay can accept
-		// multiple messages to assign a single core.
-		let assignment = if assignment.len() > 28 {
-			let mut total_parts = 0u16;
-			// Account for missing parts with a new `Idle` assignment at the start as
-			// `assign_core` on the relay assumes this is sorted. We'll add the rest of the
-			// assignments and sum the parts in one pass, so this is just initialized to 0.
-			let mut assignment_truncated = vec![(CoreAssignment::Idle, 0)];
-			// Truncate to first 27 non-idle assignments.
-			assignment_truncated.extend(
-				assignment
-					.into_iter()
-					.filter(|(a, _)| *a != CoreAssignment::Idle)
-					.take(27)
-					.inspect(|(_, parts)| total_parts += *parts)
-					.collect::<Vec<_>>(),
-			);
-
-			// Set the parts of the `Idle` assignment we injected at the start of the vec above.
-			assignment_truncated[0].1 = 57_600u16.saturating_sub(total_parts);
-			assignment_truncated
-		} else {
-			assignment
-		};
-
-		let assign_core_call =
-			RelayRuntimePallets::Coretime(AssignCore(core, begin, assignment, end_hint));
+			Weight::from_parts(250 * WEIGHT_REF_TIME_PER_MICROS, 5 * WEIGHT_PROOF_SIZE_PER_KB);
 
 		let message = Xcm(vec![
 			Instruction::UnpaidExecution {
@@ -272,22 +251,97 @@ impl CoretimeInterface for CoretimeAllocator {
 			Instruction::Transact {
 				origin_kind: OriginKind::Native,
 				fallback_max_weight: Some(call_weight),
-				call: assign_core_call.encode().into(),
+				call: credit_account_call.encode().into(),
 			},
 		]);
 
 		match PolkadotXcm::send_xcm(Here, Location::parent(), message) {
 			Ok(_) => log::debug!(
 				target: "runtime::coretime",
-				"Core assignment sent successfully."
+				"Request to credit account sent successfully."
 			),
 			Err(e) => log::error!(
 				target: "runtime::coretime",
-				"Core assignment failed to send: {e:?}"
+				"Failed to send request to credit account: {e:?}"
 			),
 		}
 	}
 
+	fn assign_core(
+		core: CoreIndex,
+		begin: RCBlockNumberOf<Self>,
+		assignment: Vec<(CoreAssignment, PartsOf57600)>,
+		end_hint: Option<RCBlockNumberOf<Self>>,
+	) {
+		use crate::coretime::{
+			AssignmentBufferCalls::AssignCorePart, CoretimeProviderCalls::AssignCore,
+		};
+
+		// Weight for `assign_core` from Kusama runtime benchmarks:
+		// `ref_time`, `proof_size`, reads, writes
+		// 12_042_907 + 80 * 13_919, 3545, 1, 2
+		// Add 30% to each component with a healthy round up.
+		let call_weight =
+			Weight::from_parts(350 * WEIGHT_REF_TIME_PER_MICROS, 5 * WEIGHT_PROOF_SIZE_PER_KB);
+
+		// `assign_core_part` is not benchmarked yet. It forwards to `assign_core` above and
+		// additionally reads and writes `PendingAssignments` and `ExpiryQueue` of the
+		// `CoretimeAssignmentBuffer`, whose maximum encoded sizes add 3190 + 6497 bytes of proof.
+		// Add 30% to the two reads and writes with a healthy round up.
+		let part_call_weight =
+			Weight::from_parts(700 * WEIGHT_REF_TIME_PER_MICROS, 18 * WEIGHT_PROOF_SIZE_PER_KB);
+
+		// The relay chain only allows `assign_core` to be called with a complete mask. Assignments
+		// that are too large for a single call are sent in several parts for the same `begin`,
+		// which are buffered by the relay chain until the complete mask was received.
+		let calls = if assignment.len() <= MAX_ASSIGNMENTS_PER_CALL {
+			vec![(
+				RelayRuntimePallets::Coretime(AssignCore(core, begin, assignment, end_hint)),
+				call_weight,
+			)]
+		} else {
+			assignment
+				.chunks(MAX_ASSIGNMENTS_PER_CALL)
+				.map(|part| {
+					(
+						RelayRuntimePallets::CoretimeAssignmentBuffer(AssignCorePart(
+							core,
+							begin,
+							part.to_vec(),
+							end_hint,
+						)),
+						part_call_weight,
+					)
+				})
+				.collect()
+		};
+
+		for (assign_core_call, weight) in calls {
+			let message = Xcm(vec![
+				Instruction::UnpaidExecution {
+					weight_limit: WeightLimit::Unlimited,
+					check_origin: None,
+				},
+				Instruction::Transact {
+					origin_kind: OriginKind::Native,
+					fallback_max_weight: Some(weight),
+					call: assign_core_call.encode().into(),
+				},
+			]);
+
+			match PolkadotXcm::send_xcm(Here, Location::parent(), message) {
+				Ok(_) => log::debug!(
+					target: "runtime::coretime",
+					"Core assignment sent successfully."
+				),
+				Err(e) => log::error!(
+					target: "runtime::coretime",
+					"Core assignment failed to send: {e:?}"
+				),
+			}
+		}
+	}
+
 	fn on_new_timeslice(t: pallet_broker::Timeslice) {
 		// Burn roughly once per day. TIMESLICE_PERIOD tested to be != 0.
 		const BURN_PERIOD: pallet_broker::Timeslice =
@@ -349,3 +403,44 @@ impl pallet_broker::Config for Runtime {
 	type PriceAdapter = pallet_broker::MinimumPrice<Balance, MinimumEndPrice>;
 	type MinimumCreditPurchase = MinimumCreditPurchase;
 }
+
+/// The phase of the current sale at the current relay chain block.
+pub fn sale_phase() -> SalePhase<Balance, relay_chain::BlockNumber> {
+	let Some(sale) = pallet_broker::SaleInfo::<Runtime>::get() else {
+		return SalePhase::NotStarted;
+	};
+	let now = RelaychainDataProvider::<Runtime>::current_block_number();
+	if now <= sale.sale_start {
+		SalePhase::Interlude(sale)
+	} else if now < sale.sale_start.saturating_add(sale.leadin_length) {
+		SalePhase::Leadin(sale)
+	} else {
+		SalePhase::FixedPrice(sale)
+	}
+}
+
+/// The price of a core in the current sale when bought at relay chain block `when`, as computed
+/// by the configured `PriceAdapter`.
+pub fn sale_price_at(when: relay_chain::BlockNumber) -> Option<Balance> {
+	let sale = pallet_broker::SaleInfo::<Runtime>::get()?;
+	if when <= sale.sale_start {
+		return None;
+	}
+	let elapsed = when.saturating_sub(sale.sale_start).min(sale.leadin_length);
+	let through = FixedU64::from_rational(elapsed.into(), sale.leadin_length.max(1).into());
+	let factor = <Runtime as pallet_broker::Config>::PriceAdapter::leadin_factor_at(through);
+	Some(factor.saturating_mul_int(sale.end_price))
+}
+
+/// The price of renewing `core` for the region beginning at timeslice `when`.
+pub fn renewal_price(core: CoreIndex, when: Timeslice) -> Option<Balance> {
+	pallet_broker::PotentialRenewals::<Runtime>::get(PotentialRenewalId { core, when })
+		.map(|renewal| renewal.price)
+}
+
+/// All regions owned by `who`.
+pub fn regions_of(who: AccountId) -> Vec<(RegionId, RegionRecordOf<Runtime>)> {
+	pallet_broker::Regions::<Runtime>::iter()
+		.filter(|(_, region)| region.owner.as_ref() == Some(&who))
+		.collect()
+}
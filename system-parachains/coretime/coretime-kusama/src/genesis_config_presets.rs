@@ -144,6 +144,7 @@ fn coretime_kusama_live_genesis(para_id: ParaId) -> serde_json::Value {
 /// Provides the names of the predefined genesis configs for this runtime.
 pub fn preset_names() -> Vec<PresetId> {
 	vec![
+		PresetId::from("live"),
 		PresetId::from(sp_genesis_builder::DEV_RUNTIME_PRESET),
 		PresetId::from(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET),
 	]
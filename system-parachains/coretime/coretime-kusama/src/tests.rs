@@ -20,6 +20,7 @@ use crate::{
 	GovernanceLocation, *,
 };
 use coretime::CoretimeAllocator;
+use coretime_common::apis::SalePhase;
 use cumulus_pallet_parachain_system::ValidationData;
 use cumulus_primitives_core::PersistedValidationData;
 use frame_support::{
@@ -30,7 +31,7 @@ use frame_support::{
 	},
 };
 use kusama_runtime_constants::system_parachain::coretime::TIMESLICE_PERIOD;
-use pallet_broker::{ConfigRecordOf, RCBlockNumberOf, SaleInfo};
+use pallet_broker::{ConfigRecordOf, Finality, RCBlockNumberOf, SaleInfo};
 use parachains_runtimes_test_utils::{ExtBuilder, GovernanceOrigin};
 use sp_core::crypto::Ss58Codec;
 use sp_runtime::{traits::AccountIdConversion, Either};
@@ -121,6 +122,82 @@ fn bulk_revenue_is_burnt() {
 		});
 }
 
+#[test]
+fn broker_info_api_works() {
+	ExtBuilder::<Runtime>::default()
+		.with_collators(vec![AccountId::from(ALICE)])
+		.with_session_keys(vec![(
+			AccountId::from(ALICE),
+			AccountId::from(ALICE),
+			SessionKeys { aura: AuraId::from(sp_core::sr25519::Public::from_raw(ALICE)) },
+		)])
+		.build()
+		.execute_with(|| {
+			let alice = AccountId::from(ALICE);
+			assert_eq!(coretime::sale_phase(), SalePhase::NotStarted);
+			assert_eq!(coretime::sale_price_at(1), None);
+
+			let config = ConfigRecordOf::<Runtime> {
+				advance_notice: 1,
+				interlude_length: 1,
+				leadin_length: 10,
+				region_length: 1,
+				ideal_bulk_proportion: Perbill::from_percent(100),
+				limit_cores_offered: None,
+				renewal_bump: Perbill::from_percent(3),
+				contribution_timeout: 1,
+			};
+			assert_ok!(Broker::configure(RuntimeOrigin::root(), config));
+			assert_ok!(Broker::start_sales(RuntimeOrigin::root(), UNITS, 1));
+
+			let sale = SaleInfo::<Runtime>::get().unwrap();
+			assert_eq!(coretime::sale_phase(), SalePhase::Interlude(sale.clone()));
+			assert_eq!(coretime::sale_price_at(sale.sale_start), None);
+
+			// The price falls during the leadin and stays at the end price afterwards.
+			let leadin_end = sale.sale_start + sale.leadin_length;
+			assert!(
+				coretime::sale_price_at(sale.sale_start + 1) >
+					coretime::sale_price_at(leadin_end - 1)
+			);
+			assert_eq!(coretime::sale_price_at(leadin_end), Some(sale.end_price));
+			assert_eq!(coretime::sale_price_at(leadin_end + 100), Some(sale.end_price));
+
+			advance_to(sale.sale_start + 1);
+			assert_eq!(coretime::sale_phase(), SalePhase::Leadin(sale.clone()));
+
+			// The reported price is the one charged on purchase.
+			let price = coretime::sale_price_at(sale.sale_start + 1).unwrap();
+			assert_ok!(Balances::mint_into(&alice, price + 200 * UNITS));
+			let alice_balance_before = Balances::balance(&alice);
+			assert_ok!(Broker::purchase(RuntimeOrigin::signed(alice.clone()), price));
+			assert_eq!(Balances::balance(&alice), alice_balance_before - price);
+
+			let regions = coretime::regions_of(alice.clone());
+			assert_eq!(regions.len(), 1);
+			let (region_id, region) = regions[0].clone();
+			assert_eq!(region.owner, Some(alice.clone()));
+			assert!(coretime::regions_of(AccountId::from([2u8; 32])).is_empty());
+
+			// Assigning the region with final finality makes it renewable.
+			assert_eq!(coretime::renewal_price(region_id.core, region.end), None);
+			assert_ok!(Broker::assign(
+				RuntimeOrigin::signed(alice.clone()),
+				region_id,
+				1000,
+				Finality::Final
+			));
+			assert!(coretime::renewal_price(region_id.core, region.end).is_some());
+			assert!(coretime::regions_of(alice).is_empty());
+
+			advance_to(leadin_end);
+			assert_eq!(
+				coretime::sale_phase(),
+				SalePhase::FixedPrice(SaleInfo::<Runtime>::get().unwrap())
+			);
+		});
+}
+
 #[test]
 fn timeslice_period_is_sane() {
 	// Config TimeslicePeriod is set to this constant - assumption in burning logic.
@@ -52,19 +52,23 @@ use frame_system::{
 	limits::{BlockLength, BlockWeights},
 	EnsureRoot,
 };
+use pallet_proxy::ProxyDefinition;
 use pallet_xcm::{EnsureXcm, IsVoiceOfBody};
 use parachains_common::{
 	message_queue::{NarrowOriginToSibling, ParaIdToSibling},
 	AccountId, AuraId, Balance, BlockNumber, Hash, Header, Nonce, Signature,
 };
 use polkadot_runtime_common::{BlockHashCount, SlowAdjustingFeeUpdate};
+use polkadot_runtime_constants::time::{
+	MILLISECS_PER_BLOCK as RC_MILLISECS_PER_BLOCK, MINUTES as RC_MINUTES,
+};
 use sp_api::impl_runtime_apis;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
 use sp_runtime::{
 	generic, impl_opaque_keys,
-	traits::{BlakeTwo256, Block as BlockT, BlockNumberProvider},
+	traits::{BlakeTwo256, Block as BlockT, BlockNumberProvider, Convert},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiAddress, Perbill, RuntimeDebug,
 };
@@ -320,7 +324,7 @@ parameter_types! {
 impl cumulus_pallet_parachain_system::Config for Runtime {
 	type WeightInfo = weights::cumulus_pallet_parachain_system::WeightInfo<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
-	type OnSystemEvent = ();
+	type OnSystemEvent = RelayStateOracle;
 	type SelfParaId = parachain_info::Pallet<Runtime>;
 	type DmpQueue = frame_support::traits::EnqueueWithOrigin<MessageQueue, RelayOrigin>;
 	type OutboundXcmpMessageSource = XcmpQueue;
@@ -627,6 +631,65 @@ impl pallet_proxy::Config for Runtime {
 	type BlockNumberProvider = System;
 }
 
+/// Converts from the Asset Hub proxy type to the local proxy type.
+pub struct AssetHubToLocalProxyTypeConverter;
+
+impl
+	Convert<
+		ProxyDefinition<AccountId, bp_asset_hub_polkadot::proxy::ProxyType, BlockNumber>,
+		Option<ProxyDefinition<AccountId, ProxyType, BlockNumber>>,
+	> for AssetHubToLocalProxyTypeConverter
+{
+	fn convert(
+		a: ProxyDefinition<AccountId, bp_asset_hub_polkadot::proxy::ProxyType, BlockNumber>,
+	) -> Option<ProxyDefinition<AccountId, ProxyType, BlockNumber>> {
+		let proxy_type = match a.proxy_type {
+			bp_asset_hub_polkadot::proxy::ProxyType::Any => ProxyType::Any,
+			bp_asset_hub_polkadot::proxy::ProxyType::NonTransfer => ProxyType::NonTransfer,
+			bp_asset_hub_polkadot::proxy::ProxyType::CancelProxy => ProxyType::CancelProxy,
+			// Proxy types that are not supported on the Coretime chain.
+			bp_asset_hub_polkadot::proxy::ProxyType::Assets |
+			bp_asset_hub_polkadot::proxy::ProxyType::AssetOwner |
+			bp_asset_hub_polkadot::proxy::ProxyType::AssetManager |
+			bp_asset_hub_polkadot::proxy::ProxyType::Collator => return None,
+		};
+
+		Some(ProxyDefinition {
+			delegate: a.delegate,
+			proxy_type,
+			// The delay is converted separately by `AssetHubToLocalDelayConverter`.
+			delay: a.delay,
+		})
+	}
+}
+
+/// Converts the delay of an Asset Hub proxy into local blocks.
+///
+/// Asset Hub measures proxy delays in relay chain blocks.
+pub type AssetHubToLocalDelayConverter = pallet_remote_proxy::BlockTimeDelayConverter<
+	ConstU64<RC_MILLISECS_PER_BLOCK>,
+	ConstU64<{ system_parachains_constants::MILLISECS_PER_BLOCK }>,
+>;
+
+impl pallet_relay_state_oracle::Config for Runtime {
+	type RelayBlockNumber = BlockNumber;
+	type RelayHash = Hash;
+	type RelayHasher = BlakeTwo256;
+	// The time between creating a proof and using the proof in a transaction.
+	type MaxStorageRootsToKeep = ConstU32<{ RC_MINUTES }>;
+}
+
+impl pallet_remote_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RelayStateOracle = RelayStateOracle;
+	type RemoteProxy = bp_asset_hub_polkadot::proxy::RemoteProxyInterface<
+		ProxyType,
+		AssetHubToLocalProxyTypeConverter,
+		AssetHubToLocalDelayConverter,
+	>;
+	type WeightInfo = weights::pallet_remote_proxy::WeightInfo<Runtime>;
+}
+
 impl pallet_utility::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -665,6 +728,8 @@ construct_runtime!(
 		Utility: pallet_utility = 40,
 		Multisig: pallet_multisig = 41,
 		Proxy: pallet_proxy = 42,
+		RemoteProxyAssetHub: pallet_remote_proxy = 43,
+		RelayStateOracle: pallet_relay_state_oracle = 44,
 
 		// The main stage.
 		Broker: pallet_broker = 50,
@@ -691,6 +756,7 @@ mod benches {
 		[pallet_message_queue, MessageQueue]
 		[pallet_multisig, Multisig]
 		[pallet_proxy, Proxy]
+		[pallet_remote_proxy, RemoteProxyAssetHub]
 		[pallet_transaction_payment, TransactionPayment]
 		[pallet_utility, Utility]
 		// NOTE: Make sure you point to the individual modules below.
@@ -1029,6 +1095,51 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl coretime_common::apis::BrokerInfoApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn sale_phase() -> coretime_common::apis::SalePhase<Balance, BlockNumber> {
+			coretime::sale_phase()
+		}
+
+		fn sale_price_at(when: BlockNumber) -> Option<Balance> {
+			coretime::sale_price_at(when)
+		}
+
+		fn renewal_price(
+			core: pallet_broker::CoreIndex,
+			when: pallet_broker::Timeslice,
+		) -> Option<Balance> {
+			coretime::renewal_price(core, when)
+		}
+
+		fn regions_of(
+			who: AccountId,
+		) -> Vec<(pallet_broker::RegionId, pallet_broker::RegionRecordOf<Runtime>)> {
+			coretime::regions_of(who)
+		}
+	}
+
+	impl pallet_remote_proxy::runtime_api::RemoteProxyApi<Block, AccountId, ProxyType, BlockNumber, BlockNumber, Hash> for Runtime {
+		fn known_storage_roots() -> Vec<(BlockNumber, Hash)> {
+			RemoteProxyAssetHub::known_storage_roots()
+		}
+
+		fn recommended_anchor_block() -> Option<BlockNumber> {
+			RemoteProxyAssetHub::recommended_anchor_block()
+		}
+
+		fn verify_remote_proxy_proof(
+			real: AccountId,
+			delegate: AccountId,
+			proxy_type: Option<ProxyType>,
+			proof: pallet_remote_proxy::RemoteProxyProof<BlockNumber>,
+		) -> Result<
+			pallet_remote_proxy::ProxyDefinition<AccountId, ProxyType, BlockNumber>,
+			sp_runtime::DispatchError,
+		> {
+			RemoteProxyAssetHub::verify_remote_proxy_proof(real, delegate, proxy_type, proof)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,
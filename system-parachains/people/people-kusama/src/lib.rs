@@ -23,6 +23,7 @@ extern crate alloc;
 // Genesis preset configurations.
 pub mod genesis_config_presets;
 pub mod people;
+pub mod recovery;
 #[cfg(test)]
 mod tests;
 mod weights;
@@ -39,7 +40,7 @@ use frame_support::{
 	parameter_types,
 	traits::{
 		tokens::imbalance::ResolveTo, ConstBool, ConstU32, ConstU64, ConstU8, EitherOfDiverse,
-		Everything, InstanceFilter, TransformOrigin,
+		InstanceFilter, TransformOrigin,
 	},
 	weights::{ConstantMultiplier, Weight, WeightToFee as _},
 	PalletId,
@@ -48,6 +49,10 @@ use frame_system::{
 	limits::{BlockLength, BlockWeights},
 	EnsureRoot,
 };
+use kusama_runtime_constants::time::{
+	MILLISECS_PER_BLOCK as RC_MILLISECS_PER_BLOCK, MINUTES as RC_MINUTES,
+};
+use pallet_proxy::ProxyDefinition;
 use pallet_xcm::{EnsureXcm, IsVoiceOfBody};
 use parachains_common::{
 	message_queue::{NarrowOriginToSibling, ParaIdToSibling},
@@ -61,7 +66,7 @@ use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 pub use sp_runtime::BuildStorage;
 use sp_runtime::{
 	generic, impl_opaque_keys,
-	traits::{BlakeTwo256, Block as BlockT},
+	traits::{BlakeTwo256, Block as BlockT, Convert},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, RuntimeDebug,
 };
@@ -209,7 +214,7 @@ parameter_types! {
 
 #[derive_impl(frame_system::config_preludes::ParaChainDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Runtime {
-	type BaseCallFilter = Everything;
+	type BaseCallFilter = recovery::RecoveryFriendsFilter<recovery::RecoveryFriendsMustBeKnownGood>;
 	type BlockWeights = RuntimeBlockWeights;
 	type BlockLength = RuntimeBlockLength;
 	type AccountId = AccountId;
@@ -286,7 +291,7 @@ parameter_types! {
 
 impl cumulus_pallet_parachain_system::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnSystemEvent = ();
+	type OnSystemEvent = RelayStateOracle;
 	type SelfParaId = parachain_info::Pallet<Runtime>;
 	type OutboundXcmpMessageSource = XcmpQueue;
 	type DmpQueue = frame_support::traits::EnqueueWithOrigin<MessageQueue, RelayOrigin>;
@@ -471,6 +476,8 @@ pub enum ProxyType {
 	IdentityJudgement,
 	/// Collator selection proxy. Can execute calls related to collator selection mechanism.
 	Collator,
+	/// Proxy for all Recovery pallet calls, except for dispatching calls as a recovered account.
+	Recovery,
 }
 impl Default for ProxyType {
 	fn default() -> Self {
@@ -490,7 +497,9 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 				// `set_subs` and `add_sub` will take and repatriate deposits from the proxied
 				// account, should not be allowed.
 				RuntimeCall::Identity(pallet_identity::Call::add_sub { .. }) |
-				RuntimeCall::Identity(pallet_identity::Call::set_subs { .. })
+				RuntimeCall::Identity(pallet_identity::Call::set_subs { .. }) |
+				// `as_recovered` can dispatch any call from a recovered account.
+				RuntimeCall::Recovery(pallet_recovery::Call::as_recovered { .. })
 			),
 			ProxyType::CancelProxy => matches!(
 				c,
@@ -518,6 +527,13 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					RuntimeCall::Utility { .. } |
 					RuntimeCall::Multisig { .. }
 			),
+			ProxyType::Recovery =>
+				matches!(
+					c,
+					RuntimeCall::Recovery { .. } |
+						RuntimeCall::Utility { .. } |
+						RuntimeCall::Multisig { .. }
+				) && !matches!(c, RuntimeCall::Recovery(pallet_recovery::Call::as_recovered { .. })),
 		}
 	}
 
@@ -529,6 +545,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			(ProxyType::Identity, ProxyType::IdentityJudgement) => true,
 			(ProxyType::NonTransfer, ProxyType::IdentityJudgement) => true,
 			(ProxyType::NonTransfer, ProxyType::Collator) => true,
+			(ProxyType::NonTransfer, ProxyType::Recovery) => true,
 			_ => false,
 		}
 	}
@@ -562,6 +579,65 @@ impl pallet_proxy::Config for Runtime {
 	type BlockNumberProvider = System;
 }
 
+/// Converts from the Asset Hub proxy type to the local proxy type.
+pub struct AssetHubToLocalProxyTypeConverter;
+
+impl
+	Convert<
+		ProxyDefinition<AccountId, bp_asset_hub_kusama::proxy::ProxyType, BlockNumber>,
+		Option<ProxyDefinition<AccountId, ProxyType, BlockNumber>>,
+	> for AssetHubToLocalProxyTypeConverter
+{
+	fn convert(
+		a: ProxyDefinition<AccountId, bp_asset_hub_kusama::proxy::ProxyType, BlockNumber>,
+	) -> Option<ProxyDefinition<AccountId, ProxyType, BlockNumber>> {
+		let proxy_type = match a.proxy_type {
+			bp_asset_hub_kusama::proxy::ProxyType::Any => ProxyType::Any,
+			bp_asset_hub_kusama::proxy::ProxyType::NonTransfer => ProxyType::NonTransfer,
+			bp_asset_hub_kusama::proxy::ProxyType::CancelProxy => ProxyType::CancelProxy,
+			// Proxy types that are not supported on the People chain.
+			bp_asset_hub_kusama::proxy::ProxyType::Assets |
+			bp_asset_hub_kusama::proxy::ProxyType::AssetOwner |
+			bp_asset_hub_kusama::proxy::ProxyType::AssetManager |
+			bp_asset_hub_kusama::proxy::ProxyType::Collator => return None,
+		};
+
+		Some(ProxyDefinition {
+			delegate: a.delegate,
+			proxy_type,
+			// The delay is converted separately by `AssetHubToLocalDelayConverter`.
+			delay: a.delay,
+		})
+	}
+}
+
+/// Converts the delay of an Asset Hub proxy into local blocks.
+///
+/// Asset Hub measures proxy delays in relay chain blocks.
+pub type AssetHubToLocalDelayConverter = pallet_remote_proxy::BlockTimeDelayConverter<
+	ConstU64<RC_MILLISECS_PER_BLOCK>,
+	ConstU64<{ system_parachains_constants::async_backing::MILLISECS_PER_BLOCK }>,
+>;
+
+impl pallet_relay_state_oracle::Config for Runtime {
+	type RelayBlockNumber = BlockNumber;
+	type RelayHash = Hash;
+	type RelayHasher = BlakeTwo256;
+	// The time between creating a proof and using the proof in a transaction.
+	type MaxStorageRootsToKeep = ConstU32<{ RC_MINUTES }>;
+}
+
+impl pallet_remote_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RelayStateOracle = RelayStateOracle;
+	type RemoteProxy = bp_asset_hub_kusama::proxy::RemoteProxyInterface<
+		ProxyType,
+		AssetHubToLocalProxyTypeConverter,
+		AssetHubToLocalDelayConverter,
+	>;
+	type WeightInfo = weights::pallet_remote_proxy::WeightInfo<Runtime>;
+}
+
 impl pallet_utility::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -620,9 +696,12 @@ construct_runtime!(
 		Utility: pallet_utility = 40,
 		Multisig: pallet_multisig = 41,
 		Proxy: pallet_proxy = 42,
+		RemoteProxyAssetHub: pallet_remote_proxy = 43,
+		RelayStateOracle: pallet_relay_state_oracle = 44,
 
 		// The main stage.
 		Identity: pallet_identity = 50,
+		Recovery: pallet_recovery = 51,
 	}
 );
 
@@ -642,6 +721,8 @@ mod benches {
 		[pallet_migrations, MultiBlockMigrations]
 		[pallet_multisig, Multisig]
 		[pallet_proxy, Proxy]
+		[pallet_recovery, Recovery]
+		[pallet_remote_proxy, RemoteProxyAssetHub]
 		[pallet_session, SessionBench::<Runtime>]
 		[pallet_timestamp, Timestamp]
 		[pallet_transaction_payment, TransactionPayment]
@@ -947,6 +1028,28 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_remote_proxy::runtime_api::RemoteProxyApi<Block, AccountId, ProxyType, BlockNumber, BlockNumber, Hash> for Runtime {
+		fn known_storage_roots() -> Vec<(BlockNumber, Hash)> {
+			RemoteProxyAssetHub::known_storage_roots()
+		}
+
+		fn recommended_anchor_block() -> Option<BlockNumber> {
+			RemoteProxyAssetHub::recommended_anchor_block()
+		}
+
+		fn verify_remote_proxy_proof(
+			real: AccountId,
+			delegate: AccountId,
+			proxy_type: Option<ProxyType>,
+			proof: pallet_remote_proxy::RemoteProxyProof<BlockNumber>,
+		) -> Result<
+			pallet_remote_proxy::ProxyDefinition<AccountId, ProxyType, BlockNumber>,
+			sp_runtime::DispatchError,
+		> {
+			RemoteProxyAssetHub::verify_remote_proxy_proof(real, delegate, proxy_type, proof)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,
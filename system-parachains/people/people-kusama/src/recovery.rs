@@ -0,0 +1,125 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Social recovery of accounts through `pallet_recovery`.
+//!
+//! If [`RecoveryFriendsMustBeKnownGood`] is set, the friends of a recoverable account must have a
+//! `KnownGood` judgement in `pallet_identity` when the recovery is created. This is enforced by
+//! [`RecoveryFriendsFilter`] as part of the `BaseCallFilter`, which also applies to nested calls
+//! and calls dispatched through XCM. [`RecoveryWeightInfo`] accounts for the identity reads.
+
+use super::*;
+use core::marker::PhantomData;
+use frame_support::{
+	parameter_types,
+	traits::{ConstBool, Contains, Get},
+};
+use pallet_identity::{IdentityOf, Judgement};
+
+parameter_types! {
+	// One storage item; key size 32, value size 4 + 16 + 1 + 2 = 23.
+	pub const ConfigDepositBase: Balance = system_para_deposit(1, 55);
+	// Additional storage item size of 32 bytes.
+	pub const FriendDepositFactor: Balance = system_para_deposit(0, 32);
+	pub const MaxFriends: u16 = 9;
+	// One storage item; key size 64, value size 4 + 16 + 1 = 21.
+	pub const RecoveryDeposit: Balance = system_para_deposit(1, 85);
+}
+
+/// Whether the friends of a recoverable account must have a `KnownGood` judgement.
+pub type RecoveryFriendsMustBeKnownGood = ConstBool<false>;
+
+impl pallet_recovery::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = RecoveryWeightInfo<
+		weights::pallet_recovery::WeightInfo<Runtime>,
+		RecoveryFriendsMustBeKnownGood,
+	>;
+	type RuntimeCall = RuntimeCall;
+	type BlockNumberProvider = System;
+	type Currency = Balances;
+	type ConfigDepositBase = ConfigDepositBase;
+	type FriendDepositFactor = FriendDepositFactor;
+	type MaxFriends = MaxFriends;
+	type RecoveryDeposit = RecoveryDeposit;
+}
+
+/// Filters `Recovery::create_recovery` calls with friends that do not have a `KnownGood`
+/// judgement, if `MustBeKnownGood` is set.
+pub struct RecoveryFriendsFilter<MustBeKnownGood>(PhantomData<MustBeKnownGood>);
+
+impl<MustBeKnownGood: Get<bool>> RecoveryFriendsFilter<MustBeKnownGood> {
+	/// Returns whether `who` has a `KnownGood` judgement of any registrar.
+	pub fn is_known_good(who: &AccountId) -> bool {
+		IdentityOf::<Runtime>::get(who).is_some_and(|registration| {
+			registration
+				.judgements
+				.iter()
+				.any(|(_, judgement)| matches!(judgement, Judgement::KnownGood))
+		})
+	}
+}
+
+impl<MustBeKnownGood: Get<bool>> Contains<RuntimeCall> for RecoveryFriendsFilter<MustBeKnownGood> {
+	fn contains(call: &RuntimeCall) -> bool {
+		match call {
+			RuntimeCall::Recovery(pallet_recovery::Call::create_recovery { friends, .. })
+				if MustBeKnownGood::get() =>
+				friends.iter().all(Self::is_known_good),
+			_ => true,
+		}
+	}
+}
+
+/// The weights of `pallet_recovery` with the identity reads of [`RecoveryFriendsFilter`] added to
+/// `create_recovery`, if `MustBeKnownGood` is set.
+pub struct RecoveryWeightInfo<W, MustBeKnownGood>(PhantomData<(W, MustBeKnownGood)>);
+
+impl<W, MustBeKnownGood> pallet_recovery::WeightInfo for RecoveryWeightInfo<W, MustBeKnownGood>
+where
+	W: pallet_recovery::WeightInfo,
+	MustBeKnownGood: Get<bool>,
+{
+	fn as_recovered() -> Weight {
+		W::as_recovered()
+	}
+	fn set_recovered() -> Weight {
+		W::set_recovered()
+	}
+	fn create_recovery(n: u32) -> Weight {
+		let identity_reads = if MustBeKnownGood::get() { n } else { 0 };
+		W::create_recovery(n).saturating_add(
+			<Runtime as frame_system::Config>::DbWeight::get().reads(identity_reads.into()),
+		)
+	}
+	fn initiate_recovery() -> Weight {
+		W::initiate_recovery()
+	}
+	fn vouch_recovery(n: u32) -> Weight {
+		W::vouch_recovery(n)
+	}
+	fn claim_recovery(n: u32) -> Weight {
+		W::claim_recovery(n)
+	}
+	fn close_recovery(n: u32) -> Weight {
+		W::close_recovery(n)
+	}
+	fn remove_recovery(n: u32) -> Weight {
+		W::remove_recovery(n)
+	}
+	fn cancel_recovered() -> Weight {
+		W::cancel_recovered()
+	}
+}